@@ -4,7 +4,7 @@
 use std::fmt;
 
 use crate::{
-    genepred::Extras,
+    genepred::{ExtraValue, Extras, GenePred},
     reader::{ReaderError, ReaderResult},
     strand::Strand,
 };
@@ -17,6 +17,10 @@ const BLOCK_STARTS: &str = "blockStarts";
 const THICK_START: &str = "thickStart";
 const THICK_END: &str = "thickEnd";
 const ITEM_RGB: &str = "itemRgb";
+const CHROM_START_1: &str = "chromStart1";
+const CHROM_END_1: &str = "chromEnd1";
+const CHROM_START_2: &str = "chromStart2";
+const CHROM_END_2: &str = "chromEnd2";
 
 /// Represents an RGB color triplet, typically from column 9 (`itemRgb`) of a BED file.
 ///
@@ -30,7 +34,7 @@ const ITEM_RGB: &str = "itemRgb";
 /// assert_eq!(color.1, 0);
 /// assert_eq!(color.2, 0);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Rgb(pub u8, pub u8, pub u8);
 
 /// A type alias for [`Rgb`] for clarity when used in BED records.
@@ -40,6 +44,7 @@ impl Rgb {
     /// Parses a string into an `Rgb` color.
     ///
     /// The string should be a comma-separated list of three numbers between 0 and 255.
+    /// The UCSC "no color" shorthands `0` and `.` are accepted as `Rgb(0, 0, 0)`.
     ///
     /// # Errors
     ///
@@ -57,6 +62,10 @@ impl Rgb {
     /// }
     /// ```
     pub(crate) fn parse(raw: &str, line: usize) -> ReaderResult<Self> {
+        if matches!(raw, "0" | ".") {
+            return Ok(Rgb(0, 0, 0));
+        }
+
         let mut parts = raw.split(',');
         let mut next = |label: &str| -> ReaderResult<u8> {
             parts
@@ -136,6 +145,11 @@ pub trait BedFormat: Sized + fmt::Debug + Send + Sync + 'static {
     /// Indicates whether the shared `Reader` implementation can parse this format
     /// line-by-line using the standard BED parser.
     const SUPPORTS_STANDARD_READER: bool = true;
+    /// Index of the BED score column, if the format has one.
+    ///
+    /// Lets the reader clamp oversized scores when lenient score parsing is
+    /// enabled.
+    const SCORE_FIELD: Option<usize> = None;
 
     /// Creates a new record from a slice of fields.
     ///
@@ -151,6 +165,19 @@ pub trait BedFormat: Sized + fmt::Debug + Send + Sync + 'static {
     /// A `ReaderResult` containing the new record, or a `ReaderError` if the
     /// record could not be parsed.
     fn from_fields(fields: &[&str], extras: Extras, line: usize) -> ReaderResult<Self>;
+
+    /// Checks that the record's blocks are consistent with its span.
+    ///
+    /// Called by the reader when block validation is enabled. Formats without
+    /// blocks accept every record.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The line number of the record in the input file.
+    fn validate_blocks(&self, line: usize) -> ReaderResult<()> {
+        let _ = line;
+        Ok(())
+    }
 }
 
 /// Parses a BED field to a `u64`.
@@ -213,6 +240,16 @@ pub(crate) fn __parse_score(field: &str, line: usize) -> ReaderResult<u16> {
     Ok(value)
 }
 
+/// Caps an integer score above the BED maximum at `1000`.
+///
+/// Other values are returned unchanged for [`__parse_score`] to check.
+pub(crate) fn __clamp_score(field: &str) -> &str {
+    match field.parse::<u64>() {
+        Ok(value) if value > 1000 => "1000",
+        _ => field,
+    }
+}
+
 /// Parses a comma-separated list of `u32` values.
 ///
 /// Splits on commas and parses each value individually.
@@ -243,6 +280,124 @@ pub(crate) fn __parse_sizes(
         .collect()
 }
 
+/// Field parsers for implementing [`BedFormat`] outside this crate.
+///
+/// These are the helpers the built-in formats use, so custom column layouts
+/// report malformed values with the same [`ReaderError::InvalidField`]
+/// messages, tagged with the line number and the column label.
+///
+/// # Example
+///
+/// ```
+/// use genepred::bed::{parse, BedFormat, Rgb};
+/// use genepred::genepred::{Extras, GenePred};
+/// use genepred::reader::{Reader, ReaderResult};
+///
+/// /// Peaks with a color and per-summit widths.
+/// #[derive(Debug)]
+/// struct Peak {
+///     chrom: Vec<u8>,
+///     start: u64,
+///     end: u64,
+///     name: Vec<u8>,
+///     score: u16,
+///     color: Rgb,
+///     summits: Vec<u32>,
+/// }
+///
+/// impl BedFormat for Peak {
+///     const FIELD_COUNT: usize = 7;
+///
+///     fn from_fields(fields: &[&str], _extras: Extras, line: usize) -> ReaderResult<Self> {
+///         Ok(Self {
+///             chrom: fields[0].as_bytes().to_vec(),
+///             start: parse::to_u64(fields[1], line, "chromStart")?,
+///             end: parse::to_u64(fields[2], line, "chromEnd")?,
+///             name: fields[3].as_bytes().to_vec(),
+///             score: parse::parse_score(fields[4], line)?,
+///             color: parse::parse_rgb(fields[5], line)?,
+///             summits: parse::parse_sizes(fields[6], line, "summits")?,
+///         })
+///     }
+/// }
+///
+/// impl From<Peak> for GenePred {
+///     fn from(peak: Peak) -> Self {
+///         let mut gene = GenePred::from_coords(peak.chrom, peak.start, peak.end, Extras::new());
+///         gene.set_name(Some(peak.name));
+///         gene.set_score(Some(peak.score));
+///         gene.set_item_rgb(Some(peak.color));
+///         gene
+///     }
+/// }
+///
+/// let data = "chr1\t10\t90\tpeak1\t700\t255,0,0\t5,8,\nchr1\t100\t200\tpeak2\t700\t255,0,0\t5,x,\n";
+/// let mut reader = Reader::<Peak>::from_reader(std::io::Cursor::new(data)).unwrap();
+/// let first = reader.records().next().unwrap().unwrap();
+/// assert_eq!(first.score(), Some(700));
+///
+/// let err = reader.records().next().unwrap().unwrap_err();
+/// assert!(err.to_string().contains("summits"));
+/// ```
+pub mod parse {
+    use super::Rgb;
+    use crate::reader::ReaderResult;
+
+    /// Parses an unsigned 64-bit integer column such as a coordinate.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Field string to parse.
+    /// * `line` - Line number for errors.
+    /// * `label` - Column label for error messages.
+    pub fn to_u64(field: &str, line: usize, label: &'static str) -> ReaderResult<u64> {
+        super::__to_u64(field, line, label)
+    }
+
+    /// Parses an unsigned 32-bit integer column such as a block count.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Field string to parse.
+    /// * `line` - Line number for errors.
+    /// * `label` - Column label for error messages.
+    pub fn to_u32(field: &str, line: usize, label: &'static str) -> ReaderResult<u32> {
+        super::__to_u32(field, line, label)
+    }
+
+    /// Parses a BED score, which must be between 0 and 1000.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Field string to parse.
+    /// * `line` - Line number for errors.
+    pub fn parse_score(field: &str, line: usize) -> ReaderResult<u16> {
+        super::__parse_score(field, line)
+    }
+
+    /// Parses a comma-separated list of `u32` values, ignoring a trailing
+    /// comma, as in `blockSizes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `list` - Comma-separated string.
+    /// * `line` - Line number for errors.
+    /// * `label` - Column label for error messages.
+    pub fn parse_sizes(list: &str, line: usize, label: &'static str) -> ReaderResult<Vec<u32>> {
+        super::__parse_sizes(list, line, label)
+    }
+
+    /// Parses an `itemRgb` column; `0` and `.` mean black.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - Field string to parse.
+    /// * `line` - Line number for errors.
+    pub fn parse_rgb(raw: &str, line: usize) -> ReaderResult<Rgb> {
+        Rgb::parse(raw, line)
+    }
+}
+
 /// A BED3 record, containing the essential fields for a genomic region.
 ///
 /// The `chrom`, `start`, and `end` fields are the only required fields in a BED file.
@@ -375,6 +530,7 @@ pub struct Bed5 {
 
 impl BedFormat for Bed5 {
     const FIELD_COUNT: usize = 5;
+    const SCORE_FIELD: Option<usize> = Some(4);
 
     fn from_fields(fields: &[&str], extras: Extras, line: usize) -> ReaderResult<Self> {
         Ok(Self {
@@ -429,6 +585,7 @@ pub struct Bed6 {
 
 impl BedFormat for Bed6 {
     const FIELD_COUNT: usize = 6;
+    const SCORE_FIELD: Option<usize> = Some(4);
 
     fn from_fields(fields: &[&str], extras: Extras, line: usize) -> ReaderResult<Self> {
         Ok(Self {
@@ -494,6 +651,7 @@ pub struct Bed8 {
 
 impl BedFormat for Bed8 {
     const FIELD_COUNT: usize = 8;
+    const SCORE_FIELD: Option<usize> = Some(4);
 
     fn from_fields(fields: &[&str], extras: Extras, line: usize) -> ReaderResult<Self> {
         Ok(Self {
@@ -562,6 +720,7 @@ pub struct Bed9 {
 
 impl BedFormat for Bed9 {
     const FIELD_COUNT: usize = 9;
+    const SCORE_FIELD: Option<usize> = Some(4);
 
     /// Parses a BED9 record from a slice of fields.
     ///
@@ -693,6 +852,7 @@ pub struct Bed12 {
 
 impl BedFormat for Bed12 {
     const FIELD_COUNT: usize = 12;
+    const SCORE_FIELD: Option<usize> = Some(4);
 
     /// Parses a BED12 record from a slice of fields.
     ///
@@ -800,4 +960,181 @@ impl BedFormat for Bed12 {
             extras,
         })
     }
+
+    /// Checks that the blocks tile the feature span.
+    ///
+    /// The first block must start at 0, each block must start at or after
+    /// the end of the previous one, and the last block must end at
+    /// `end - start`.
+    fn validate_blocks(&self, line: usize) -> ReaderResult<()> {
+        let length = self.end.saturating_sub(self.start);
+        let mut previous_end = 0u64;
+        for (index, (offset, size)) in self.block_starts.iter().zip(&self.block_sizes).enumerate() {
+            let offset = *offset as u64;
+            if index == 0 && offset != 0 {
+                return Err(ReaderError::invalid_field(
+                    line,
+                    BLOCK_STARTS,
+                    format!(
+                        "ERROR: first block starts at {offset}, expected 0 in {line}:{BLOCK_STARTS}"
+                    ),
+                ));
+            }
+            if offset < previous_end {
+                return Err(ReaderError::invalid_field(
+                    line,
+                    BLOCK_STARTS,
+                    format!(
+                        "ERROR: block {} starts at {offset}, before the previous block ends at {previous_end} in {line}:{BLOCK_STARTS}",
+                        index + 1
+                    ),
+                ));
+            }
+            previous_end = offset + *size as u64;
+        }
+
+        if !self.block_starts.is_empty() && previous_end != length {
+            return Err(ReaderError::invalid_field(
+                line,
+                BLOCK_SIZES,
+                format!(
+                    "ERROR: last block ends at {previous_end}, expected feature length {length} in {line}:{BLOCK_SIZES}"
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// A BEDPE record describing a pair of linked genomic intervals.
+///
+/// BEDPE is commonly used for structural variants and chromatin contacts
+/// (e.g. Hi-C loops). Each line carries two intervals plus a shared name,
+/// score, and one strand per interval:
+///
+/// `chrom1 start1 end1 chrom2 start2 end2 name score strand1 strand2 [extras...]`
+///
+/// Since [`GenePred`] models a single interval, each side of the pair is
+/// stored as its own `GenePred` carrying the shared name and its own strand.
+/// The score is kept verbatim because BEDPE allows arbitrary values (including `.`).
+///
+/// `BedPe` does not convert into a single `GenePred`, so it cannot be used with
+/// [`crate::reader::Reader`]; parse lines with [`BedPe::parse_line`] instead.
+///
+/// # Example
+///
+/// ```
+/// use genepred::bed::BedPe;
+/// use genepred::strand::Strand;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let pair = BedPe::parse_line("chr1\t100\t200\tchr5\t500\t600\tsv1\t60\t+\t-", 1)?;
+/// assert_eq!(pair.left.chrom(), b"chr1");
+/// assert_eq!(pair.right.start(), 500);
+/// assert_eq!(pair.right.strand(), Some(Strand::Reverse));
+/// assert_eq!(pair.score, b"60");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BedPe {
+    /// The first interval of the pair (`chrom1`, `start1`, `end1`, `strand1`).
+    pub left: GenePred,
+    /// The second interval of the pair (`chrom2`, `start2`, `end2`, `strand2`).
+    pub right: GenePred,
+    /// The raw score column shared by both intervals.
+    pub score: Vec<u8>,
+    /// Any extra fields beyond the standard BEDPE fields.
+    pub extras: Extras,
 }
+
+impl BedPe {
+    /// Returns the name shared by both intervals, if any.
+    pub fn name(&self) -> Option<&[u8]> {
+        self.left.name()
+    }
+
+    /// Returns `true` if both intervals lie on the same chromosome.
+    pub fn is_intrachromosomal(&self) -> bool {
+        self.left.chrom() == self.right.chrom()
+    }
+
+    /// Consumes the record and returns the two intervals.
+    pub fn into_pair(self) -> (GenePred, GenePred) {
+        (self.left, self.right)
+    }
+
+    /// Parses a single tab-separated BEDPE line.
+    ///
+    /// Columns beyond the first ten are stored in `extras` keyed by their
+    /// 1-based column index, mirroring the BED reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line has fewer than ten columns or any of the
+    /// coordinate or strand columns are malformed.
+    pub fn parse_line(line: &str, line_number: usize) -> ReaderResult<Self> {
+        let fields: Vec<&str> = line.trim_end_matches(['\n', '\r']).split('\t').collect();
+        if fields.len() < Self::FIELD_COUNT {
+            return Err(ReaderError::unexpected_field_count(
+                line_number,
+                Self::FIELD_COUNT,
+                fields.len(),
+            ));
+        }
+
+        let mut extras = Extras::new();
+        for (idx, value) in fields[Self::FIELD_COUNT..].iter().enumerate() {
+            extras.insert(
+                (Self::FIELD_COUNT + idx + 1).to_string().into_bytes(),
+                ExtraValue::Scalar(value.as_bytes().to_vec()),
+            );
+        }
+
+        Self::from_fields(&fields[..Self::FIELD_COUNT], extras, line_number)
+    }
+}
+
+impl BedFormat for BedPe {
+    const FIELD_COUNT: usize = 10;
+    const SUPPORTS_STANDARD_READER: bool = false;
+
+    fn from_fields(fields: &[&str], extras: Extras, line: usize) -> ReaderResult<Self> {
+        let name = fields[6].as_bytes().to_vec();
+
+        let mut left = GenePred::from_coords(
+            fields[0].as_bytes().to_vec(),
+            __to_u64(fields[1], line, CHROM_START_1)?,
+            __to_u64(fields[2], line, CHROM_END_1)?,
+            Extras::new(),
+        );
+        left.name = Some(name.clone());
+        left.strand = Some(Strand::parse(fields[8], line)?);
+
+        let mut right = GenePred::from_coords(
+            fields[3].as_bytes().to_vec(),
+            __to_u64(fields[4], line, CHROM_START_2)?,
+            __to_u64(fields[5], line, CHROM_END_2)?,
+            Extras::new(),
+        );
+        right.name = Some(name);
+        right.strand = Some(Strand::parse(fields[9], line)?);
+
+        Ok(Self {
+            left,
+            right,
+            score: fields[7].as_bytes().to_vec(),
+            extras,
+        })
+    }
+}
+
+/// Marker type for bedGraph output (4 columns).
+///
+/// bedGraph is write-only here: records are written through
+/// [`Writer`](crate::Writer) as `chrom start end value`, where the value is
+/// the record score or, with [`WriterOptions::value_key`](crate::WriterOptions::value_key),
+/// a numeric extra. Extras are never appended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BedGraph;
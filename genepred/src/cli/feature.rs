@@ -249,23 +249,14 @@ fn synthesize(
     additional_fields: Option<&[String]>,
 ) -> GenePred {
     let extras = match additional_fields {
-        Some(names) if !names.is_empty() => extras_for_additional_fields(&parent.extras, names),
+        Some(names) if !names.is_empty() => extras_for_additional_fields(parent.extras(), names),
         _ => Extras::new(),
     };
 
-    GenePred {
-        chrom: parent.chrom.clone(),
-        start,
-        end,
-        name: parent.name.clone(),
-        strand: parent.strand,
-        thick_start: None,
-        thick_end: None,
-        block_count: None,
-        block_starts: None,
-        block_ends: None,
-        extras,
-    }
+    let mut gene = GenePred::from_coords(parent.chrom.clone(), start, end, extras);
+    gene.name = parent.name.clone();
+    gene.strand = parent.strand;
+    gene
 }
 
 /// Builds an extras map keyed by zero-padded positional indexes so the writer
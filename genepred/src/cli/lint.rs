@@ -743,8 +743,8 @@ enum GxfLineLocation {
 enum GxfLineClass {
     /// Preserve line (comment or header).
     Preserve,
-    /// Transcript feature line.
-    Transcript(Vec<u8>),
+    /// Transcript feature line, with the IDs of the transcripts it belongs to.
+    Transcript(Vec<Vec<u8>>),
     /// Drop line (not valid).
     Drop,
 }
@@ -803,11 +803,13 @@ impl<F: GxfFormat> GxfPruneState<F> {
         };
 
         match self.aggregator.ingest_line(line, line_number) {
-            GxfLineStatus::Aggregated { parent_id } => {
-                self.first_lines
-                    .entry(parent_id.clone())
-                    .or_insert(line_number);
-                GxfLineClass::Transcript(parent_id)
+            GxfLineStatus::Aggregated { parent_ids } => {
+                for parent_id in &parent_ids {
+                    self.first_lines
+                        .entry(parent_id.clone())
+                        .or_insert(line_number);
+                }
+                GxfLineClass::Transcript(parent_ids)
             }
             GxfLineStatus::Skipped => GxfLineClass::Drop,
             GxfLineStatus::Invalid { parent_id, error } => {
@@ -821,7 +823,7 @@ impl<F: GxfFormat> GxfPruneState<F> {
                         .entry(parent_id.clone())
                         .or_default()
                         .push(diagnostic);
-                    GxfLineClass::Transcript(parent_id)
+                    GxfLineClass::Transcript(vec![parent_id])
                 } else {
                     self.summary.push_outcome(RecordOutcome {
                         diagnostics: vec![diagnostic],
@@ -835,7 +837,16 @@ impl<F: GxfFormat> GxfPruneState<F> {
     /// Finishes aggregation and returns the final summary plus valid transcript IDs.
     fn finish(mut self) -> (LintSummary, HashSet<Vec<u8>>) {
         let mut valid_ids = HashSet::new();
-        for (parent_id, gene) in self.aggregator.into_genepreds() {
+        let genes = match self.aggregator.into_genepreds() {
+            Ok(genes) => genes,
+            Err(error) => {
+                self.summary.push_outcome(RecordOutcome {
+                    diagnostics: vec![diagnostic_from_reader_error(error, None)],
+                });
+                Vec::new()
+            }
+        };
+        for (parent_id, gene) in genes {
             let line = self.first_lines.get(&parent_id).copied();
             let mut diagnostics = self.id_diagnostics.remove(&parent_id).unwrap_or_default();
             if !self.invalid_ids.contains(&parent_id) {
@@ -874,7 +885,9 @@ where
     for line in lines {
         let emit = match &line.class {
             GxfLineClass::Preserve => true,
-            GxfLineClass::Transcript(parent_id) => valid_ids.contains(parent_id),
+            GxfLineClass::Transcript(parent_ids) => parent_ids
+                .iter()
+                .any(|parent_id| valid_ids.contains(parent_id)),
             GxfLineClass::Drop => false,
         };
 
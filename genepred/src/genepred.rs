@@ -2,19 +2,30 @@
 // Distributed under the terms of the Apache License, Version 2.0.
 
 use std::any::{type_name, TypeId};
-use std::collections::{hash_map::Entry, HashMap};
+use std::cmp::Ordering;
+use std::collections::{hash_map::Entry, BTreeMap, HashMap, HashSet};
 use std::fmt;
+use std::sync::OnceLock;
 
 use crate::{
-    bed::{Bed12, Bed3, Bed4, Bed5, Bed6, Bed8, Bed9, BedFormat},
-    gxf::{Gff, Gtf},
+    bed::{Bed12, Bed3, Bed4, Bed5, Bed6, Bed8, Bed9, BedFormat, Rgb},
+    gxf::{push_quoted, Gff, Gtf},
+    index::IntervalIndex,
+    reader::ChromStyle,
     strand::Strand,
+    writer::WriterError,
 };
 
 /// Canonical representation of a GenePred record.
 ///
 /// Fields that are not present in the originating record are left as `None`.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Blocks are always stored in absolute genomic coordinates:
+/// `block_starts[i]..block_ends[i]` is exon `i`, independent of `start`.
+/// Readers convert relative BED12 `blockStarts`/`blockSizes` on the way in,
+/// and writers convert back on the way out, so BED- and GTF/GFF-derived
+/// records with the same geometry compare equal block for block.
+#[derive(Debug, Clone)]
 pub struct GenePred {
     /// Chromosome or scaffold name.
     pub chrom: Vec<u8>,
@@ -24,20 +35,59 @@ pub struct GenePred {
     pub end: u64,
     /// Optional transcript or gene name.
     pub name: Option<Vec<u8>>,
+    /// Optional BED score (0-1000).
+    pub score: Option<u16>,
     /// Optional strand information.
     pub strand: Option<Strand>,
     /// Optional coding region start.
     pub thick_start: Option<u64>,
     /// Optional coding region end.
     pub thick_end: Option<u64>,
+    /// Optional BED item color.
+    pub item_rgb: Option<Rgb>,
     /// Optional exon (block) count.
     pub block_count: Option<u32>,
     /// Optional exon start positions (absolute coordinates).
     pub block_starts: Option<Vec<u64>>,
     /// Optional exon end positions (absolute coordinates).
     pub block_ends: Option<Vec<u64>>,
+    /// Optional phase (0-2) of each CDS segment, in ascending genomic order.
+    ///
+    /// Populated from the phase column of GTF/GFF CDS rows.
+    pub cds_phases: Option<Vec<u8>>,
     /// Additional trailing fields grouped by key.
-    pub extras: Extras,
+    ///
+    /// Readers built with lazy extras leave trailing BED columns out of this
+    /// map until first access, so it is only reachable through
+    /// [`GenePred::extras`] and [`GenePred::extras_mut`].
+    extras: Extras,
+    /// Raw trailing columns not yet merged into `extras`.
+    pending_extras: Option<Box<PendingExtras>>,
+}
+
+/// Trailing BED columns kept as raw bytes until first accessed.
+#[derive(Debug, Clone)]
+struct PendingExtras {
+    /// Tab-separated trailing columns, as read from the line.
+    raw: Vec<u8>,
+    /// One-based column number of the first trailing column.
+    first_field: usize,
+    /// Materialized extras, built on first access.
+    cache: OnceLock<Extras>,
+}
+
+impl PendingExtras {
+    /// Builds the extras map from `base` plus the raw trailing columns.
+    ///
+    /// Keys follow the eager reader: the one-based column number as ASCII.
+    fn materialize(&self, base: &Extras) -> Extras {
+        let mut extras = base.clone();
+        for (idx, field) in self.raw.split(|byte| *byte == b'\t').enumerate() {
+            let key = (self.first_field + idx).to_string().into_bytes();
+            extras.insert(key, ExtraValue::Scalar(field.to_vec()));
+        }
+        extras
+    }
 }
 
 /// Represents additional key/value information associated with a `GenePred`.
@@ -50,7 +100,7 @@ pub type Extras = HashMap<Vec<u8>, ExtraValue>;
 ///
 /// This enum is used to store the values of extra fields in a `GenePred` record.
 /// It avoids allocation for the common case where an extra field has a single value.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ExtraValue {
     /// A single scalar value.
     Scalar(Vec<u8>),
@@ -240,6 +290,138 @@ impl ExtraValue {
     }
 }
 
+/// Typed accessors and update helpers for [`Extras`].
+///
+/// `Extras` stores raw bytes; the getters read the first value of a key and
+/// convert it, returning `None` when the key is missing or conversion fails.
+/// [`ExtrasExt::add_value`] and [`ExtrasExt::set_value`] append to or replace
+/// the values of a key.
+///
+/// # Example
+///
+/// ```
+/// use genepred::genepred::{ExtraValue, Extras, ExtrasExt};
+///
+/// let mut extras = Extras::new();
+/// extras.insert(b"exon_number".to_vec(), ExtraValue::Scalar(b"3".to_vec()));
+/// extras.insert(
+///     b"tag".to_vec(),
+///     ExtraValue::Array(vec![b"basic".to_vec(), b"CCDS".to_vec()]),
+/// );
+///
+/// assert_eq!(extras.get_i64(b"exon_number"), Some(3));
+/// assert_eq!(extras.get_str(b"tag"), Some("basic"));
+/// assert_eq!(extras.get_all_str(b"tag"), Some(vec!["basic", "CCDS"]));
+/// assert_eq!(extras.get_f64(b"missing"), None);
+/// assert_eq!(extras.get_first_ci(b"EXON_NUMBER"), Some(&b"3"[..]));
+/// ```
+pub trait ExtrasExt {
+    /// Returns the first value of `key` as UTF-8.
+    fn get_str(&self, key: &[u8]) -> Option<&str>;
+
+    /// Returns every value of `key` as UTF-8, or `None` if any is not UTF-8.
+    fn get_all_str(&self, key: &[u8]) -> Option<Vec<&str>>;
+
+    /// Returns the first value of `key` parsed as an `i64`.
+    fn get_i64(&self, key: &[u8]) -> Option<i64> {
+        self.get_str(key)?.trim().parse().ok()
+    }
+
+    /// Returns the first value of `key` parsed as an `f64`.
+    fn get_f64(&self, key: &[u8]) -> Option<f64> {
+        self.get_str(key)?.trim().parse().ok()
+    }
+
+    /// Returns the value of `key`, comparing keys ASCII case-insensitively.
+    ///
+    /// An exact match is tried first; on a miss every key is scanned, so the
+    /// fallback is O(n) in the number of extras. When several keys differ
+    /// only by case, the exact match wins, then the lexicographically
+    /// smallest key.
+    fn get_ci(&self, key: &[u8]) -> Option<&ExtraValue>;
+
+    /// Returns the first value of `key`, comparing keys ASCII
+    /// case-insensitively. See [`ExtrasExt::get_ci`].
+    fn get_first_ci(&self, key: &[u8]) -> Option<&[u8]> {
+        self.get_ci(key)?.first()
+    }
+
+    /// Appends `value` to `key`, turning an existing scalar into an array.
+    ///
+    /// Follows [`ExtraValue::push`], so a value already present is not
+    /// repeated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{ExtraValue, Extras, ExtrasExt};
+    ///
+    /// let mut extras = Extras::new();
+    /// extras.add_value("tag", "basic");
+    /// extras.add_value("tag", "CCDS");
+    /// assert_eq!(
+    ///     extras.get(b"tag".as_ref()),
+    ///     Some(&ExtraValue::Array(vec![b"basic".to_vec(), b"CCDS".to_vec()]))
+    /// );
+    /// ```
+    fn add_value<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<Vec<u8>>,
+        V: Into<Vec<u8>>;
+
+    /// Replaces the values of `key` with the scalar `value`, returning the
+    /// previous values.
+    fn set_value<K, V>(&mut self, key: K, value: V) -> Option<ExtraValue>
+    where
+        K: Into<Vec<u8>>,
+        V: Into<Vec<u8>>;
+}
+
+impl ExtrasExt for Extras {
+    fn get_str(&self, key: &[u8]) -> Option<&str> {
+        std::str::from_utf8(self.get(key)?.first()?).ok()
+    }
+
+    fn get_all_str(&self, key: &[u8]) -> Option<Vec<&str>> {
+        self.get(key)?
+            .iter()
+            .map(|value| std::str::from_utf8(value).ok())
+            .collect()
+    }
+
+    fn get_ci(&self, key: &[u8]) -> Option<&ExtraValue> {
+        self.get(key).or_else(|| {
+            self.iter()
+                .filter(|(candidate, _)| candidate.eq_ignore_ascii_case(key))
+                .min_by(|left, right| left.0.cmp(right.0))
+                .map(|(_, value)| value)
+        })
+    }
+
+    fn add_value<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<Vec<u8>>,
+        V: Into<Vec<u8>>,
+    {
+        match self.entry(key.into()) {
+            Entry::Vacant(slot) => {
+                slot.insert(ExtraValue::Scalar(value.into()));
+            }
+            Entry::Occupied(mut slot) => {
+                slot.get_mut().push(value.into());
+            }
+        }
+    }
+
+    fn set_value<K, V>(&mut self, key: K, value: V) -> Option<ExtraValue>
+    where
+        K: Into<Vec<u8>>,
+        V: Into<Vec<u8>>,
+    {
+        self.insert(key.into(), ExtraValue::Scalar(value.into()))
+    }
+}
+
 /// Convert a byte buffer into an [`ExtraValue`].
 impl From<Vec<u8>> for ExtraValue {
     fn from(value: Vec<u8>) -> Self {
@@ -326,16 +508,108 @@ impl GenePred {
             start,
             end,
             name: None,
+            score: None,
             strand: None,
             thick_start: None,
             thick_end: None,
+            item_rgb: None,
             block_count: None,
             block_starts: None,
             block_ends: None,
+            cds_phases: None,
             extras,
+            pending_extras: None,
         }
     }
 
+    /// Creates a new `GenePred`, rejecting an empty chromosome or a start
+    /// past the end.
+    ///
+    /// Fails with [`WriterError::MissingField`] for an empty `chrom` and with
+    /// [`WriterError::Invalid`] when `start > end`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// assert!(GenePred::try_new(b"chr1".to_vec(), 100, 200, Extras::new()).is_ok());
+    /// assert!(GenePred::try_new(b"chr1".to_vec(), 200, 100, Extras::new()).is_err());
+    /// ```
+    pub fn try_new(
+        chrom: Vec<u8>,
+        start: u64,
+        end: u64,
+        extras: Extras,
+    ) -> Result<Self, WriterError> {
+        let gene = Self::from_coords(chrom, start, end, extras);
+        gene.check_span()?;
+        Ok(gene)
+    }
+
+    /// Checks that the record is internally consistent.
+    ///
+    /// On top of the [`GenePred::try_new`] checks, block starts and ends must
+    /// have the same length, matching `block_count` when set, and thick
+    /// bounds must be ordered and lie within `[start, end]`. Fails with
+    /// [`WriterError::Invalid`] describing the first problem found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// assert!(gene.validate().is_ok());
+    ///
+    /// gene.set_thick_end(Some(250));
+    /// assert!(gene.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), WriterError> {
+        self.check_span()?;
+
+        let starts = self.block_starts.as_ref().map(Vec::len);
+        let ends = self.block_ends.as_ref().map(Vec::len);
+        if starts != ends {
+            return Err(block_length_mismatch(
+                starts.unwrap_or(0),
+                ends.unwrap_or(0),
+            ));
+        }
+        if let Some(count) = self.block_count {
+            let blocks = starts.unwrap_or(0);
+            if count as usize != blocks {
+                return Err(WriterError::Invalid(format!(
+                    "block count ({count}) does not match {blocks} blocks"
+                )));
+            }
+        }
+
+        let thick_start = self.thick_start.unwrap_or(self.start);
+        let thick_end = self.thick_end.unwrap_or(self.end);
+        if thick_start > thick_end || thick_start < self.start || thick_end > self.end {
+            return Err(WriterError::Invalid(format!(
+                "thick region {thick_start}-{thick_end} lies outside {}-{}",
+                self.start, self.end
+            )));
+        }
+        Ok(())
+    }
+
+    /// Rejects an empty chromosome or a start past the end.
+    fn check_span(&self) -> Result<(), WriterError> {
+        if self.chrom.is_empty() {
+            return Err(WriterError::MissingField("chrom"));
+        }
+        if self.start > self.end {
+            return Err(WriterError::Invalid(format!(
+                "start ({}) must be <= end ({})",
+                self.start, self.end
+            )));
+        }
+        Ok(())
+    }
+
     /// Returns the chromosome name as raw bytes.
     #[inline]
     pub fn chrom(&self) -> &[u8] {
@@ -360,6 +634,12 @@ impl GenePred {
         self.name.as_deref()
     }
 
+    /// Returns the BED score, if present.
+    #[inline]
+    pub fn score(&self) -> Option<u16> {
+        self.score
+    }
+
     /// Returns the strand information, if present.
     #[inline]
     pub fn strand(&self) -> Option<Strand> {
@@ -378,6 +658,12 @@ impl GenePred {
         self.thick_end
     }
 
+    /// Returns the BED item color, if present.
+    #[inline]
+    pub fn item_rgb(&self) -> Option<Rgb> {
+        self.item_rgb
+    }
+
     /// Returns the block count, if present.
     #[inline]
     pub fn block_count(&self) -> Option<u32> {
@@ -396,18 +682,65 @@ impl GenePred {
         self.block_ends.as_deref()
     }
 
+    /// Returns the stored CDS phases, if present.
+    #[inline]
+    pub fn cds_phases(&self) -> Option<&[u8]> {
+        self.cds_phases.as_deref()
+    }
+
     /// Returns a reference to all extra key/value pairs.
+    ///
+    /// Deferred trailing columns are materialized on the first call.
     #[inline]
     pub fn extras(&self) -> &Extras {
-        &self.extras
+        match &self.pending_extras {
+            Some(pending) => pending
+                .cache
+                .get_or_init(|| pending.materialize(&self.extras)),
+            None => &self.extras,
+        }
     }
 
     /// Returns a mutable reference to all extra key/value pairs.
     #[inline]
     pub fn extras_mut(&mut self) -> &mut Extras {
+        self.materialize_extras();
         &mut self.extras
     }
 
+    /// Returns `true` if trailing columns are still held unparsed.
+    #[inline]
+    pub fn has_pending_extras(&self) -> bool {
+        self.pending_extras
+            .as_ref()
+            .is_some_and(|pending| pending.cache.get().is_none())
+    }
+
+    /// Merges any deferred trailing columns into `extras`.
+    pub fn materialize_extras(&mut self) {
+        if let Some(pending) = self.pending_extras.take() {
+            let mut pending = *pending;
+            self.extras = match pending.cache.take() {
+                Some(extras) => extras,
+                None => pending.materialize(&self.extras),
+            };
+        }
+    }
+
+    /// Defers parsing of trailing BED columns until first access.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - Tab-separated trailing columns.
+    /// * `first_field` - One-based column number of the first trailing column.
+    pub(crate) fn defer_extras(&mut self, raw: Vec<u8>, first_field: usize) {
+        self.pending_extras = Some(Box::new(PendingExtras {
+            raw,
+            first_field,
+            cache: OnceLock::new(),
+        }));
+    }
+
     /// Returns the length of the feature (end - start).
     #[inline]
     pub fn len(&self) -> u64 {
@@ -425,6 +758,27 @@ impl GenePred {
         self.chrom = chrom;
     }
 
+    /// Rewrites the chromosome name to follow `style`.
+    ///
+    /// [`ChromStyle::Ucsc`] adds a missing `chr` prefix and maps `chrMT` to
+    /// `chrM`; [`ChromStyle::Ensembl`] strips the prefix and maps `M` to `MT`.
+    /// [`ChromStyle::Consistent`] has no target convention and leaves the
+    /// name untouched.
+    pub fn normalize_chrom(&mut self, style: ChromStyle) {
+        let base = self.chrom.strip_prefix(b"chr").unwrap_or(&self.chrom);
+        self.chrom = match style {
+            ChromStyle::Ucsc => {
+                let base: &[u8] = if base == b"MT" { b"M" } else { base };
+                [b"chr".as_slice(), base].concat()
+            }
+            ChromStyle::Ensembl => {
+                let base: &[u8] = if base == b"M" { b"MT" } else { base };
+                base.to_vec()
+            }
+            ChromStyle::Consistent => return,
+        };
+    }
+
     /// Sets the start position.
     pub fn set_start(&mut self, start: u64) {
         self.start = start;
@@ -440,11 +794,23 @@ impl GenePred {
         self.name = name;
     }
 
+    /// Sets the BED score.
+    pub fn set_score(&mut self, score: Option<u16>) {
+        self.score = score;
+    }
+
     /// Sets the strand information.
     pub fn set_strand(&mut self, strand: Option<Strand>) {
         self.strand = strand;
     }
 
+    /// Swaps the strand to its opposite, leaving coordinates untouched.
+    ///
+    /// Records without a strand are left unchanged.
+    pub fn flip_strand(&mut self) {
+        self.strand = self.strand.map(Strand::opposite);
+    }
+
     /// Sets the thick start (coding start).
     pub fn set_thick_start(&mut self, thick_start: Option<u64>) {
         self.thick_start = thick_start;
@@ -460,49 +826,164 @@ impl GenePred {
         self.block_count = block_count;
     }
 
-    /// Sets the block starts.
+    /// Sets the block starts, in absolute genomic coordinates.
     pub fn set_block_starts(&mut self, block_starts: Option<Vec<u64>>) {
         self.block_starts = block_starts;
     }
 
-    /// Sets the block ends.
+    /// Sets the block ends, in absolute genomic coordinates.
     pub fn set_block_ends(&mut self, block_ends: Option<Vec<u64>>) {
         self.block_ends = block_ends;
     }
 
-    /// Set the RGB color of the feature as an ExtraValue
-    pub fn set_item_rgb(&mut self, rgb: Vec<u8>) {
-        self.extras.insert(b"rgb".to_vec(), ExtraValue::Scalar(rgb));
+    /// Sets the CDS phases, one per CDS segment in ascending genomic order.
+    pub fn set_cds_phases(&mut self, cds_phases: Option<Vec<u8>>) {
+        self.cds_phases = cds_phases;
+    }
+
+    /// Returns the record with its name set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    /// use genepred::strand::Strand;
+    ///
+    /// let gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new())
+    ///     .with_name(b"tx1".to_vec())
+    ///     .with_strand(Strand::Forward)
+    ///     .with_blocks(vec![100, 150], vec![120, 200]);
+    ///
+    /// assert_eq!(gene.name(), Some(&b"tx1"[..]));
+    /// assert_eq!(gene.exons(), vec![(100, 120), (150, 200)]);
+    /// ```
+    pub fn with_name(mut self, name: impl Into<Vec<u8>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Returns the record with its strand set.
+    pub fn with_strand(mut self, strand: Strand) -> Self {
+        self.strand = Some(strand);
+        self
+    }
+
+    /// Returns the record with its BED score set.
+    pub fn with_score(mut self, score: u16) -> Self {
+        self.score = Some(score);
+        self
+    }
+
+    /// Returns the record with its thick (coding) region set.
+    pub fn with_thick(mut self, thick_start: u64, thick_end: u64) -> Self {
+        self.thick_start = Some(thick_start);
+        self.thick_end = Some(thick_end);
+        self
+    }
+
+    /// Returns the record with its blocks set from absolute start and end
+    /// coordinates. `block_count` is taken from the slice length.
+    ///
+    /// Use [`GenePred::try_with_blocks`] for blocks that come from user data.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `starts` and `ends` differ in length.
+    pub fn with_blocks(self, starts: Vec<u64>, ends: Vec<u64>) -> Self {
+        self.try_with_blocks(starts, ends)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Like [`GenePred::with_blocks`], but fails with [`WriterError::Invalid`]
+    /// instead of panicking when `starts` and `ends` differ in length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// assert!(gene.clone().try_with_blocks(vec![100, 150], vec![120]).is_err());
+    ///
+    /// let gene = gene.try_with_blocks(vec![100, 150], vec![120, 200]).unwrap();
+    /// assert_eq!(gene.block_count(), Some(2));
+    /// ```
+    pub fn try_with_blocks(
+        mut self,
+        starts: Vec<u64>,
+        ends: Vec<u64>,
+    ) -> Result<Self, WriterError> {
+        if starts.len() != ends.len() {
+            return Err(block_length_mismatch(starts.len(), ends.len()));
+        }
+        self.block_count = Some(starts.len() as u32);
+        self.block_starts = Some(starts);
+        self.block_ends = Some(ends);
+        Ok(self)
+    }
+
+    /// Sets the BED item color.
+    pub fn set_item_rgb(&mut self, rgb: Option<Rgb>) {
+        self.item_rgb = rgb;
     }
 
     /// Sets the entire extras map.
     pub fn set_extras(&mut self, extras: Extras) {
+        self.pending_extras = None;
         self.extras = extras;
     }
 
     /// Adds an extra value to the provided key.
+    ///
+    /// Same as [`GenePred::add_attribute`].
     pub fn add_extra<K, V>(&mut self, key: K, value: V)
     where
         K: Into<Vec<u8>>,
         V: Into<Vec<u8>>,
     {
-        match self.extras.entry(key.into()) {
-            Entry::Vacant(slot) => {
-                slot.insert(ExtraValue::Scalar(value.into()));
-            }
-            Entry::Occupied(mut slot) => {
-                slot.get_mut().push(value.into());
-            }
-        }
+        self.add_attribute(key, value);
+    }
+
+    /// Adds a value to an attribute, turning an existing scalar into an array.
+    ///
+    /// A value the attribute already holds is not repeated; see
+    /// [`ExtrasExt::add_value`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{Extras, GenePred};
+    ///
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new());
+    /// gene.add_attribute("tag", "basic");
+    /// gene.add_attribute("tag", "CCDS");
+    /// assert_eq!(gene.get_extra(b"tag").map(|value| value.iter().count()), Some(2));
+    /// ```
+    pub fn add_attribute<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<Vec<u8>>,
+        V: Into<Vec<u8>>,
+    {
+        self.extras_mut().add_value(key, value);
+    }
+
+    /// Sets an attribute to a single value, returning its previous values.
+    pub fn set_attribute<K, V>(&mut self, key: K, value: V) -> Option<ExtraValue>
+    where
+        K: Into<Vec<u8>>,
+        V: Into<Vec<u8>>,
+    {
+        self.extras_mut().set_value(key, value)
     }
 
     /// Returns the value associated with a key, if present.
     pub fn get_extra(&self, key: &[u8]) -> Option<&ExtraValue> {
-        self.extras.get(key)
+        self.extras().get(key)
     }
 
     /// Clears all extra fields.
     pub fn clear_extras(&mut self) {
+        self.pending_extras = None;
         self.extras.clear();
     }
 
@@ -527,27 +1008,45 @@ impl GenePred {
     /// assert_eq!(gene.exons(), vec![(100, 110), (130, 150)]);
     /// ```
     pub fn exons(&self) -> Vec<(u64, u64)> {
-        match (&self.block_count, &self.block_starts, &self.block_ends) {
-            (Some(count), Some(starts), Some(ends)) if *count > 0 => {
-                let count = *count as usize;
-                let mut exons = Vec::with_capacity(count);
-
-                for i in 0..count.min(starts.len()).min(ends.len()) {
-                    let exon_start = starts[i];
-                    let exon_end = ends[i];
-                    if exon_start < exon_end {
-                        exons.push((exon_start, exon_end));
-                    }
-                }
+        self.exons_iter().collect()
+    }
 
-                if exons.is_empty() {
-                    vec![(self.start, self.end)]
-                } else {
-                    exons
+    /// Returns an iterator over exonic coordinates without allocating.
+    ///
+    /// Yields the same intervals as [`GenePred::exons`], including the
+    /// single-interval fallback when no valid blocks are defined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// assert_eq!(gene.exons_iter().collect::<Vec<_>>(), vec![(100, 200)]);
+    ///
+    /// gene.set_block_count(Some(2));
+    /// gene.set_block_starts(Some(vec![100, 130]));
+    /// gene.set_block_ends(Some(vec![110, 150]));
+    /// assert!(gene.exons_iter().eq(gene.exons()));
+    /// ```
+    pub fn exons_iter(&self) -> impl Iterator<Item = (u64, u64)> + '_ {
+        let (starts, ends): (&[u64], &[u64]) =
+            match (&self.block_count, &self.block_starts, &self.block_ends) {
+                (Some(count), Some(starts), Some(ends)) if *count > 0 => {
+                    let count = (*count as usize).min(starts.len()).min(ends.len());
+                    (&starts[..count], &ends[..count])
                 }
-            }
-            _ => vec![(self.start, self.end)],
-        }
+                _ => (&[], &[]),
+            };
+        let blocks = move || {
+            starts
+                .iter()
+                .zip(ends)
+                .map(|(start, end)| (*start, *end))
+                .filter(|(start, end)| start < end)
+        };
+        let fallback = blocks().next().is_none().then_some((self.start, self.end));
+        blocks().chain(fallback)
     }
 
     /// Returns true intronic coordinates as a vector of (start, end) tuples.
@@ -591,11 +1090,80 @@ impl GenePred {
         introns
     }
 
+    /// Returns the donor and acceptor dinucleotide of every intron.
+    ///
+    /// For each intron, in genomic order, yields the 2 bp donor interval
+    /// followed by the 2 bp acceptor interval. Donors sit on the 5' side of
+    /// the intron: its start on the forward strand and its end on the reverse
+    /// strand. Records without a strand are treated as forward. Intervals are
+    /// clipped to the intron for introns shorter than 2 bp.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    /// use genepred::Strand;
+    ///
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// gene.set_strand(Some(Strand::Forward));
+    /// gene.set_block_count(Some(2));
+    /// gene.set_block_starts(Some(vec![100, 150]));
+    /// gene.set_block_ends(Some(vec![120, 200]));
+    ///
+    /// assert_eq!(gene.splice_sites(), vec![(120, 122), (148, 150)]);
+    /// ```
+    pub fn splice_sites(&self) -> Vec<(u64, u64)> {
+        let reverse = self.strand == Some(Strand::Reverse);
+        let mut sites = Vec::with_capacity(self.intron_count() * 2);
+        for (start, end) in self.introns() {
+            let left = (start, (start + 2).min(end));
+            let right = (end.saturating_sub(2).max(start), end);
+            if reverse {
+                sites.extend([right, left]);
+            } else {
+                sites.extend([left, right]);
+            }
+        }
+        sites
+    }
+
+    /// Sorts the exons and coalesces overlapping or touching ones.
+    ///
+    /// Blocks are rewritten only when merging or sorting changes them.
+    /// Returns the number of exons removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// gene.set_block_count(Some(3));
+    /// gene.set_block_starts(Some(vec![100, 120, 160]));
+    /// gene.set_block_ends(Some(vec![130, 150, 200]));
+    ///
+    /// assert_eq!(gene.merge_exons(), 1);
+    /// assert_eq!(gene.exons(), vec![(100, 150), (160, 200)]);
+    /// ```
+    pub fn merge_exons(&mut self) -> usize {
+        let original = self.exons();
+        let merged = merge_intervals(original.clone());
+
+        if merged == original {
+            return 0;
+        }
+
+        let removed = original.len() - merged.len();
+        self.block_count = Some(merged.len() as u32);
+        self.block_starts = Some(merged.iter().map(|(start, _)| *start).collect());
+        self.block_ends = Some(merged.iter().map(|(_, end)| *end).collect());
+        removed
+    }
+
     /// Returns the total exonic length (sum of all exon sizes).
     pub fn exonic_length(&self) -> u64 {
-        self.exons()
-            .iter()
-            .map(|(start, end)| end.saturating_sub(*start))
+        self.exons_iter()
+            .map(|(start, end)| end.saturating_sub(start))
             .sum()
     }
 
@@ -607,6 +1175,90 @@ impl GenePred {
             .sum()
     }
 
+    /// Tiles the record span `[start, end)` with windows of `size` bases,
+    /// advancing by `step`.
+    ///
+    /// The last window is clipped to `end`, and tiling stops once a window
+    /// reaches it, so `step < size` yields overlapping windows without
+    /// trailing duplicates. A zero `size` or `step` yields nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let gene = GenePred::from_coords(b"chr1".to_vec(), 100, 125, Extras::new());
+    /// let tiles: Vec<_> = gene.windows(10, 10).collect();
+    /// assert_eq!(tiles, vec![(100, 110), (110, 120), (120, 125)]);
+    /// ```
+    pub fn windows(&self, size: u64, step: u64) -> impl Iterator<Item = (u64, u64)> {
+        tiles(self.start, self.end, size, step)
+    }
+
+    /// Tiles the exonic sequence with windows of `size` spliced bases,
+    /// advancing by `step`.
+    ///
+    /// Windows are laid out over the exons concatenated in genomic order, as
+    /// in [`GenePred::windows`], and each is returned as the genomic segments
+    /// it covers; a window crossing an intron has one segment per exon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// gene.set_block_count(Some(2));
+    /// gene.set_block_starts(Some(vec![100, 150]));
+    /// gene.set_block_ends(Some(vec![110, 160]));
+    ///
+    /// let tiles: Vec<_> = gene.exon_windows(15, 15).collect();
+    /// assert_eq!(tiles, vec![vec![(100, 110), (150, 155)], vec![(155, 160)]]);
+    /// ```
+    pub fn exon_windows(&self, size: u64, step: u64) -> impl Iterator<Item = Vec<(u64, u64)>> {
+        let exons = self.exons();
+        tiles(0, self.exonic_length(), size, step).map(move |(from, to)| {
+            let mut segments = Vec::new();
+            let mut offset = 0;
+            for &(start, end) in &exons {
+                let length = end.saturating_sub(start);
+                let lo = from.max(offset);
+                let hi = to.min(offset + length);
+                if lo < hi {
+                    segments.push((start + lo - offset, start + hi - offset));
+                }
+                offset += length;
+            }
+            segments
+        })
+    }
+
+    /// Returns true if the record has a coding region.
+    ///
+    /// Follows the UCSC convention: a record is non-coding when its thick
+    /// bounds are missing or empty, as with `thickStart == thickEnd ==
+    /// chromStart`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// gene.set_thick_start(Some(100));
+    /// gene.set_thick_end(Some(100));
+    /// assert!(!gene.is_coding());
+    ///
+    /// gene.set_thick_end(Some(150));
+    /// assert!(gene.is_coding());
+    /// ```
+    pub fn is_coding(&self) -> bool {
+        matches!(
+            (self.thick_start, self.thick_end),
+            (Some(thick_start), Some(thick_end)) if thick_start < thick_end
+        )
+    }
+
     /// Returns coding exon coordinates (intersection of exons with thick regions).
     ///
     /// If thick_start and thick_end are defined, returns only the portions of exons
@@ -685,6 +1337,8 @@ impl GenePred {
     }
 
     /// Returns all 5' UTR (untranslated) exons (strand-aware)
+    ///
+    /// Empty when the strand is unknown or the thick bounds are absent or equal.
     pub fn five_prime_utr(&self) -> Vec<(u64, u64)> {
         match self.strand {
             Some(Strand::Forward) => match (self.thick_start, self.thick_end) {
@@ -726,6 +1380,8 @@ impl GenePred {
     }
 
     /// Returns all 3' UTR (untranslated) exons (strand-aware)
+    ///
+    /// Empty when the strand is unknown or the thick bounds are absent or equal.
     pub fn three_prime_utr(&self) -> Vec<(u64, u64)> {
         match self.strand {
             Some(Strand::Reverse) => match (self.thick_start, self.thick_end) {
@@ -774,16 +1430,334 @@ impl GenePred {
             .sum()
     }
 
-    /// Unnests the extras field by splitting on a delimiter.
+    /// Maps a 0-based genomic position to its 0-based offset in the spliced
+    /// transcript.
+    ///
+    /// Offsets count from the 5' end, so on the reverse strand they run from
+    /// the highest genomic exon end. Records without a strand are treated as
+    /// forward. Returns `None` for intronic positions and positions outside
+    /// the transcript.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    /// use genepred::strand::Strand;
+    ///
+    /// let gene = GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new())
+    ///     .with_strand(Strand::Reverse)
+    ///     .with_blocks(vec![100, 250], vec![150, 300]);
+    ///
+    /// assert_eq!(gene.genomic_to_transcript(299), Some(0));
+    /// assert_eq!(gene.genomic_to_transcript(149), Some(50));
+    /// assert_eq!(gene.genomic_to_transcript(200), None);
+    /// ```
+    pub fn genomic_to_transcript(&self, pos: u64) -> Option<u64> {
+        segment_offset(&self.exons(), self.is_reverse(), pos)
+    }
+
+    /// Maps a 0-based spliced transcript offset back to its genomic position.
+    ///
+    /// This is the inverse of [`GenePred::genomic_to_transcript`]; returns
+    /// `None` when `tpos` is not below the exonic length.
+    pub fn transcript_to_genomic(&self, tpos: u64) -> Option<u64> {
+        segment_position(&self.exons(), self.is_reverse(), tpos)
+    }
+
+    /// Maps a 0-based genomic position to its 0-based offset in the CDS.
+    ///
+    /// Uses [`GenePred::coding_exons`] in transcription order, so offset 0 is
+    /// the first base of the start codon on either strand. Returns `None`
+    /// for positions outside the coding exons, including introns and UTRs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    /// use genepred::strand::Strand;
+    ///
+    /// let gene = GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new())
+    ///     .with_strand(Strand::Forward)
+    ///     .with_blocks(vec![100, 250], vec![150, 300])
+    ///     .with_thick(140, 260);
+    ///
+    /// assert_eq!(gene.genomic_to_cds(140), Some(0));
+    /// assert_eq!(gene.genomic_to_cds(250), Some(10));
+    /// assert_eq!(gene.genomic_to_cds(120), None);
+    /// ```
+    pub fn genomic_to_cds(&self, pos: u64) -> Option<u64> {
+        segment_offset(&self.coding_exons(), self.is_reverse(), pos)
+    }
+
+    /// Converts a 0-based CDS offset into a 0-based amino-acid index and the
+    /// position (0-2) within its codon.
+    ///
+    /// The CDS is assumed to start in frame 0; stored
+    /// [`GenePred::cds_phases`] are not taken into account.
+    pub fn cds_to_protein(&self, cds_pos: u64) -> (u64, u8) {
+        (cds_pos / 3, (cds_pos % 3) as u8)
+    }
+
+    /// Returns true if the record lies on the reverse strand.
+    fn is_reverse(&self) -> bool {
+        self.strand == Some(Strand::Reverse)
+    }
+
+    /// Unnests the extras field by splitting on a delimiter.
+    ///
+    /// This is useful when extra fields contain delimited data that should be
+    /// expanded into separate strings.
+    ///
+    /// # Arguments
+    /// * `delimiter` - The delimiter to split on (e.g., ",", ";", "|")
+    ///
+    /// # Returns
+    /// A flattened vector of all split values from all extra fields (each as a byte buffer).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// gene.add_extra("tags", "tag1,tag2");
+    ///
+    /// assert_eq!(gene.unnest_extras(","), vec![b"tag1".to_vec(), b"tag2".to_vec()]);
+    /// ```
+    pub fn unnest_extras(&self, delimiter: &str) -> Vec<Vec<u8>> {
+        let mut flattened = Vec::new();
+        for value in self.extras().values() {
+            for field in value.iter() {
+                if delimiter.is_empty() {
+                    flattened.push(field.to_vec());
+                    continue;
+                }
+
+                match std::str::from_utf8(field) {
+                    Ok(text) => {
+                        for segment in text.split(delimiter).filter(|segment| !segment.is_empty()) {
+                            flattened.push(segment.as_bytes().to_vec());
+                        }
+                    }
+                    Err(_) => flattened.push(field.to_vec()),
+                }
+            }
+        }
+        flattened
+    }
+
+    /// Checks if the feature overlaps with a given interval.
+    ///
+    /// # Arguments
+    /// * `query_start` - Start position of the query interval
+    /// * `query_end` - End position of the query interval
+    ///
+    /// # Returns
+    /// `true` if there is any overlap, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    ///
+    /// assert!(gene.overlaps(150, 250));
+    /// assert!(!gene.overlaps(300, 400));
+    /// ```
+    #[inline]
+    pub fn overlaps(&self, query_start: u64, query_end: u64) -> bool {
+        self.start < query_end && self.end > query_start
+    }
+
+    /// Returns the locus identity of the record: chromosome, span and strand.
+    ///
+    /// Name, score, blocks and extras are ignored, so records from different
+    /// annotation sources describing the same locus share a key.
+    pub fn coordinate_key(&self) -> (Vec<u8>, u64, u64, Option<Strand>) {
+        (self.chrom.clone(), self.start, self.end, self.strand)
+    }
+
+    /// Borrows the record as a [`CoordKey`] for hashing by locus.
+    pub fn coord_key(&self) -> CoordKey<'_> {
+        CoordKey {
+            chrom: &self.chrom,
+            start: self.start,
+            end: self.end,
+            strand: self.strand,
+        }
+    }
+
+    /// Compares two records by structure, tolerating representation details.
+    ///
+    /// Chromosome, span, name, strand and exons must match; records without
+    /// blocks compare as a single exon over their span. Extras are compared
+    /// with array values sorted, and a scalar equals a one-element array.
+    /// Score, thick bounds, color and CDS phases are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{Extras, GenePred};
+    ///
+    /// let mut a = GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new());
+    /// let mut b = a.clone();
+    /// a.add_extra("tag", "basic");
+    /// a.add_extra("tag", "CCDS");
+    /// b.add_extra("tag", "CCDS");
+    /// b.add_extra("tag", "basic");
+    /// assert_ne!(a, b);
+    /// assert!(a.structurally_eq(&b));
+    /// ```
+    pub fn structurally_eq(&self, other: &GenePred) -> bool {
+        self.chrom == other.chrom
+            && self.start == other.start
+            && self.end == other.end
+            && self.name == other.name
+            && self.strand == other.strand
+            && self.exons_iter().eq(other.exons_iter())
+            && self.extras_structurally_eq(other)
+    }
+
+    /// Compares extras with array values sorted, so a scalar equals a
+    /// one-element array and value order is ignored.
+    pub(crate) fn extras_structurally_eq(&self, other: &GenePred) -> bool {
+        fn normalized(extras: &Extras) -> BTreeMap<&[u8], Vec<&[u8]>> {
+            extras
+                .iter()
+                .map(|(key, value)| {
+                    let mut values: Vec<&[u8]> = value.iter().collect();
+                    values.sort_unstable();
+                    (key.as_slice(), values)
+                })
+                .collect()
+        }
+
+        normalized(self.extras()) == normalized(other.extras())
+    }
+
+    /// Checks if any exon overlaps with a given interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// gene.set_block_count(Some(2));
+    /// gene.set_block_starts(Some(vec![100, 180]));
+    /// gene.set_block_ends(Some(vec![120, 200]));
+    ///
+    /// assert!(gene.exon_overlaps(105, 115));
+    /// assert!(!gene.exon_overlaps(120, 130));
+    /// ```
+    pub fn exon_overlaps(&self, query_start: u64, query_end: u64) -> bool {
+        self.exons()
+            .iter()
+            .any(|&(start, end)| start < query_end && end > query_start)
+    }
+
+    /// Returns the number of bases separating this feature from `other`.
+    ///
+    /// Returns `None` when the chromosomes differ and `Some(0)` when the spans
+    /// overlap. Book-ended features (`self.end == other.start`) have no bases
+    /// between them and also yield `Some(0)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let a = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// let b = GenePred::from_coords(b"chr1".to_vec(), 250, 300, Extras::new());
+    /// assert_eq!(a.distance_to(&b), Some(50));
+    /// assert_eq!(b.distance_to(&a), Some(50));
+    /// ```
+    pub fn distance_to(&self, other: &GenePred) -> Option<u64> {
+        if self.chrom != other.chrom {
+            return None;
+        }
+        Some(
+            other
+                .start
+                .saturating_sub(self.end)
+                .max(self.start.saturating_sub(other.end)),
+        )
+    }
+
+    /// Checks whether this feature lies entirely upstream of `other`.
+    ///
+    /// Direction follows the strand of `self`: for `Strand::Reverse` upstream
+    /// means higher coordinates; otherwise lower coordinates. Features on
+    /// different chromosomes or with overlapping spans are never upstream.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    /// use genepred::strand::Strand;
+    ///
+    /// let mut a = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// let b = GenePred::from_coords(b"chr1".to_vec(), 250, 300, Extras::new());
+    /// assert!(a.is_upstream_of(&b));
+    ///
+    /// a.set_strand(Some(Strand::Reverse));
+    /// assert!(a.is_downstream_of(&b));
+    /// ```
+    pub fn is_upstream_of(&self, other: &GenePred) -> bool {
+        if self.chrom != other.chrom {
+            return false;
+        }
+        match self.strand {
+            Some(Strand::Reverse) => self.start >= other.end,
+            _ => self.end <= other.start,
+        }
+    }
+
+    /// Checks whether this feature lies entirely downstream of `other`.
+    ///
+    /// The strand-aware counterpart of [`GenePred::is_upstream_of`].
+    pub fn is_downstream_of(&self, other: &GenePred) -> bool {
+        if self.chrom != other.chrom {
+            return false;
+        }
+        match self.strand {
+            Some(Strand::Reverse) => self.end <= other.start,
+            _ => self.start >= other.end,
+        }
+    }
+
+    /// Returns the exonic portions that fall inside `[query_start, query_end)`.
     ///
-    /// This is useful when extra fields contain delimited data that should be
-    /// expanded into separate strings.
+    /// Operates on [`GenePred::exons`], so intronic bases are never reported.
     ///
-    /// # Arguments
-    /// * `delimiter` - The delimiter to split on (e.g., ",", ";", "|")
+    /// # Example
     ///
-    /// # Returns
-    /// A flattened vector of all split values from all extra fields (each as a byte buffer).
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// gene.set_block_count(Some(2));
+    /// gene.set_block_starts(Some(vec![100, 180]));
+    /// gene.set_block_ends(Some(vec![120, 200]));
+    ///
+    /// assert_eq!(gene.intersect(110, 190), vec![(110, 120), (180, 190)]);
+    /// ```
+    pub fn intersect(&self, query_start: u64, query_end: u64) -> Vec<(u64, u64)> {
+        self.exons()
+            .into_iter()
+            .filter_map(|(start, end)| {
+                let clipped_start = start.max(query_start);
+                let clipped_end = end.min(query_end);
+                (clipped_start < clipped_end).then_some((clipped_start, clipped_end))
+            })
+            .collect()
+    }
+
+    /// Returns the exonic portions that fall outside `[query_start, query_end)`.
+    ///
+    /// The complement of [`GenePred::intersect`] within the exons; an exon
+    /// straddling the window may be split into two pieces.
     ///
     /// # Example
     ///
@@ -791,85 +1765,264 @@ impl GenePred {
     /// use genepred::genepred::{GenePred, Extras};
     ///
     /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
-    /// gene.add_extra("tags", "tag1,tag2");
+    /// gene.set_block_count(Some(2));
+    /// gene.set_block_starts(Some(vec![100, 180]));
+    /// gene.set_block_ends(Some(vec![120, 200]));
     ///
-    /// assert_eq!(gene.unnest_extras(","), vec![b"tag1".to_vec(), b"tag2".to_vec()]);
+    /// assert_eq!(gene.subtract(110, 190), vec![(100, 110), (190, 200)]);
     /// ```
-    pub fn unnest_extras(&self, delimiter: &str) -> Vec<Vec<u8>> {
-        let mut flattened = Vec::new();
-        for value in self.extras.values() {
-            for field in value.iter() {
-                if delimiter.is_empty() {
-                    flattened.push(field.to_vec());
-                    continue;
-                }
+    pub fn subtract(&self, query_start: u64, query_end: u64) -> Vec<(u64, u64)> {
+        let mut pieces = Vec::new();
+        for (start, end) in self.exons() {
+            if query_end <= start || query_start >= end || query_start >= query_end {
+                pieces.push((start, end));
+                continue;
+            }
+            if start < query_start {
+                pieces.push((start, query_start));
+            }
+            if query_end < end {
+                pieces.push((query_end, end));
+            }
+        }
+        pieces
+    }
 
-                match std::str::from_utf8(field) {
-                    Ok(text) => {
-                        for segment in text.split(delimiter).filter(|segment| !segment.is_empty()) {
-                            flattened.push(segment.as_bytes().to_vec());
-                        }
-                    }
-                    Err(_) => flattened.push(field.to_vec()),
-                }
+    /// Returns the number of exonic bases shared with `other`.
+    ///
+    /// Both exon sets are sorted and merged, then swept together, so
+    /// overlapping blocks within one record are not counted twice. Records on
+    /// different chromosomes share no bases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let mut a = GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new());
+    /// a.set_block_count(Some(2));
+    /// a.set_block_starts(Some(vec![100, 200]));
+    /// a.set_block_ends(Some(vec![150, 300]));
+    /// let b = GenePred::from_coords(b"chr1".to_vec(), 120, 220, Extras::new());
+    ///
+    /// assert_eq!(a.exonic_overlap_bases(&b), 30 + 20);
+    /// ```
+    pub fn exonic_overlap_bases(&self, other: &GenePred) -> u64 {
+        if self.chrom != other.chrom {
+            return 0;
+        }
+        let ours = merge_intervals(self.exons());
+        let theirs = merge_intervals(other.exons());
+
+        let (mut i, mut j, mut shared) = (0, 0, 0);
+        while i < ours.len() && j < theirs.len() {
+            let (a_start, a_end) = ours[i];
+            let (b_start, b_end) = theirs[j];
+            shared += a_end.min(b_end).saturating_sub(a_start.max(b_start));
+            if a_end <= b_end {
+                i += 1;
+            } else {
+                j += 1;
             }
         }
-        flattened
+        shared
     }
 
-    /// Checks if the feature overlaps with a given interval.
+    /// Returns the Jaccard index of the two records' exonic bases.
     ///
-    /// # Arguments
-    /// * `query_start` - Start position of the query interval
-    /// * `query_end` - End position of the query interval
+    /// This is the shared exonic length divided by the length of the union,
+    /// in `[0.0, 1.0]`. Records on different chromosomes, or with no exonic
+    /// bases at all, score `0.0`.
     ///
-    /// # Returns
-    /// `true` if there is any overlap, `false` otherwise.
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let a = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// let b = GenePred::from_coords(b"chr1".to_vec(), 150, 250, Extras::new());
+    ///
+    /// assert!((a.exonic_jaccard(&b) - 50.0 / 150.0).abs() < 1e-9);
+    /// ```
+    pub fn exonic_jaccard(&self, other: &GenePred) -> f64 {
+        let shared = self.exonic_overlap_bases(other);
+        let total: u64 = [self, other]
+            .iter()
+            .flat_map(|record| merge_intervals(record.exons()))
+            .map(|(start, end)| end - start)
+            .sum();
+        let union = total - shared;
+        if union == 0 {
+            return 0.0;
+        }
+        shared as f64 / union as f64
+    }
+
+    /// Returns the promoter window around the transcription start site.
+    ///
+    /// The window is strand-aware: for `Strand::Reverse` the TSS is `end` and
+    /// `upstream` extends towards higher coordinates; otherwise the TSS is
+    /// `start` and `upstream` extends towards lower coordinates. The window
+    /// start is clamped at 0.
     ///
     /// # Example
     ///
     /// ```
     /// use genepred::genepred::{GenePred, Extras};
+    /// use genepred::strand::Strand;
     ///
-    /// let gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 1000, 2000, Extras::new());
+    /// gene.set_strand(Some(Strand::Forward));
+    /// assert_eq!(gene.promoter(500, 100), (500, 1100));
     ///
-    /// assert!(gene.overlaps(150, 250));
-    /// assert!(!gene.overlaps(300, 400));
+    /// gene.set_strand(Some(Strand::Reverse));
+    /// assert_eq!(gene.promoter(500, 100), (1900, 2500));
     /// ```
-    #[inline]
-    pub fn overlaps(&self, query_start: u64, query_end: u64) -> bool {
-        self.start < query_end && self.end > query_start
+    pub fn promoter(&self, upstream: u64, downstream: u64) -> (u64, u64) {
+        match self.strand {
+            Some(Strand::Reverse) => (
+                self.end.saturating_sub(downstream),
+                self.end.saturating_add(upstream),
+            ),
+            _ => (
+                self.start.saturating_sub(upstream),
+                self.start.saturating_add(downstream),
+            ),
+        }
     }
 
-    /// Checks if any exon overlaps with a given interval.
+    /// Widens the feature span by `upstream` bases on the 5' side and
+    /// `downstream` bases on the 3' side.
+    ///
+    /// The extension is strand-aware: for `Strand::Reverse` the 5' side is
+    /// `end`; otherwise it is `start`. The new start is clamped at 0 and the
+    /// new end at `chrom_len` when provided; the span never shrinks. Block and
+    /// thick coordinates are left untouched, so only the span widens.
     ///
     /// # Example
     ///
     /// ```
     /// use genepred::genepred::{GenePred, Extras};
+    /// use genepred::strand::Strand;
     ///
-    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
-    /// gene.set_block_count(Some(2));
-    /// gene.set_block_starts(Some(vec![100, 180]));
-    /// gene.set_block_ends(Some(vec![120, 200]));
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 1000, 2000, Extras::new());
+    /// gene.set_strand(Some(Strand::Reverse));
+    /// gene.extend(100, 50, Some(2050));
+    /// assert_eq!((gene.start(), gene.end()), (950, 2050));
+    /// ```
+    pub fn extend(&mut self, upstream: u64, downstream: u64, chrom_len: Option<u64>) {
+        let (left, right) = match self.strand {
+            Some(Strand::Reverse) => (downstream, upstream),
+            _ => (upstream, downstream),
+        };
+        let mut end = self.end.saturating_add(right);
+        if let Some(limit) = chrom_len {
+            end = end.min(limit).max(self.end);
+        }
+        self.start = self.start.saturating_sub(left);
+        self.end = end;
+    }
+
+    /// Reflects the feature about a chromosome of length `chrom_len`.
+    ///
+    /// Every coordinate `x` becomes `chrom_len - x`, so starts and ends trade
+    /// places: blocks are re-sorted, thick bounds swapped, CDS phases
+    /// reversed and the strand flipped. Mirroring twice restores the record.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WriterError::Invalid`] without changing the record if any
+    /// coordinate exceeds `chrom_len`.
+    ///
+    /// # Example
     ///
-    /// assert!(gene.exon_overlaps(105, 115));
-    /// assert!(!gene.exon_overlaps(120, 130));
     /// ```
-    pub fn exon_overlaps(&self, query_start: u64, query_end: u64) -> bool {
-        self.exons()
-            .iter()
-            .any(|&(start, end)| start < query_end && end > query_start)
+    /// use genepred::genepred::{GenePred, Extras};
+    /// use genepred::strand::Strand;
+    ///
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 10, 40, Extras::new());
+    /// gene.set_strand(Some(Strand::Forward));
+    /// gene.mirror(100).unwrap();
+    /// assert_eq!((gene.start(), gene.end()), (60, 90));
+    /// assert_eq!(gene.strand(), Some(Strand::Reverse));
+    /// ```
+    pub fn mirror(&mut self, chrom_len: u64) -> Result<(), WriterError> {
+        let furthest = [
+            self.end,
+            self.thick_start.unwrap_or(0),
+            self.thick_end.unwrap_or(0),
+        ]
+        .into_iter()
+        .chain(self.block_starts.iter().flatten().copied())
+        .chain(self.block_ends.iter().flatten().copied())
+        .max()
+        .unwrap_or(0);
+        if furthest > chrom_len {
+            return Err(WriterError::Invalid(format!(
+                "coordinate {furthest} exceeds chromosome length {chrom_len}"
+            )));
+        }
+
+        let flip = |x: u64| chrom_len - x;
+        (self.start, self.end) = (flip(self.end), flip(self.start));
+        if let (Some(thick_start), Some(thick_end)) = (self.thick_start, self.thick_end) {
+            self.thick_start = Some(flip(thick_end));
+            self.thick_end = Some(flip(thick_start));
+        }
+        if let (Some(starts), Some(ends)) = (&mut self.block_starts, &mut self.block_ends) {
+            let mut blocks: Vec<(u64, u64)> = starts
+                .iter()
+                .zip(ends.iter())
+                .map(|(&start, &end)| (flip(end), flip(start)))
+                .collect();
+            blocks.sort_unstable();
+            (*starts, *ends) = blocks.into_iter().unzip();
+        }
+        if let Some(phases) = &mut self.cds_phases {
+            phases.reverse();
+        }
+        self.flip_strand();
+        Ok(())
+    }
+
+    /// Returns the per-base read depth over the feature span.
+    ///
+    /// Each read is a half-open `(start, end)` interval in genomic coordinates;
+    /// reads are clipped to `[start, end)` and accumulated with a sweep over a
+    /// difference array. Element `i` of the result is the depth at `start + i`.
+    ///
+    /// Memory is O(feature length): a 2 Mb gene allocates a 2M-element vector,
+    /// so prefer [`GenePred::exonic_coverage_profile`] for long spliced genes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::genepred::{GenePred, Extras};
+    ///
+    /// let gene = GenePred::from_coords(b"chr1".to_vec(), 10, 15, Extras::new());
+    /// assert_eq!(gene.coverage_profile(&[(8, 12), (11, 13)]), vec![1, 2, 1, 0, 0]);
+    /// ```
+    pub fn coverage_profile(&self, reads: &[(u64, u64)]) -> Vec<u32> {
+        coverage_over(&[(self.start, self.end)], reads)
+    }
+
+    /// Returns the per-base read depth over the spliced exonic sequence.
+    ///
+    /// Like [`GenePred::coverage_profile`], but positions are the concatenation
+    /// of `exons()` in genomic order, so intronic bases are excluded. Memory is
+    /// O(exonic length).
+    pub fn exonic_coverage_profile(&self, reads: &[(u64, u64)]) -> Vec<u32> {
+        coverage_over(&self.exons(), reads)
     }
 
     /// Returns the number of exons (blocks).
     pub fn exon_count(&self) -> usize {
-        self.exons().len()
+        self.exons_iter().count()
     }
 
     /// Returns the number of introns.
     pub fn intron_count(&self) -> usize {
-        self.exon_count().saturating_sub(1)
+        self.exons_iter().count().saturating_sub(1)
     }
 
     /// Builds a BED line matching the provided BED type layout.
@@ -916,8 +2069,7 @@ impl GenePred {
         }
 
         if field_count >= 5 {
-            // BED score is currently not represented by GenePred; emit spec-safe default.
-            fields.push(b"0".to_vec());
+            fields.push(self.score.unwrap_or(0).to_string().into_bytes());
         }
 
         if field_count >= 6 {
@@ -935,14 +2087,8 @@ impl GenePred {
         }
 
         if field_count >= 9 {
-            // INFO: try to get rgb from Extras, rollback to 0,0,0 if not found
-            let rgb = self
-                .extras
-                .get(b"rgb".as_slice())
-                .and_then(|extra| extra.first())
-                .unwrap_or(b"0,0,0");
-
-            fields.push(rgb.to_vec());
+            let Rgb(r, g, b) = self.item_rgb.unwrap_or(Rgb(0, 0, 0));
+            fields.push(format!("{r},{g},{b}").into_bytes());
         }
 
         if field_count == 12 {
@@ -954,7 +2100,7 @@ impl GenePred {
 
         for idx in 0..additional_fields {
             let key = (field_count + idx + 1).to_string();
-            let Some(value) = self.extras.get(key.as_bytes()) else {
+            let Some(value) = self.extras().get(key.as_bytes()) else {
                 panic!(
                     "missing additional BED field key '{key}': requested {additional_fields} additional field(s) for BED{field_count}"
                 );
@@ -1009,7 +2155,7 @@ impl GenePred {
         let kind = gxf_output_kind::<K>();
         let transcript_id = resolve_gxf_transcript_id(self);
         let gene_id = resolve_gxf_gene_id(self, &transcript_id, transcript_gene_map);
-        let extra_attrs = collect_gxf_additional_attributes(&self.extras, additional_fields);
+        let extra_attrs = collect_gxf_additional_attributes(self.extras(), additional_fields);
 
         let gene_attrs = render_gxf_feature_attributes(
             kind,
@@ -1148,6 +2294,118 @@ impl GenePred {
 
         lines
     }
+
+    /// Converts the record into a typed [`Bed3`] value.
+    ///
+    /// Extras are carried over unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::{Bed3, Extras, GenePred};
+    ///
+    /// let gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// let bed = gene.to_bed3().unwrap();
+    /// assert_eq!((bed.start, bed.end), (100, 200));
+    /// ```
+    pub fn to_bed3(&self) -> Result<Bed3, WriterError> {
+        Ok(Bed3 {
+            chrom: self.chrom.clone(),
+            start: self.start,
+            end: self.end,
+            extras: self.extras().clone(),
+        })
+    }
+
+    /// Converts the record into a typed [`Bed6`] value.
+    ///
+    /// A missing strand defaults to [`Strand::Unknown`] and a missing score
+    /// to `0`. Fails with [`WriterError::MissingField`] when the record has
+    /// no name.
+    pub fn to_bed6(&self) -> Result<Bed6, WriterError> {
+        Ok(Bed6 {
+            chrom: self.chrom.clone(),
+            start: self.start,
+            end: self.end,
+            name: self.name.clone().ok_or(WriterError::MissingField("name"))?,
+            score: self.score.unwrap_or(0),
+            strand: self.strand.unwrap_or(Strand::Unknown),
+            extras: self.extras().clone(),
+        })
+    }
+
+    /// Converts the record into a typed [`Bed12`] value.
+    ///
+    /// Block sizes and starts are derived from [`GenePred::exons`] relative
+    /// to `start`. `thick_start`/`thick_end` default to `start`/`end`, the
+    /// strand to [`Strand::Unknown`], the score to `0` and the item RGB to
+    /// black. Fails with [`WriterError::MissingField`] when the record has no
+    /// name, and with [`WriterError::Invalid`] when a block lies outside the
+    /// record or does not fit in 32 bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::{Extras, GenePred};
+    ///
+    /// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    /// gene.set_name(Some(b"tx1".to_vec()));
+    /// gene.set_block_count(Some(2));
+    /// gene.set_block_starts(Some(vec![100, 150]));
+    /// gene.set_block_ends(Some(vec![120, 200]));
+    ///
+    /// let bed = gene.to_bed12().unwrap();
+    /// assert_eq!(bed.block_sizes, vec![20, 50]);
+    /// assert_eq!(bed.block_starts, vec![0, 50]);
+    /// ```
+    pub fn to_bed12(&self) -> Result<Bed12, WriterError> {
+        let name = self.name.clone().ok_or(WriterError::MissingField("name"))?;
+        let exons = self.exons();
+        let mut block_sizes = Vec::with_capacity(exons.len());
+        let mut block_starts = Vec::with_capacity(exons.len());
+        for (start, end) in &exons {
+            if *start < self.start || *end > self.end {
+                return Err(WriterError::Invalid(format!(
+                    "block {start}-{end} lies outside record bounds {}-{}",
+                    self.start, self.end
+                )));
+            }
+            let offset = u32::try_from(start - self.start).map_err(|_| {
+                WriterError::Invalid(format!(
+                    "block start offset {} exceeds u32",
+                    start - self.start
+                ))
+            })?;
+            let size = u32::try_from(end - start).map_err(|_| {
+                WriterError::Invalid(format!("block size {} exceeds u32", end - start))
+            })?;
+            block_starts.push(offset);
+            block_sizes.push(size);
+        }
+
+        Ok(Bed12 {
+            chrom: self.chrom.clone(),
+            start: self.start,
+            end: self.end,
+            name,
+            score: self.score.unwrap_or(0),
+            strand: self.strand.unwrap_or(Strand::Unknown),
+            thick_start: self.thick_start.unwrap_or(self.start),
+            thick_end: self.thick_end.unwrap_or(self.end),
+            item_rgb: self.item_rgb.unwrap_or(Rgb(0, 0, 0)),
+            block_count: exons.len() as u32,
+            block_sizes,
+            block_starts,
+            extras: self.extras().clone(),
+        })
+    }
+}
+
+/// Reports block starts and ends of different lengths.
+fn block_length_mismatch(starts: usize, ends: usize) -> WriterError {
+    WriterError::Invalid(format!(
+        "block starts ({starts}) and ends ({ends}) differ in length"
+    ))
 }
 
 /// Convert a `Strand` to a BED strand byte.
@@ -1320,13 +2578,13 @@ where
 /// * `record` - GenePred record to extract transcript ID from.
 fn resolve_gxf_transcript_id(record: &GenePred) -> Vec<u8> {
     record
-        .extras
+        .extras()
         .get(b"transcript_id".as_ref())
         .and_then(ExtraValue::first)
         .map(|value| value.to_vec())
         .or_else(|| {
             record
-                .extras
+                .extras()
                 .get(b"ID".as_ref())
                 .and_then(ExtraValue::first)
                 .map(|value| value.to_vec())
@@ -1466,9 +2724,9 @@ fn render_gtf_attributes(attributes: &[(Vec<u8>, Vec<u8>)]) -> Vec<u8> {
     let mut out = Vec::with_capacity(attributes.len() * 16);
     for (key, value) in attributes {
         out.extend_from_slice(key);
-        out.extend_from_slice(b" \"");
-        out.extend_from_slice(value);
-        out.extend_from_slice(b"\"; ");
+        out.push(b' ');
+        push_quoted(&mut out, value);
+        out.extend_from_slice(b"; ");
     }
 
     while out.last().is_some_and(|byte| *byte == b' ') {
@@ -1706,6 +2964,18 @@ fn derive_gxf_coding_exons(
     }
 }
 
+/// Tiles `[start, end)` with windows of `size` advancing by `step`, clipping
+/// the last one to `end`. See [`GenePred::windows`].
+fn tiles(start: u64, end: u64, size: u64, step: u64) -> impl Iterator<Item = (u64, u64)> {
+    let mut next = (size > 0 && step > 0 && start < end).then_some(start);
+    std::iter::from_fn(move || {
+        let from = next?;
+        let to = from.saturating_add(size).min(end);
+        next = Some(from.saturating_add(step)).filter(|&from| to < end && from < end);
+        Some((from, to))
+    })
+}
+
 /// Returns the exon number for a transcript.
 ///
 /// Strand-aware numbering: forward uses 1-based ascending,
@@ -1716,13 +2986,161 @@ fn derive_gxf_coding_exons(
 /// * `strand` - Strand orientation.
 /// * `exon_index` - 0-based exon index.
 /// * `exon_count` - Total number of exons.
-fn transcript_exon_number(strand: Strand, exon_index: usize, exon_count: usize) -> usize {
+pub(crate) fn transcript_exon_number(
+    strand: Strand,
+    exon_index: usize,
+    exon_count: usize,
+) -> usize {
     match strand {
         Strand::Reverse => exon_count.saturating_sub(exon_index),
         Strand::Forward | Strand::Unknown => exon_index + 1,
     }
 }
 
+impl PartialEq for GenePred {
+    fn eq(&self, other: &Self) -> bool {
+        self.chrom == other.chrom
+            && self.start == other.start
+            && self.end == other.end
+            && self.name == other.name
+            && self.score == other.score
+            && self.strand == other.strand
+            && self.thick_start == other.thick_start
+            && self.thick_end == other.thick_end
+            && self.item_rgb == other.item_rgb
+            && self.block_count == other.block_count
+            && self.block_starts == other.block_starts
+            && self.block_ends == other.block_ends
+            && self.cds_phases == other.cds_phases
+            && self.extras() == other.extras()
+    }
+}
+
+impl Eq for GenePred {}
+
+/// Borrowed locus of a record, hashed and compared by coordinates only.
+///
+/// Two keys are equal when their chromosome, start, end and strand match;
+/// see [`GenePred::coordinate_key`] for the owned equivalent.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashSet;
+/// use genepred::genepred::{Extras, GenePred};
+///
+/// let a = GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new()).with_name("a");
+/// let b = GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new()).with_name("b");
+/// let loci: HashSet<_> = [&a, &b].into_iter().map(GenePred::coord_key).collect();
+/// assert_eq!(loci.len(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CoordKey<'a> {
+    /// Chromosome or scaffold name.
+    pub chrom: &'a [u8],
+    /// 0-based start position.
+    pub start: u64,
+    /// End position.
+    pub end: u64,
+    /// Strand, if known.
+    pub strand: Option<Strand>,
+}
+
+impl PartialOrd for GenePred {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GenePred {
+    /// Orders records by chromosome (bytewise), start, end, then name.
+    ///
+    /// Remaining fields only break ties so the ordering agrees with `Eq`.
+    /// See [`crate::sort::natural_chrom_cmp`] for `chr2` < `chr10` ordering.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.chrom
+            .cmp(&other.chrom)
+            .then(self.start.cmp(&other.start))
+            .then(self.end.cmp(&other.end))
+            .then_with(|| self.name.cmp(&other.name))
+            .then_with(|| self.strand.cmp(&other.strand))
+            .then_with(|| self.score.cmp(&other.score))
+            .then_with(|| self.thick_start.cmp(&other.thick_start))
+            .then_with(|| self.thick_end.cmp(&other.thick_end))
+            .then_with(|| self.item_rgb.cmp(&other.item_rgb))
+            .then_with(|| self.block_count.cmp(&other.block_count))
+            .then_with(|| self.block_starts.cmp(&other.block_starts))
+            .then_with(|| self.block_ends.cmp(&other.block_ends))
+            .then_with(|| self.cds_phases.cmp(&other.cds_phases))
+            .then_with(|| sorted_extras(self.extras()).cmp(&sorted_extras(other.extras())))
+    }
+}
+
+/// Sorts intervals and coalesces overlapping or touching ones.
+fn merge_intervals(mut intervals: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+    intervals.sort_unstable();
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Returns the offset of `pos` within sorted, non-overlapping `segments`
+/// concatenated in transcription order.
+fn segment_offset(segments: &[(u64, u64)], reverse: bool, pos: u64) -> Option<u64> {
+    let index = segments
+        .iter()
+        .position(|&(start, end)| start <= pos && pos < end)?;
+    if reverse {
+        let after: u64 = segments[index + 1..]
+            .iter()
+            .map(|(start, end)| end - start)
+            .sum();
+        Some(after + (segments[index].1 - 1 - pos))
+    } else {
+        let before: u64 = segments[..index]
+            .iter()
+            .map(|(start, end)| end - start)
+            .sum();
+        Some(before + (pos - segments[index].0))
+    }
+}
+
+/// Returns the genomic position at `offset` within `segments` concatenated
+/// in transcription order; the inverse of [`segment_offset`].
+fn segment_position(segments: &[(u64, u64)], reverse: bool, offset: u64) -> Option<u64> {
+    let mut remaining = offset;
+    let mut locate = |&(start, end): &(u64, u64)| {
+        let len = end - start;
+        if remaining < len {
+            Some(if reverse {
+                end - 1 - remaining
+            } else {
+                start + remaining
+            })
+        } else {
+            remaining -= len;
+            None
+        }
+    };
+    if reverse {
+        segments.iter().rev().find_map(&mut locate)
+    } else {
+        segments.iter().find_map(&mut locate)
+    }
+}
+
+/// Returns extras as key-sorted pairs for order-independent comparison.
+fn sorted_extras(extras: &Extras) -> Vec<(&Vec<u8>, &ExtraValue)> {
+    let mut pairs: Vec<_> = extras.iter().collect();
+    pairs.sort_unstable();
+    pairs
+}
+
 impl fmt::Display for GenePred {
     /// Formats a gene prediction as a string.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1776,11 +3194,11 @@ impl fmt::Display for GenePred {
             }
         }
 
-        if !self.extras.is_empty() {
-            let mut keys: Vec<&Vec<u8>> = self.extras.keys().collect();
+        if !self.extras().is_empty() {
+            let mut keys: Vec<&Vec<u8>> = self.extras().keys().collect();
             keys.sort();
             for key in keys {
-                if let Some(extra) = self.extras.get(key) {
+                if let Some(extra) = self.extras().get(key) {
                     f.write_str("\t")?;
                     f.write_str(&String::from_utf8_lossy(key))?;
                     f.write_str("=")?;
@@ -1806,6 +3224,117 @@ impl fmt::Display for GenePred {
     }
 }
 
+/// Computes per-base depth of `reads` over the concatenation of `segments`.
+///
+/// `segments` must be sorted and non-overlapping.
+fn coverage_over(segments: &[(u64, u64)], reads: &[(u64, u64)]) -> Vec<u32> {
+    let total: u64 = segments
+        .iter()
+        .map(|(start, end)| end.saturating_sub(*start))
+        .sum();
+    let mut deltas = vec![0i64; total as usize + 1];
+
+    let mut offset = 0u64;
+    for &(seg_start, seg_end) in segments {
+        if seg_end <= seg_start {
+            continue;
+        }
+        for &(read_start, read_end) in reads {
+            let start = read_start.max(seg_start);
+            let end = read_end.min(seg_end);
+            if start < end {
+                deltas[(offset + start - seg_start) as usize] += 1;
+                deltas[(offset + end - seg_start) as usize] -= 1;
+            }
+        }
+        offset += seg_end - seg_start;
+    }
+
+    let mut depth = 0i64;
+    deltas
+        .iter()
+        .take(total as usize)
+        .map(|delta| {
+            depth += delta;
+            depth as u32
+        })
+        .collect()
+}
+
+/// Returns, for each gene, the indices of peaks overlapping its promoter window.
+///
+/// The promoter of every gene is computed with [`GenePred::promoter`] and queried
+/// against an [`IntervalIndex`] of `peaks`. Genes without any
+/// overlapping peak are still reported with an empty index list, so the output
+/// has one entry per gene in input order. Peak indices are returned ascending.
+///
+/// # Example
+///
+/// ```
+/// use genepred::genepred::{promoter_overlaps, GenePred, Extras};
+/// use genepred::strand::Strand;
+///
+/// let mut gene = GenePred::from_coords(b"chr1".to_vec(), 1000, 2000, Extras::new());
+/// gene.set_strand(Some(Strand::Forward));
+/// let peaks = vec![GenePred::from_coords(b"chr1".to_vec(), 800, 900, Extras::new())];
+///
+/// let hits = promoter_overlaps(&[gene], &peaks, 500, 100);
+/// assert_eq!(hits, vec![(0, vec![0])]);
+/// ```
+pub fn promoter_overlaps(
+    genes: &[GenePred],
+    peaks: &[GenePred],
+    upstream: u64,
+    downstream: u64,
+) -> Vec<(usize, Vec<usize>)> {
+    let index = IntervalIndex::from_spans(
+        peaks
+            .iter()
+            .enumerate()
+            .map(|(idx, peak)| (peak.chrom.clone(), peak.start, peak.end, idx)),
+    );
+
+    genes
+        .iter()
+        .enumerate()
+        .map(|(gene_idx, gene)| {
+            let (window_start, window_end) = gene.promoter(upstream, downstream);
+            let mut hits: Vec<usize> = index
+                .query_spans(gene.chrom(), window_start, window_end)
+                .copied()
+                .collect();
+            hits.sort_unstable();
+            (gene_idx, hits)
+        })
+        .collect()
+}
+
+/// Removes records sharing a locus, keeping the first occurrence of each.
+///
+/// Loci are compared with [`GenePred::coordinate_key`]; input order is
+/// otherwise preserved.
+///
+/// # Example
+///
+/// ```
+/// use genepred::genepred::{dedup_by_coords, Extras, GenePred};
+///
+/// let records = vec![
+///     GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new()).with_name("a"),
+///     GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new()).with_name("b"),
+/// ];
+/// let unique = dedup_by_coords(records);
+/// assert_eq!(unique.len(), 1);
+/// assert_eq!(unique[0].name(), Some(b"a".as_ref()));
+/// ```
+pub fn dedup_by_coords(records: Vec<GenePred>) -> Vec<GenePred> {
+    let mut seen = HashSet::with_capacity(records.len());
+    records
+        .into_iter()
+        .filter(|record| seen.insert(record.coordinate_key()))
+        .collect()
+}
+
 /// Converts a `Bed3` record to a `GenePred` record.
 impl From<Bed3> for GenePred {
     fn from(record: Bed3) -> Self {
@@ -1827,6 +3356,7 @@ impl From<Bed5> for GenePred {
     fn from(record: Bed5) -> Self {
         let mut gene = GenePred::from_coords(record.chrom, record.start, record.end, record.extras);
         gene.name = Some(record.name);
+        gene.score = Some(record.score);
         gene
     }
 }
@@ -1836,6 +3366,7 @@ impl From<Bed6> for GenePred {
     fn from(record: Bed6) -> Self {
         let mut gene = GenePred::from_coords(record.chrom, record.start, record.end, record.extras);
         gene.name = Some(record.name);
+        gene.score = Some(record.score);
         gene.strand = Some(record.strand);
         gene
     }
@@ -1846,6 +3377,7 @@ impl From<Bed8> for GenePred {
     fn from(record: Bed8) -> Self {
         let mut gene = GenePred::from_coords(record.chrom, record.start, record.end, record.extras);
         gene.name = Some(record.name);
+        gene.score = Some(record.score);
         gene.strand = Some(record.strand);
         gene.thick_start = Some(record.thick_start);
         gene.thick_end = Some(record.thick_end);
@@ -1858,9 +3390,11 @@ impl From<Bed9> for GenePred {
     fn from(record: Bed9) -> Self {
         let mut gene = GenePred::from_coords(record.chrom, record.start, record.end, record.extras);
         gene.name = Some(record.name);
+        gene.score = Some(record.score);
         gene.strand = Some(record.strand);
         gene.thick_start = Some(record.thick_start);
         gene.thick_end = Some(record.thick_end);
+        gene.item_rgb = Some(record.item_rgb);
         gene
     }
 }
@@ -1870,9 +3404,11 @@ impl From<Bed12> for GenePred {
     fn from(record: Bed12) -> Self {
         let mut gene = GenePred::from_coords(record.chrom, record.start, record.end, record.extras);
         gene.name = Some(record.name);
+        gene.score = Some(record.score);
         gene.strand = Some(record.strand);
         gene.thick_start = Some(record.thick_start);
         gene.thick_end = Some(record.thick_end);
+        gene.item_rgb = Some(record.item_rgb);
         gene.block_count = Some(record.block_count);
 
         let mut block_starts = Vec::with_capacity(record.block_starts.len());
@@ -3,10 +3,11 @@
 
 #[cfg(feature = "mmap")]
 use std::io::Cursor;
+#[cfg(feature = "mmap")]
+use std::sync::Arc;
 use std::{
-    collections::{hash_map::Entry, HashMap},
+    collections::{hash_map::Entry, BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
     fmt,
-    fs::File,
     io::{BufRead, BufReader, Read},
     path::Path,
 };
@@ -15,16 +16,18 @@ use std::{
 use bzip2::read::BzDecoder;
 #[cfg(feature = "gzip")]
 use flate2::read::MultiGzDecoder;
-use memchr::memchr;
+use memchr::{memchr, memchr2};
 #[cfg(feature = "mmap")]
-use memmap2::MmapOptions;
+use memmap2::{Mmap, MmapOptions};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 #[cfg(feature = "zstd")]
 use zstd::stream::read::Decoder as ZstdDecoder;
 
 use crate::{
     bed::BedFormat,
     genepred::{ExtraValue, Extras, GenePred},
-    reader::{ReaderError, ReaderOptions, ReaderResult},
+    reader::{open_file, ReaderError, ReaderOptions, ReaderResult},
     strand::Strand,
 };
 
@@ -55,8 +58,26 @@ pub trait GxfFormat {
     const DEFAULT_PARENT_FEATURE: &'static [u8];
     /// Human readable format name (for error messages).
     const TYPE_NAME: &'static str;
+    /// Attributes whose values are comma-separated lists by spec.
+    const MULTI_VALUE_ATTRIBUTES: &'static [&'static [u8]] = &[];
 }
 
+/// GFF3 attributes defined by the spec as comma-separated lists.
+///
+/// Values for these keys are always parsed into [`ExtraValue::Array`] when
+/// reading GFF, e.g. `Dbxref=GeneID:1,HGNC:2` yields two entries. Override
+/// the list with [`ReaderOptions::multi_value_attributes`].
+pub const GFF3_MULTI_VALUE_ATTRIBUTES: &[&[u8]] =
+    &[b"Parent", b"Alias", b"Dbxref", b"Ontology_term"];
+
+/// Attributes tried, in order, to name an aggregated transcript.
+///
+/// The first attribute present on any of the transcript's rows becomes
+/// [`GenePred::name`](crate::GenePred::name); the parent identifier is used
+/// when none match. Override the list with [`ReaderOptions::name_attributes`].
+pub const DEFAULT_NAME_ATTRIBUTES: &[&[u8]] =
+    &[b"transcript_name", b"Name", b"gene_name", b"transcript_id"];
+
 impl GxfFormat for Gtf {
     const ATTR_SEPARATOR: u8 = b' ';
     const DEFAULT_PARENT_ATTRIBUTE: &'static [u8] = b"transcript_id";
@@ -71,6 +92,222 @@ impl GxfFormat for Gff {
     const DEFAULT_CHILD_ATTRIBUTE: &'static [u8] = b"Parent";
     const DEFAULT_PARENT_FEATURE: &'static [u8] = b"mRNA";
     const TYPE_NAME: &'static str = "GFF";
+    const MULTI_VALUE_ATTRIBUTES: &'static [&'static [u8]] = GFF3_MULTI_VALUE_ATTRIBUTES;
+}
+
+/// Controls how transcripts without any exon rows are handled during aggregation.
+///
+/// A parent feature (e.g. a `transcript` line) that never receives an `exon`
+/// child may be a legitimately sparse annotation or a malformed record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExonlessPolicy {
+    /// Synthesize a single exon spanning the transcript extent (default).
+    #[default]
+    Synthesize,
+    /// Omit the transcript from the output.
+    Skip,
+    /// Fail with a `ReaderError`.
+    Error,
+}
+
+/// Aggregation bucket for feature types registered with
+/// [`ReaderOptions::extra_feature_types`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureKind {
+    /// Treat rows as `exon` rows.
+    Exon,
+    /// Treat rows as `CDS` rows.
+    Cds,
+}
+
+impl FeatureKind {
+    /// Returns the canonical feature name rows of this kind are aggregated as.
+    fn feature_name(self) -> &'static [u8] {
+        match self {
+            FeatureKind::Exon => b"exon",
+            FeatureKind::Cds => b"CDS",
+        }
+    }
+}
+
+/// Controls what a streaming GXF reader does when a transcript's rows
+/// reappear after the transcript was closed.
+///
+/// See [`ReaderOptions::streaming_window`] for when a transcript closes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReappearPolicy {
+    /// Fail with a `ReaderError` (default). Every closed transcript ID is
+    /// remembered to detect this, so memory grows with the number of
+    /// transcripts in the input.
+    #[default]
+    Error,
+    /// Emit the later rows as a separate record. Closed IDs are not
+    /// remembered, so memory stays bounded by the streaming window.
+    Split,
+}
+
+/// Controls how transcripts whose rows disagree on chromosome or strand are
+/// handled during aggregation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictPolicy {
+    /// Fail with a `ReaderError` (default).
+    #[default]
+    Error,
+    /// Omit the transcript from the output.
+    Skip,
+    /// Keep the transcript with [`Strand::Unknown`]. Rows on a chromosome
+    /// other than the transcript's first are ignored.
+    SetUnknown,
+}
+
+/// Granularity at which GXF rows are aggregated into `GenePred` records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AggregationLevel {
+    /// One record per transcript (default).
+    #[default]
+    Transcript,
+    /// One record per gene, merging the exons of all its transcripts.
+    Gene,
+}
+
+/// Header pragmas collected from a GXF file.
+///
+/// Both GFF3 `##key value` directives and the Ensembl GTF `#!key value`
+/// form are recognized. Plain `#` comments and the `###` forward-reference
+/// marker are ignored.
+///
+/// # Example
+///
+/// ```
+/// use genepred::GxfMetadata;
+///
+/// let mut metadata = GxfMetadata::default();
+/// metadata.ingest("##gff-version 3\n");
+/// metadata.ingest("##genome-build NCBI GRCh38\n");
+/// metadata.ingest("##sequence-region chr1 1 248956422\n");
+/// assert_eq!(metadata.gff_version.as_deref(), Some("3"));
+/// assert_eq!(metadata.genome_build.as_deref(), Some("NCBI GRCh38"));
+/// assert_eq!(metadata.sequence_regions[0].end, 248956422);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GxfMetadata {
+    /// Value of `##gff-version`.
+    pub gff_version: Option<String>,
+    /// Value of `##species`, usually an NCBI taxonomy URL.
+    pub species: Option<String>,
+    /// Value of `##genome-build`, source and build name as written.
+    pub genome_build: Option<String>,
+    /// Every well-formed `##sequence-region` directive, in file order.
+    pub sequence_regions: Vec<SequenceRegion>,
+    /// Unrecognized or malformed pragmas, verbatim without the line ending.
+    pub other: Vec<String>,
+    /// Sequences from a trailing `##FASTA` section, when captured.
+    pub sequences: Vec<FastaSequence>,
+}
+
+/// A sequence from the `##FASTA` section of a GFF3 file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastaSequence {
+    /// The identifier: the header up to the first whitespace.
+    pub id: String,
+    /// The residues with line breaks removed.
+    pub sequence: Vec<u8>,
+}
+
+/// A `##sequence-region seqid start end` directive (1-based, inclusive).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SequenceRegion {
+    /// The sequence identifier.
+    pub seqid: String,
+    /// The 1-based start coordinate.
+    pub start: u64,
+    /// The 1-based inclusive end coordinate.
+    pub end: u64,
+}
+
+/// Feature counts gathered by [`summarize`] without aggregating transcripts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeatureSummary {
+    /// Number of rows per feature type (column 3), e.g. `exon` or `CDS`.
+    pub feature_counts: BTreeMap<Vec<u8>, usize>,
+    /// Number of distinct values of the format's child grouping attribute
+    /// (`transcript_id` for GTF, `Parent` for GFF).
+    pub parents: usize,
+    /// Every sequence name seen in column 1.
+    pub chroms: BTreeSet<Vec<u8>>,
+}
+
+impl FeatureSummary {
+    /// Returns the number of rows of the given feature type.
+    pub fn count(&self, feature: &[u8]) -> usize {
+        self.feature_counts.get(feature).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of feature rows.
+    pub fn total(&self) -> usize {
+        self.feature_counts.values().sum()
+    }
+}
+
+impl GxfMetadata {
+    /// Records a header line if it is a pragma.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A raw comment line, with or without its line ending.
+    pub fn ingest(&mut self, line: &str) {
+        let line = line.trim_end();
+        let Some(directive) = line.strip_prefix("##").or_else(|| line.strip_prefix("#!")) else {
+            return;
+        };
+        if directive.is_empty() || directive.starts_with('#') {
+            return;
+        }
+
+        let (key, value) = match directive.split_once(char::is_whitespace) {
+            Some((key, value)) => (key, value.trim()),
+            None => (directive, ""),
+        };
+        let value = (!value.is_empty()).then(|| value.to_string());
+
+        match (key, value) {
+            ("gff-version", Some(value)) => self.gff_version = Some(value),
+            ("species", Some(value)) => self.species = Some(value),
+            ("genome-build", Some(value)) => self.genome_build = Some(value),
+            ("sequence-region", Some(value)) => match SequenceRegion::parse(&value) {
+                Some(region) => self.sequence_regions.push(region),
+                None => self.other.push(line.to_string()),
+            },
+            _ => self.other.push(line.to_string()),
+        }
+    }
+
+    /// Returns `true` when no pragma or sequence has been recorded.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the captured `##FASTA` sequence for `id`, if present.
+    pub fn sequence(&self, id: &str) -> Option<&[u8]> {
+        self.sequences
+            .iter()
+            .find(|record| record.id == id)
+            .map(|record| record.sequence.as_slice())
+    }
+}
+
+impl SequenceRegion {
+    /// Parses the `seqid start end` value of a `##sequence-region` pragma.
+    fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.split_whitespace();
+        let seqid = parts.next()?.to_string();
+        let start = parts.next()?.parse().ok()?;
+        let end = parts.next()?.parse().ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self { seqid, start, end })
+    }
 }
 
 /// Reads a GXF (GTF/GFF) file and produces fully aggregated `GenePred` records.
@@ -91,7 +328,7 @@ impl GxfFormat for Gff {
 pub(crate) fn read_gxf_file<F, P>(
     path: P,
     options: &ReaderOptions<'_>,
-) -> ReaderResult<Vec<GenePred>>
+) -> ReaderResult<(Vec<GenePred>, GxfMetadata)>
 where
     F: GxfFormat,
     P: AsRef<Path>,
@@ -101,11 +338,49 @@ where
     parse_gxf_stream::<F, _>(reader, options)
 }
 
+/// Parses an in-memory GXF (GTF/GFF) buffer into aggregated `GenePred` records.
+///
+/// # Arguments
+///
+/// * `bytes` - The uncompressed GXF contents.
+/// * `options` - Configuration options for parsing the buffer.
+pub(crate) fn read_gxf_bytes<F: GxfFormat>(
+    bytes: &[u8],
+    options: &ReaderOptions<'_>,
+) -> ReaderResult<(Vec<GenePred>, GxfMetadata)> {
+    parse_gxf_stream::<F, _>(bytes, options)
+}
+
+/// Opens a GXF (GTF/GFF) file as a lazily aggregating record stream.
+///
+/// Unlike [`read_gxf_file`], records are emitted as soon as their parent
+/// transcript is closed, so only the currently open `TranscriptBuilder`s are
+/// kept in memory, plus the IDs of closed transcripts under
+/// [`ReappearPolicy::Error`]. See [`GxfStream`] for the close heuristic.
+///
+/// # Arguments
+///
+/// * `path` - The path to the GXF file.
+/// * `options` - Configuration options for parsing the file.
+pub(crate) fn stream_gxf_file<F, P>(
+    path: P,
+    options: &ReaderOptions<'_>,
+) -> ReaderResult<GxfStream<F, BufReader<Box<dyn Read + Send>>>>
+where
+    F: GxfFormat,
+    P: AsRef<Path>,
+{
+    let stream = open_stream(path.as_ref())?;
+    let reader = BufReader::with_capacity(128 * 1024, stream);
+    Ok(GxfStream::new(reader, options))
+}
+
 #[cfg(feature = "mmap")]
 /// Reads a GXF file from a memory-mapped file.
 ///
 /// This function reads a GXF file from a memory-mapped file, parses it, and
-/// aggregates the records into a `Vec<GenePred>`.
+/// aggregates the records into a `Vec<GenePred>`. Compressed files are mapped
+/// as well and decoded from the mapping.
 ///
 /// # Arguments
 ///
@@ -119,13 +394,22 @@ where
 pub(crate) fn read_gxf_mmap<F, P>(
     path: P,
     options: &ReaderOptions<'_>,
-) -> ReaderResult<Vec<GenePred>>
+) -> ReaderResult<(Vec<GenePred>, GxfMetadata)>
 where
     F: GxfFormat,
     P: AsRef<Path>,
 {
-    let file = File::open(path.as_ref())?;
+    let file = open_file(path.as_ref())?;
     let map = unsafe { MmapOptions::new().map(&file) }.map_err(ReaderError::Mmap)?;
+    if is_compressed(path.as_ref()) {
+        let lines = MappedLines {
+            map: Arc::new(map),
+            cursor: 0,
+        };
+        let stream = decompress(lines, path.as_ref())?;
+        let reader = BufReader::with_capacity(128 * 1024, stream);
+        return parse_gxf_stream::<F, _>(reader, options);
+    }
     let cursor = Cursor::new(&map[..]);
     let reader = BufReader::with_capacity(128 * 1024, cursor);
     let result = parse_gxf_stream::<F, _>(reader, options);
@@ -133,6 +417,158 @@ where
     result
 }
 
+#[cfg(feature = "mmap")]
+/// Opens a memory-mapped GXF file as a lazily aggregating record stream.
+///
+/// Lines are read straight from the mapping, which the stream keeps alive,
+/// so records are materialized only as iteration demands them. Compressed
+/// files are decoded from the mapping on the fly. See [`GxfStream`] for the
+/// close heuristic.
+///
+/// # Arguments
+///
+/// * `path` - The path to the GXF file.
+/// * `options` - Configuration options for parsing the file.
+pub(crate) fn stream_gxf_mmap<F, P>(
+    path: P,
+    options: &ReaderOptions<'_>,
+) -> ReaderResult<GxfStream<F, Box<dyn BufRead + Send>>>
+where
+    F: GxfFormat,
+    P: AsRef<Path>,
+{
+    let file = open_file(path.as_ref())?;
+    let map = unsafe { MmapOptions::new().map(&file) }.map_err(ReaderError::Mmap)?;
+    let lines = MappedLines {
+        map: Arc::new(map),
+        cursor: 0,
+    };
+    let reader: Box<dyn BufRead + Send> = if is_compressed(path.as_ref()) {
+        let stream = decompress(lines, path.as_ref())?;
+        Box::new(BufReader::with_capacity(128 * 1024, stream))
+    } else {
+        Box::new(lines)
+    };
+    Ok(GxfStream::new(reader, options))
+}
+
+#[cfg(feature = "mmap")]
+/// Returns `true` if `path` carries a compressed file extension.
+fn is_compressed(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| matches!(ext.to_str(), Some("gz" | "zst" | "zstd" | "bz2" | "bzip2")))
+}
+
+#[cfg(feature = "mmap")]
+/// Buffered line source over a shared memory map.
+pub(crate) struct MappedLines {
+    /// The mapped file.
+    map: Arc<Mmap>,
+    /// Offset of the next unread byte.
+    cursor: usize,
+}
+
+#[cfg(feature = "mmap")]
+impl Read for MappedLines {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = (&self.map[self.cursor..]).read(buf)?;
+        self.cursor += read;
+        Ok(read)
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl BufRead for MappedLines {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(&self.map[self.cursor..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.cursor = (self.cursor + amt).min(self.map.len());
+    }
+}
+
+/// Counts the feature rows of a GXF (GTF/GFF) file by type.
+///
+/// Only the sequence, feature and attribute columns are looked at and no
+/// transcripts are built, so this is much cheaper than a full read. Comment
+/// lines are skipped and scanning stops at a GFF3 `##FASTA` section. Handles
+/// the same compressed inputs as [`crate::Reader::from_gxf`].
+///
+/// # Arguments
+///
+/// * `path` - The path to the GXF file.
+///
+/// # Example
+///
+/// ```rust,no_run,ignore
+/// use genepred::gxf::{summarize, Gtf};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let summary = summarize::<Gtf, _>("tests/data/simple.gtf")?;
+///     println!("{} exons in {} transcripts", summary.count(b"exon"), summary.parents);
+///     Ok(())
+/// }
+/// ```
+pub fn summarize<F, P>(path: P) -> ReaderResult<FeatureSummary>
+where
+    F: GxfFormat,
+    P: AsRef<Path>,
+{
+    let mut reader = BufReader::with_capacity(128 * 1024, open_stream(path.as_ref())?);
+    let mut summary = FeatureSummary::default();
+    let mut parents: HashSet<Vec<u8>> = HashSet::new();
+    let mut line = String::with_capacity(2048);
+    let mut line_number = 0usize;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        line_number += 1;
+        if starts_fasta(&line) {
+            break;
+        }
+        if should_skip(&line) {
+            continue;
+        }
+
+        let mut fields = line.trim_end_matches(['\n', '\r']).split('\t');
+        let chrom = fields.next().ok_or_else(|| missing("seqid", line_number))?;
+        let feature = fields
+            .nth(1)
+            .ok_or_else(|| missing("feature", line_number))?;
+        let attributes = fields
+            .nth(5)
+            .ok_or_else(|| missing("attributes", line_number))?;
+
+        if !summary.chroms.contains(chrom.as_bytes()) {
+            summary.chroms.insert(chrom.as_bytes().to_vec());
+        }
+        *summary
+            .feature_counts
+            .entry(feature.as_bytes().to_vec())
+            .or_insert(0) += 1;
+
+        let Ok(attributes) = parse_attributes(attributes.as_bytes(), F::ATTR_SEPARATOR) else {
+            continue;
+        };
+        if let Some(value) = attributes.get(F::DEFAULT_CHILD_ATTRIBUTE) {
+            for raw in value.iter() {
+                for parent in raw.split(|byte| *byte == b',') {
+                    if !parent.is_empty() && !parents.contains(parent) {
+                        parents.insert(parent.to_vec());
+                    }
+                }
+            }
+        }
+    }
+
+    summary.parents = parents.len();
+    Ok(summary)
+}
+
 /// Opens a file and returns a boxed reader.
 ///
 /// This function opens a file from the given path and returns a boxed `Read`
@@ -154,16 +590,26 @@ where
 /// }
 /// ```
 fn open_stream(path: &Path) -> ReaderResult<Box<dyn Read + Send>> {
+    decompress(open_file(path)?, path)
+}
+
+/// Wraps `source` in the decoder matching the extension of `path`.
+///
+/// Plain inputs are returned as is, so this works for both files and
+/// memory-mapped bytes.
+fn decompress<S>(source: S, path: &Path) -> ReaderResult<Box<dyn Read + Send>>
+where
+    S: Read + Send + 'static,
+{
     #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
     {
-        let file = File::open(path)?;
         let compression = compression_from_extension(path);
         return match compression {
-            Compression::None | Compression::Auto => Ok(Box::new(file)),
-            Compression::Gzip => {
+            Compression::None | Compression::Auto => Ok(Box::new(source)),
+            Compression::Gzip | Compression::Bgzf => {
                 #[cfg(feature = "gzip")]
                 {
-                    Ok(Box::new(MultiGzDecoder::new(file)))
+                    Ok(Box::new(MultiGzDecoder::new(source)))
                 }
                 #[cfg(not(feature = "gzip"))]
                 {
@@ -175,7 +621,7 @@ fn open_stream(path: &Path) -> ReaderResult<Box<dyn Read + Send>> {
             Compression::Zstd => {
                 #[cfg(feature = "zstd")]
                 {
-                    Ok(Box::new(ZstdDecoder::new(file)?))
+                    Ok(Box::new(ZstdDecoder::new(source)?))
                 }
                 #[cfg(not(feature = "zstd"))]
                 {
@@ -187,7 +633,7 @@ fn open_stream(path: &Path) -> ReaderResult<Box<dyn Read + Send>> {
             Compression::Bzip2 => {
                 #[cfg(feature = "bz2")]
                 {
-                    Ok(Box::new(BzDecoder::new(file)))
+                    Ok(Box::new(BzDecoder::new(source)))
                 }
                 #[cfg(not(feature = "bz2"))]
                 {
@@ -208,7 +654,7 @@ fn open_stream(path: &Path) -> ReaderResult<Box<dyn Read + Send>> {
                 "ERROR: enable a compression feature to read compressed inputs".into(),
             ));
         }
-        Ok(Box::new(File::open(path)?))
+        Ok(Box::new(source))
     }
 }
 
@@ -230,7 +676,7 @@ fn open_stream(path: &Path) -> ReaderResult<Box<dyn Read + Send>> {
 fn compression_from_extension(path: &Path) -> Compression {
     let ext = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
     match ext {
-        "gz" => Compression::Gzip,
+        "gz" => crate::reader::gzip_flavor(path),
         "zst" | "zstd" => Compression::Zstd,
         "bz2" | "bzip2" => Compression::Bzip2,
         _ => Compression::None,
@@ -249,16 +695,25 @@ fn compression_from_extension(path: &Path) -> Compression {
 ///
 /// # Returns
 ///
-/// A `ReaderResult` containing a `Vec<GenePred>` of the parsed records, or a
+/// A `ReaderResult` containing the parsed records and header pragmas, or a
 /// `ReaderError` if the stream could not be read or parsed.
-fn parse_gxf_stream<F, R>(mut reader: R, options: &ReaderOptions<'_>) -> ReaderResult<Vec<GenePred>>
+fn parse_gxf_stream<F, R>(
+    mut reader: R,
+    options: &ReaderOptions<'_>,
+) -> ReaderResult<(Vec<GenePred>, GxfMetadata)>
 where
     F: GxfFormat,
     R: BufRead,
 {
+    #[cfg(feature = "rayon")]
+    if options.is_parallel() {
+        return parse_gxf_par::<F, _>(reader, options);
+    }
+
     let mut line = String::with_capacity(2048);
     let mut line_number = 0usize;
     let mut aggregator = GxfAggregator::<F>::new(options);
+    let mut metadata = GxfMetadata::default();
 
     loop {
         line.clear();
@@ -266,7 +721,14 @@ where
             break;
         }
         line_number += 1;
+        if starts_fasta(&line) {
+            if options.captures_fasta() {
+                read_fasta(&mut reader, &line, &mut metadata.sequences)?;
+            }
+            break;
+        }
         if should_skip(&line) {
+            metadata.ingest(&line);
             continue;
         }
 
@@ -276,19 +738,305 @@ where
         }
     }
 
-    Ok(aggregator
-        .into_genepreds()
+    let records = aggregator
+        .into_genepreds()?
         .into_iter()
         .map(|(_, gene)| gene)
-        .collect())
+        .collect();
+    let mut records = match options.aggregation_level() {
+        AggregationLevel::Transcript => records,
+        AggregationLevel::Gene => merge_by_gene(records),
+    };
+    if options.sorts_output() {
+        records.sort();
+    }
+    Ok((records, metadata))
+}
+
+#[cfg(feature = "rayon")]
+/// Parses a GXF stream across the rayon thread pool.
+///
+/// The input is read into memory, feature lines are parsed in parallel and
+/// bucketed by parent ID, and each transcript is then built on its own
+/// thread. Records are returned sorted by their [`Ord`] order (chromosome,
+/// start, end, name) so the output does not depend on scheduling.
+///
+/// # Arguments
+///
+/// * `reader` - The reader to read the GXF stream from.
+/// * `options` - Configuration options for parsing the stream.
+fn parse_gxf_par<F, R>(
+    mut reader: R,
+    options: &ReaderOptions<'_>,
+) -> ReaderResult<(Vec<GenePred>, GxfMetadata)>
+where
+    F: GxfFormat,
+    R: BufRead,
+{
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+
+    let mut metadata = GxfMetadata::default();
+    let mut lines = Vec::new();
+    let mut offset = 0usize;
+    for (idx, line) in text.split_inclusive('\n').enumerate() {
+        offset += line.len();
+        if starts_fasta(line) {
+            if options.captures_fasta() {
+                read_fasta(
+                    &mut &text.as_bytes()[offset..],
+                    line,
+                    &mut metadata.sequences,
+                )?;
+            }
+            break;
+        }
+        if should_skip(line) {
+            metadata.ingest(line);
+            continue;
+        }
+        lines.push((idx + 1, line));
+    }
+
+    let aggregator = GxfAggregator::<F>::new(options);
+    let classified: Vec<_> = lines
+        .par_iter()
+        .map(|(line_number, line)| aggregator.classify_line(line, *line_number))
+        .collect();
+
+    let mut index: HashMap<Vec<u8>, usize> = HashMap::new();
+    let mut buckets: Vec<Vec<(usize, GxfFeature)>> = Vec::new();
+    for ((line_number, _), features) in lines.iter().zip(classified) {
+        for feature in features? {
+            let slot = match index.get(&feature.parent_id) {
+                Some(slot) => *slot,
+                None => {
+                    index.insert(feature.parent_id.clone(), buckets.len());
+                    buckets.push(Vec::new());
+                    buckets.len() - 1
+                }
+            };
+            buckets[slot].push((*line_number, feature));
+        }
+    }
+
+    let built: Vec<ReaderResult<Option<GenePred>>> = buckets
+        .into_par_iter()
+        .map(|rows| {
+            let (first_line, first) = &rows[0];
+            let mut builder = TranscriptBuilder::new(&first.record, *first_line);
+            for (line_number, feature) in &rows {
+                let own_id = own_id_key(
+                    &aggregator.parent_attr,
+                    &aggregator.child_attr,
+                    feature.is_parent,
+                );
+                builder.absorb_line(
+                    feature,
+                    own_id,
+                    &aggregator.name_attributes,
+                    aggregator.conflict,
+                    *line_number,
+                )?;
+            }
+            builder.into_genepred(
+                first.parent_id.clone(),
+                aggregator.exonless,
+                aggregator.derive_exons,
+            )
+        })
+        .collect();
+
+    let mut records = Vec::with_capacity(built.len());
+    for gene in built {
+        records.extend(gene?);
+    }
+    let mut records = match options.aggregation_level() {
+        AggregationLevel::Transcript => records,
+        AggregationLevel::Gene => merge_by_gene(records),
+    };
+    if options.sorts_output() {
+        records.par_sort();
+    }
+    Ok((records, metadata))
+}
+
+/// Streaming GXF aggregator that yields `GenePred`s as transcripts close.
+///
+/// A transcript is considered closed once more than `window` other parent IDs
+/// have been touched since its last line. With the default window of 1 this
+/// means "close the previous transcript when a new parent ID is seen", which
+/// is correct for inputs sorted (grouped) by transcript. Inputs whose
+/// transcripts are interleaved need a wider window; a parent ID that reappears
+/// after being closed is handled according to [`ReappearPolicy`]: by default
+/// it is reported as an error rather than silently split into two records.
+///
+/// Detecting a reappearing transcript requires remembering every closed parent
+/// ID, so under [`ReappearPolicy::Error`] memory grows with the number of
+/// transcript IDs in the input, even though only `window` transcripts are ever
+/// aggregated at once. [`ReappearPolicy::Split`] keeps no such set.
+pub(crate) struct GxfStream<F: GxfFormat, R: BufRead> {
+    /// Underlying line reader.
+    reader: R,
+    /// Reusable line buffer.
+    line: String,
+    /// Current one-based line number.
+    line_number: usize,
+    /// Aggregator holding the currently open transcripts.
+    aggregator: GxfAggregator<F>,
+    /// Open parent IDs ordered from least to most recently touched.
+    open: VecDeque<Vec<u8>>,
+    /// Parent IDs that have already been emitted; only filled under
+    /// [`ReappearPolicy::Error`].
+    closed: HashSet<Vec<u8>>,
+    /// Maximum number of transcripts kept open at once.
+    window: usize,
+    /// Behaviour for transcripts that reappear after being closed.
+    reappear: ReappearPolicy,
+    /// Records closed but not yet yielded.
+    ready: VecDeque<GenePred>,
+    /// Whether the input is exhausted or an error was returned.
+    finished: bool,
+}
+
+impl<F: GxfFormat, R: BufRead> GxfStream<F, R> {
+    /// Creates a new streaming aggregator over `reader`.
+    pub(crate) fn new(reader: R, options: &ReaderOptions<'_>) -> Self {
+        Self {
+            reader,
+            line: String::with_capacity(2048),
+            line_number: 0,
+            aggregator: GxfAggregator::new(options),
+            open: VecDeque::new(),
+            closed: HashSet::new(),
+            window: options.streaming_window_size(),
+            reappear: options.reappear_policy(),
+            ready: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /// Consumes the leading comment block and collects its pragmas.
+    ///
+    /// Stops at the first line not starting with `#`, leaving it unread.
+    pub(crate) fn read_header(&mut self) -> ReaderResult<GxfMetadata> {
+        let mut metadata = GxfMetadata::default();
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.first() != Some(&b'#') {
+                return Ok(metadata);
+            }
+            self.line.clear();
+            self.reader.read_line(&mut self.line)?;
+            self.line_number += 1;
+            metadata.ingest(&self.line);
+        }
+    }
+
+    /// Marks `parent_id` as the most recently touched open transcript.
+    fn touch(&mut self, parent_id: Vec<u8>) {
+        if self.open.back() == Some(&parent_id) {
+            return;
+        }
+        if let Some(pos) = self.open.iter().position(|id| *id == parent_id) {
+            self.open.remove(pos);
+        }
+        self.open.push_back(parent_id);
+    }
+
+    /// Closes the least recently touched transcript.
+    fn close_oldest(&mut self) -> ReaderResult<()> {
+        if let Some(parent_id) = self.open.pop_front() {
+            if let Some(gene) = self.aggregator.finish_transcript(&parent_id)? {
+                self.ready.push_back(gene);
+            }
+            if self.reappear == ReappearPolicy::Error {
+                self.closed.insert(parent_id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads and ingests the next line, closing transcripts as needed.
+    ///
+    /// Returns `Ok(false)` at end of input.
+    fn advance(&mut self) -> ReaderResult<bool> {
+        self.line.clear();
+        if self.reader.read_line(&mut self.line)? == 0 {
+            while !self.open.is_empty() {
+                self.close_oldest()?;
+            }
+            return Ok(false);
+        }
+        self.line_number += 1;
+        if starts_fasta(&self.line) {
+            while !self.open.is_empty() {
+                self.close_oldest()?;
+            }
+            return Ok(false);
+        }
+        if should_skip(&self.line) {
+            return Ok(true);
+        }
+
+        match self.aggregator.ingest_line(&self.line, self.line_number) {
+            GxfLineStatus::Aggregated { parent_ids } => {
+                for parent_id in parent_ids {
+                    if self.closed.contains(&parent_id) {
+                        return Err(ReaderError::invalid_field(
+                            self.line_number,
+                            "parent",
+                            format!(
+                                "ERROR: transcript '{}' reappeared after being closed; \
+                                 input is not grouped by transcript (increase the \
+                                 streaming window, disable streaming or use \
+                                 ReappearPolicy::Split)",
+                                String::from_utf8_lossy(&parent_id)
+                            ),
+                        ));
+                    }
+                    self.touch(parent_id);
+                }
+                while self.open.len() > self.window {
+                    self.close_oldest()?;
+                }
+                Ok(true)
+            }
+            GxfLineStatus::Skipped => Ok(true),
+            GxfLineStatus::Invalid { error, .. } => Err(error),
+        }
+    }
+}
+
+impl<F: GxfFormat, R: BufRead> Iterator for GxfStream<F, R> {
+    type Item = ReaderResult<GenePred>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(gene) = self.ready.pop_front() {
+                return Some(Ok(gene));
+            }
+            if self.finished {
+                return None;
+            }
+            match self.advance() {
+                Ok(true) => {}
+                Ok(false) => self.finished = true,
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(error));
+                }
+            }
+        }
+    }
 }
 
 /// Result of ingesting a GXF feature line into an aggregator.
 pub(crate) enum GxfLineStatus {
-    /// The line was relevant and attached to a parent feature.
+    /// The line was relevant and attached to its parent features.
     Aggregated {
-        /// Parent transcript or record identifier.
-        parent_id: Vec<u8>,
+        /// Parent transcript or record identifiers, in attribute order.
+        parent_ids: Vec<Vec<u8>>,
     },
     /// The line was syntactically valid but not relevant to aggregation.
     Skipped,
@@ -311,10 +1059,24 @@ pub(crate) struct GxfAggregator<F: GxfFormat> {
     parent_feature: Vec<u8>,
     /// Optional allowed child feature names.
     child_features: Option<Vec<Vec<u8>>>,
+    /// Additional feature names aggregated as exon or CDS rows.
+    extra_features: Vec<(Vec<u8>, FeatureKind)>,
+    /// Behaviour for transcripts without exon rows.
+    exonless: ExonlessPolicy,
+    /// Behaviour for transcripts spanning several chromosomes or strands.
+    conflict: ConflictPolicy,
+    /// Rebuild exons from CDS and UTR rows when no exon rows exist.
+    derive_exons: bool,
+    /// Attributes split into arrays on commas.
+    multi_value: Vec<Vec<u8>>,
+    /// Decode GFF3 `%XX` escapes in attribute values.
+    decode_percent: bool,
+    /// Attributes tried, in order, to name a transcript.
+    name_attributes: Vec<Vec<u8>>,
     /// Transcript builders keyed by parent ID.
     transcripts: HashMap<Vec<u8>, TranscriptBuilder>,
     /// Marker for the GXF format implementation.
-    _marker: std::marker::PhantomData<F>,
+    _marker: std::marker::PhantomData<fn() -> F>,
 }
 
 /// Helper methods for GXF aggregation.
@@ -335,6 +1097,13 @@ impl<F: GxfFormat> GxfAggregator<F> {
                     .map(|feature| feature.as_ref().to_vec())
                     .collect()
             }),
+            extra_features: options.resolved_extra_feature_types(),
+            exonless: options.exonless_policy(),
+            conflict: options.strand_conflict_policy(),
+            derive_exons: options.derives_exons_from_cds_utr(),
+            multi_value: options.resolved_multi_value_attributes::<F>(),
+            decode_percent: options.decodes_percent(),
+            name_attributes: options.resolved_name_attributes(),
             transcripts: HashMap::new(),
             _marker: std::marker::PhantomData,
         }
@@ -347,8 +1116,9 @@ impl<F: GxfFormat> GxfAggregator<F> {
     /// * `line` - Raw GTF/GFF feature line.
     /// * `line_number` - One-based source line number.
     pub(crate) fn ingest_line(&mut self, line: &str, line_number: usize) -> GxfLineStatus {
-        let record = match GxfRecord::parse(line, line_number, F::ATTR_SEPARATOR) {
-            Ok(record) => record,
+        let features = match self.classify_line(line, line_number) {
+            Ok(features) if features.is_empty() => return GxfLineStatus::Skipped,
+            Ok(features) => features,
             Err(error) => {
                 return GxfLineStatus::Invalid {
                     parent_id: None,
@@ -357,67 +1127,154 @@ impl<F: GxfFormat> GxfAggregator<F> {
             }
         };
 
-        let is_parent_feature = eq_ignore_ascii(&record.feature, &self.parent_feature);
-        if !is_parent_feature {
-            if let Some(features) = &self.child_features {
+        let mut parent_ids = Vec::with_capacity(features.len());
+        for feature in features {
+            let own_id = own_id_key(&self.parent_attr, &self.child_attr, feature.is_parent);
+            let entry = self
+                .transcripts
+                .entry(feature.parent_id.clone())
+                .or_insert_with(|| TranscriptBuilder::new(&feature.record, line_number));
+
+            if let Err(error) = entry.absorb_line(
+                &feature,
+                own_id,
+                &self.name_attributes,
+                self.conflict,
+                line_number,
+            ) {
+                return GxfLineStatus::Invalid {
+                    parent_id: Some(feature.parent_id),
+                    error,
+                };
+            }
+            parent_ids.push(feature.parent_id);
+        }
+        GxfLineStatus::Aggregated { parent_ids }
+    }
+
+    /// Parses a feature line and resolves the transcripts it belongs to.
+    ///
+    /// Child rows listing several parents yield one feature per parent.
+    /// Returns an empty list for rows filtered out by the child feature list
+    /// or lacking the grouping attribute. Does not touch aggregation state, so
+    /// lines can be classified concurrently.
+    fn classify_line(&self, line: &str, line_number: usize) -> ReaderResult<Vec<GxfFeature>> {
+        let mut record = GxfRecord::parse(
+            line,
+            line_number,
+            F::ATTR_SEPARATOR,
+            &self.multi_value,
+            self.decode_percent,
+        )?;
+
+        let is_parent = eq_ignore_ascii(&record.feature, &self.parent_feature);
+        if !is_parent {
+            let extra = self
+                .extra_features
+                .iter()
+                .find(|(feature, _)| eq_ignore_ascii(&record.feature, feature));
+            if let Some((_, kind)) = extra {
+                record.feature = kind.feature_name().to_vec();
+            } else if let Some(features) = &self.child_features {
                 if !features
                     .iter()
                     .any(|feature| eq_ignore_ascii(&record.feature, feature))
                 {
-                    return GxfLineStatus::Skipped;
+                    return Ok(Vec::new());
                 }
             }
         }
 
-        let attribute_key = if is_parent_feature {
+        let attribute_key = if is_parent {
             &self.parent_attr
         } else {
             &self.child_attr
         };
-        let Some(parent_value) = record
-            .attributes
-            .get(attribute_key.as_slice())
-            .and_then(ExtraValue::first)
-        else {
-            return GxfLineStatus::Skipped;
+        let mut parent_ids: Vec<Vec<u8>> = match record.attributes.get(attribute_key.as_slice()) {
+            Some(value) if is_parent => value.first().map(<[u8]>::to_vec).into_iter().collect(),
+            Some(value) => value.iter().map(<[u8]>::to_vec).collect(),
+            None => Vec::new(),
         };
-        let parent_id = parent_value.to_vec();
-
-        let entry = self
-            .transcripts
-            .entry(parent_id.clone())
-            .or_insert_with(|| TranscriptBuilder::new(&record));
 
-        if let Err(error) = entry.update_bounds(
-            &record.chrom,
-            record.strand,
-            record.start,
-            record.end,
-            line_number,
-        ) {
-            return GxfLineStatus::Invalid {
-                parent_id: Some(parent_id),
-                error,
-            };
+        // A child shared by several transcripts (`Parent=tx1,tx2`) is
+        // attached to each of them, naming only that parent.
+        if parent_ids.len() > 1 {
+            return Ok(parent_ids
+                .into_iter()
+                .map(|parent_id| {
+                    let mut record = record.clone();
+                    record
+                        .attributes
+                        .insert(attribute_key.clone(), ExtraValue::Scalar(parent_id.clone()));
+                    GxfFeature {
+                        parent_id,
+                        record,
+                        is_parent,
+                    }
+                })
+                .collect());
         }
+        Ok(parent_ids
+            .pop()
+            .map(|parent_id| GxfFeature {
+                parent_id,
+                record,
+                is_parent,
+            })
+            .into_iter()
+            .collect())
+    }
 
-        entry.absorb_feature(&record.feature, record.start, record.end, is_parent_feature);
-        entry.merge_attributes(&record.attributes);
-        entry.update_name(&record.attributes, &parent_id);
-        GxfLineStatus::Aggregated { parent_id }
+    /// Removes a single open transcript and converts it into a `GenePred`.
+    ///
+    /// Returns `Ok(None)` when the parent ID is unknown or the transcript is
+    /// dropped by the configured [`ExonlessPolicy`].
+    pub(crate) fn finish_transcript(&mut self, parent_id: &[u8]) -> ReaderResult<Option<GenePred>> {
+        match self.transcripts.remove(parent_id) {
+            Some(builder) => {
+                builder.into_genepred(parent_id.to_vec(), self.exonless, self.derive_exons)
+            }
+            None => Ok(None),
+        }
     }
 
     /// Consumes the aggregator and returns `(parent_id, GenePred)` records.
-    pub(crate) fn into_genepreds(self) -> Vec<(Vec<u8>, GenePred)> {
+    ///
+    /// Transcripts without exon rows are handled according to the configured
+    /// [`ExonlessPolicy`].
+    pub(crate) fn into_genepreds(self) -> ReaderResult<Vec<(Vec<u8>, GenePred)>> {
         let mut genes = Vec::with_capacity(self.transcripts.len());
         for (name, builder) in self.transcripts {
-            let gene = builder.into_genepred(name.clone());
-            genes.push((name, gene));
+            if let Some(gene) =
+                builder.into_genepred(name.clone(), self.exonless, self.derive_exons)?
+            {
+                genes.push((name, gene));
+            }
         }
-        genes
+        Ok(genes)
     }
 }
 
+/// Returns the attribute naming a child row itself, which is not merged.
+///
+/// A child's own identifier (e.g. the shared `ID` of a discontinuous CDS)
+/// names that feature, not the transcript. Formats grouping children by the
+/// parent attribute itself (GTF `transcript_id`) have no such key.
+fn own_id_key<'a>(parent_attr: &'a [u8], child_attr: &[u8], is_parent: bool) -> Option<&'a [u8]> {
+    (!is_parent && parent_attr != child_attr).then_some(parent_attr)
+}
+
+/// A parsed feature row together with the transcript it belongs to.
+#[derive(Debug, Clone)]
+struct GxfFeature {
+    /// Value of the grouping attribute.
+    parent_id: Vec<u8>,
+    /// The parsed row.
+    record: GxfRecord,
+    /// Whether the row is the transcript's parent feature.
+    is_parent: bool,
+}
+
 /// Parsed record from a GXF (GTF/GFF) file.
 #[derive(Debug, Clone)]
 struct GxfRecord {
@@ -431,6 +1288,10 @@ struct GxfRecord {
     end: u64,
     /// Strand orientation.
     strand: Strand,
+    /// Feature score, or `None` for `.`.
+    score: Option<u16>,
+    /// Reading frame phase (0-2), or `None` for `.`.
+    phase: Option<u8>,
     /// Attribute key-value pairs.
     attributes: Extras,
 }
@@ -443,12 +1304,20 @@ impl GxfRecord {
     /// * `line` - The raw line from the GXF file.
     /// * `line_number` - The 1-based line number for error reporting.
     /// * `sep` - The attribute separator character (e.g., `b' '` for GTF, `b'='` for GFF).
+    /// * `multi_value` - Attributes whose values are split on commas into arrays.
+    /// * `decode_percent` - Whether `%XX` escapes in values are decoded.
     ///
     /// # Returns
     ///
     /// A `ReaderResult` containing the parsed `GxfRecord`, or a `ReaderError`
     /// if the line could not be parsed.
-    fn parse(line: &str, line_number: usize, sep: u8) -> ReaderResult<Self> {
+    fn parse(
+        line: &str,
+        line_number: usize,
+        sep: u8,
+        multi_value: &[Vec<u8>],
+        decode_percent: bool,
+    ) -> ReaderResult<Self> {
         let trimmed = line.trim_end_matches(['\n', '\r']);
         let mut fields = trimmed.split('\t');
 
@@ -467,11 +1336,11 @@ impl GxfRecord {
             .to_vec();
         let start_raw = fields.next().ok_or_else(|| missing("start", line_number))?;
         let end_raw = fields.next().ok_or_else(|| missing("end", line_number))?;
-        let _score = fields.next().ok_or_else(|| missing("score", line_number))?;
+        let score_raw = fields.next().ok_or_else(|| missing("score", line_number))?;
         let strand_raw = fields
             .next()
             .ok_or_else(|| missing("strand", line_number))?;
-        let _phase = fields.next().ok_or_else(|| missing("phase", line_number))?;
+        let phase_raw = fields.next().ok_or_else(|| missing("phase", line_number))?;
         let attributes_raw = fields
             .next()
             .ok_or_else(|| missing("attributes", line_number))?;
@@ -498,10 +1367,30 @@ impl GxfRecord {
             ));
         }
 
+        let score = parse_score(score_raw, line_number)?;
+
+        let phase = match phase_raw {
+            "." => None,
+            "0" => Some(0),
+            "1" => Some(1),
+            "2" => Some(2),
+            _ => {
+                return Err(ReaderError::invalid_field(
+                    line_number,
+                    "phase",
+                    format!("ERROR: phase must be 0, 1, 2 or '.', found '{phase_raw}'"),
+                ))
+            }
+        };
+
         let strand = Strand::parse(strand_raw, line_number)?;
-        let attributes = parse_attributes(attributes_raw.as_bytes(), sep).map_err(|err| {
+        let mut attributes = parse_attributes(attributes_raw.as_bytes(), sep).map_err(|err| {
             ReaderError::invalid_field(line_number, "attributes", err.to_string())
         })?;
+        split_multi_value_attributes(&mut attributes, multi_value);
+        if decode_percent {
+            decode_percent_attributes(&mut attributes);
+        }
 
         Ok(Self {
             chrom,
@@ -509,11 +1398,36 @@ impl GxfRecord {
             start: start.saturating_sub(1),
             end,
             strand,
+            score,
+            phase,
             attributes,
         })
     }
 }
 
+/// Parses a GXF score column into a BED-range score.
+///
+/// `.` maps to `None`. Fractional scores are rounded and values outside
+/// `0..=u16::MAX` are clamped, since `GenePred` stores BED integer scores.
+fn parse_score(raw: &str, line_number: usize) -> ReaderResult<Option<u16>> {
+    if raw == "." {
+        return Ok(None);
+    }
+    if let Ok(score) = raw.parse::<u16>() {
+        return Ok(Some(score));
+    }
+    match raw.parse::<f64>() {
+        Ok(score) if score.is_finite() => {
+            Ok(Some(score.round().clamp(0.0, u16::MAX as f64) as u16))
+        }
+        _ => Err(ReaderError::invalid_field(
+            line_number,
+            "score",
+            format!("ERROR: score must be numeric or '.', found '{raw}'"),
+        )),
+    }
+}
+
 /// Returns a `ReaderError` for a missing field.
 fn missing(field: &'static str, line: usize) -> ReaderError {
     ReaderError::invalid_field(
@@ -540,19 +1454,32 @@ struct TranscriptBuilder {
     exons: Vec<Interval>,
     /// CDS intervals.
     cds: Vec<Interval>,
+    /// CDS `(start, phase)` pairs; phase is `None` when the row had `.`.
+    cds_phases: Vec<(u64, Option<u8>)>,
     /// Start codon intervals.
     start_codons: Vec<Interval>,
     /// Stop codon intervals.
     stop_codons: Vec<Interval>,
+    /// UTR intervals.
+    utrs: Vec<Interval>,
+    /// Score of the parent feature row.
+    parent_score: Option<u16>,
+    /// Highest score among exon rows.
+    exon_score: Option<u16>,
     /// Aggregated attributes.
     extras: Extras,
     /// Transcript name.
     name: Option<Vec<u8>>,
+    /// First source line that contributed to this transcript.
+    first_line: usize,
+    /// Set when a conflicting row under [`ConflictPolicy::Skip`] drops the
+    /// transcript.
+    dropped: bool,
 }
 
 impl TranscriptBuilder {
     /// Creates a new `TranscriptBuilder` from the first `GxfRecord` for a transcript.
-    fn new(record: &GxfRecord) -> Self {
+    fn new(record: &GxfRecord, line_number: usize) -> Self {
         Self {
             chrom: record.chrom.clone(),
             strand: record.strand,
@@ -561,56 +1488,129 @@ impl TranscriptBuilder {
             observed_end: record.end,
             exons: Vec::new(),
             cds: Vec::new(),
+            cds_phases: Vec::new(),
             start_codons: Vec::new(),
             stop_codons: Vec::new(),
+            utrs: Vec::new(),
+            parent_score: None,
+            exon_score: None,
             extras: Extras::new(),
             name: None,
+            first_line: line_number,
+            dropped: false,
         }
     }
 
     /// Updates the bounds of the transcript based on a new `GxfRecord`.
     ///
     /// Ensures that all records for a single transcript are on the same chromosome
-    /// and strand.
+    /// and strand, resolving disagreements with `conflict`. Returns `false` when
+    /// the row should not be absorbed.
     fn update_bounds(
         &mut self,
         chrom: &[u8],
         strand: Strand,
         start: u64,
         end: u64,
+        conflict: ConflictPolicy,
         line: usize,
-    ) -> ReaderResult<()> {
+    ) -> ReaderResult<bool> {
         if self.chrom != chrom {
-            return Err(ReaderError::invalid_field(
-                line,
-                "chrom",
-                format!(
-                    "ERROR: grouped records span multiple chromosomes ({} vs {})",
-                    String::from_utf8_lossy(&self.chrom),
-                    String::from_utf8_lossy(chrom)
-                ),
-            ));
+            match conflict {
+                ConflictPolicy::Error => {
+                    return Err(ReaderError::invalid_field(
+                        line,
+                        "chrom",
+                        format!(
+                            "ERROR: grouped records span multiple chromosomes ({} vs {})",
+                            String::from_utf8_lossy(&self.chrom),
+                            String::from_utf8_lossy(chrom)
+                        ),
+                    ))
+                }
+                ConflictPolicy::Skip => self.dropped = true,
+                ConflictPolicy::SetUnknown => {}
+            }
+            return Ok(false);
         }
 
         if self.strand != strand {
-            return Err(ReaderError::invalid_field(
-                line,
-                "strand",
-                "ERROR: grouped records span multiple strands".into(),
-            ));
+            match conflict {
+                ConflictPolicy::Error => {
+                    return Err(ReaderError::invalid_field(
+                        line,
+                        "strand",
+                        "ERROR: grouped records span multiple strands".into(),
+                    ))
+                }
+                ConflictPolicy::Skip => self.dropped = true,
+                ConflictPolicy::SetUnknown => self.strand = Strand::Unknown,
+            }
         }
 
         self.observed_start = self.observed_start.min(start);
         self.observed_end = self.observed_end.max(end);
+        Ok(true)
+    }
+
+    /// Adds one classified row to the transcript.
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - The classified row.
+    /// * `own_id` - Attribute naming the row itself, left out of the merge.
+    /// * `name_attributes` - Attributes tried, in order, to name the transcript.
+    /// * `conflict` - Behaviour when the row disagrees on chromosome or strand.
+    /// * `line_number` - One-based source line number.
+    fn absorb_line(
+        &mut self,
+        feature: &GxfFeature,
+        own_id: Option<&[u8]>,
+        name_attributes: &[Vec<u8>],
+        conflict: ConflictPolicy,
+        line_number: usize,
+    ) -> ReaderResult<()> {
+        let record = &feature.record;
+        if !self.update_bounds(
+            &record.chrom,
+            record.strand,
+            record.start,
+            record.end,
+            conflict,
+            line_number,
+        )? {
+            return Ok(());
+        }
+        self.absorb_feature(
+            &record.feature,
+            record.start,
+            record.end,
+            record.score,
+            record.phase,
+            feature.is_parent,
+        );
+        self.merge_attributes(&record.attributes, own_id);
+        self.update_name(&record.attributes, name_attributes, &feature.parent_id);
         Ok(())
     }
 
     /// Absorbs a feature from a `GxfRecord` into the builder.
     ///
     /// This method categorizes features like "exon", "cds", "start_codon",
-    /// and "stop_codon" and stores their intervals.
-    fn absorb_feature(&mut self, feature: &[u8], start: u64, end: u64, is_parent: bool) {
+    /// and "stop_codon" and stores their intervals. CDS phases are kept
+    /// alongside their interval; parent and exon scores are tracked for the
+    /// transcript score.
+    fn absorb_feature(
+        &mut self,
+        feature: &[u8],
+        start: u64,
+        end: u64,
+        score: Option<u16>,
+        phase: Option<u8>,
+        is_parent: bool,
+    ) {
         if is_parent {
+            self.parent_score = self.parent_score.max(score);
             self.transcript_extent = Some(match self.transcript_extent {
                 Some((current_start, current_end)) => {
                     (current_start.min(start), current_end.max(end))
@@ -623,20 +1623,28 @@ impl TranscriptBuilder {
         let interval = Interval { start, end };
         if eq_ignore_ascii(feature, b"exon") {
             self.exons.push(interval);
+            self.exon_score = self.exon_score.max(score);
         } else if eq_ignore_ascii(feature, b"cds") {
             self.cds.push(interval);
+            self.cds_phases.push((start, phase));
         } else if eq_ignore_ascii(feature, b"start_codon") {
             self.start_codons.push(interval);
         } else if eq_ignore_ascii(feature, b"stop_codon") {
             self.stop_codons.push(interval);
+        } else if is_utr_feature(feature) {
+            self.utrs.push(interval);
         }
     }
 
     /// Merges attributes from a `GxfRecord` into the builder's `Extras`.
     ///
     /// If a key already exists, the new values are appended to the existing ones.
-    fn merge_attributes(&mut self, attributes: &Extras) {
+    /// The `skip` key, when given, is left out entirely.
+    fn merge_attributes(&mut self, attributes: &Extras, skip: Option<&[u8]>) {
         for (key, value) in attributes {
+            if skip == Some(key.as_slice()) {
+                continue;
+            }
             match self.extras.entry(key.clone()) {
                 Entry::Vacant(slot) => {
                     slot.insert(value.clone());
@@ -653,19 +1661,18 @@ impl TranscriptBuilder {
 
     /// Updates the name of the transcript, preferring specific attributes.
     ///
-    /// It looks for "transcript_name", "Name", or "gene_name" in the attributes,
-    /// falling back to a provided `fallback` name if none are found.
-    fn update_name(&mut self, attributes: &Extras, fallback: &[u8]) {
+    /// It looks for each of `candidates` in order (by default
+    /// [`DEFAULT_NAME_ATTRIBUTES`]), falling back to a provided `fallback`
+    /// name if none are found.
+    fn update_name(&mut self, attributes: &Extras, candidates: &[Vec<u8>], fallback: &[u8]) {
         if self.name.is_some() {
             return;
         }
-        for candidate in [
-            b"transcript_name".as_ref(),
-            b"Name".as_ref(),
-            b"gene_name".as_ref(),
-            b"transcript_id".as_ref(),
-        ] {
-            if let Some(value) = attributes.get(candidate).and_then(ExtraValue::first) {
+        for candidate in candidates {
+            if let Some(value) = attributes
+                .get(candidate.as_slice())
+                .and_then(ExtraValue::first)
+            {
                 self.name = Some(value.to_vec());
                 return;
             }
@@ -678,15 +1685,65 @@ impl TranscriptBuilder {
     /// Consumes the builder and produces a `GenePred` record.
     ///
     /// This method aggregates all collected information (exons, CDS, attributes)
-    /// into a final `GenePred` structure.
-    fn into_genepred(mut self, parent_name: Vec<u8>) -> GenePred {
+    /// into a final `GenePred` structure. Returns `Ok(None)` when the transcript
+    /// has no exons and `exonless` is [`ExonlessPolicy::Skip`], or when a
+    /// conflicting row dropped it under [`ConflictPolicy::Skip`]. With
+    /// `derive_exons`, a transcript without exon rows first gets exons from the
+    /// union of its CDS, codon and UTR rows; without it, only a transcript
+    /// with neither exon nor CDS rows takes its exons from its UTR rows.
+    fn into_genepred(
+        mut self,
+        parent_name: Vec<u8>,
+        exonless: ExonlessPolicy,
+        derive_exons: bool,
+    ) -> ReaderResult<Option<GenePred>> {
         let (span_start, span_end) = self
             .transcript_extent
             .unwrap_or((self.observed_start, self.observed_end));
 
+        if self.dropped {
+            return Ok(None);
+        }
+
+        if self.exons.is_empty() && derive_exons {
+            self.exons = merge_intervals(
+                self.cds
+                    .iter()
+                    .chain(&self.utrs)
+                    .chain(&self.start_codons)
+                    .chain(&self.stop_codons)
+                    .copied()
+                    .collect(),
+            );
+        }
+
+        // UTRs are wholly exonic, so a transcript built only from UTR rows
+        // takes its exons from them instead of a span-wide synthetic exon.
+        if self.exons.is_empty() && self.cds.is_empty() {
+            self.exons = merge_intervals(self.utrs.clone());
+        }
+
+        if self.exons.is_empty() {
+            match exonless {
+                ExonlessPolicy::Synthesize => {}
+                ExonlessPolicy::Skip => return Ok(None),
+                ExonlessPolicy::Error => {
+                    return Err(ReaderError::invalid_field(
+                        self.first_line,
+                        "exon",
+                        format!(
+                            "ERROR: transcript '{}' has no exon features",
+                            String::from_utf8_lossy(&parent_name)
+                        ),
+                    ))
+                }
+            }
+        }
+
         let mut gene = GenePred::from_coords(self.chrom, span_start, span_end, self.extras);
         gene.set_name(self.name.or(Some(parent_name)));
         gene.set_strand(Some(self.strand));
+        gene.set_score(self.parent_score.or(self.exon_score));
 
         if self.exons.is_empty() {
             self.exons.push(Interval {
@@ -714,6 +1771,16 @@ impl TranscriptBuilder {
             let cds_start = self.cds.first().map(|interval| interval.start).unwrap();
             let cds_end = self.cds.last().map(|interval| interval.end).unwrap();
             coding_bounds = Some((cds_start, cds_end));
+
+            if self.cds_phases.iter().all(|(_, phase)| phase.is_some()) {
+                self.cds_phases.sort_by_key(|(start, _)| *start);
+                gene.set_cds_phases(Some(
+                    self.cds_phases
+                        .iter()
+                        .filter_map(|(_, phase)| *phase)
+                        .collect(),
+                ));
+            }
         }
 
         if !(self.start_codons.is_empty() && self.stop_codons.is_empty()) {
@@ -749,7 +1816,148 @@ impl TranscriptBuilder {
             }
         }
 
-        gene
+        Ok(Some(gene))
+    }
+}
+
+/// Attributes consulted, in order, for a transcript's gene identifier.
+const GENE_KEY_ATTRIBUTES: &[&[u8]] = &[b"gene_id", b"gene", b"Parent"];
+
+/// Returns the `(chrom, gene identifier)` grouping key of a transcript.
+fn gene_key(record: &GenePred) -> Option<(Vec<u8>, Vec<u8>)> {
+    let extras = record.extras();
+    let id = GENE_KEY_ATTRIBUTES
+        .iter()
+        .find_map(|key| match extras.get(*key)? {
+            ExtraValue::Scalar(value) => Some(value.clone()),
+            ExtraValue::Array(values) => values.first().cloned(),
+        })?;
+    Some((record.chrom().to_vec(), id))
+}
+
+/// Merges transcripts that share a gene into one record per gene.
+///
+/// Genes keep the order of their first transcript. Transcripts without a
+/// gene identifier are passed through unchanged.
+fn merge_by_gene(records: Vec<GenePred>) -> Vec<GenePred> {
+    let mut groups: Vec<Vec<GenePred>> = Vec::new();
+    let mut index: HashMap<(Vec<u8>, Vec<u8>), usize> = HashMap::new();
+    for record in records {
+        match gene_key(&record) {
+            Some(key) => match index.entry(key) {
+                Entry::Occupied(entry) => groups[*entry.get()].push(record),
+                Entry::Vacant(entry) => {
+                    entry.insert(groups.len());
+                    groups.push(vec![record]);
+                }
+            },
+            None => groups.push(vec![record]),
+        }
+    }
+    groups.into_iter().map(merge_gene).collect()
+}
+
+/// Collapses the transcripts of one gene into a single `GenePred`.
+///
+/// Blocks are the union of all exons with overlapping or touching blocks
+/// coalesced, thick bounds span every coding transcript, and only extras
+/// shared verbatim by all transcripts are kept.
+fn merge_gene(mut transcripts: Vec<GenePred>) -> GenePred {
+    if transcripts.len() == 1 {
+        return transcripts.pop().unwrap();
+    }
+
+    let first = &transcripts[0];
+    let name = gene_key(first).map(|(_, id)| id);
+    let mut extras = first.extras().clone();
+    extras.retain(|key, value| {
+        transcripts[1..]
+            .iter()
+            .all(|other| other.extras().get(key) == Some(value))
+    });
+
+    let start = transcripts.iter().map(GenePred::start).min().unwrap();
+    let end = transcripts.iter().map(GenePred::end).max().unwrap();
+    let exons = merge_intervals(
+        transcripts
+            .iter()
+            .flat_map(|transcript| transcript.exons())
+            .map(|(start, end)| Interval { start, end })
+            .collect(),
+    );
+    let thick = transcripts
+        .iter()
+        .filter_map(|transcript| Some((transcript.thick_start()?, transcript.thick_end()?)))
+        .filter(|(start, end)| start < end)
+        .reduce(|(s1, e1), (s2, e2)| (s1.min(s2), e1.max(e2)));
+
+    let mut gene = GenePred::from_coords(first.chrom().to_vec(), start, end, extras);
+    gene.set_name(name.or_else(|| first.name().map(<[u8]>::to_vec)));
+    gene.set_strand(first.strand());
+    gene.set_block_count(Some(exons.len() as u32));
+    gene.set_block_starts(Some(exons.iter().map(|exon| exon.start).collect()));
+    gene.set_block_ends(Some(exons.iter().map(|exon| exon.end).collect()));
+    if let Some((thick_start, thick_end)) = thick {
+        gene.set_thick_start(Some(thick_start));
+        gene.set_thick_end(Some(thick_end));
+    }
+    gene
+}
+
+/// Merges consecutive streamed transcripts of the same gene.
+///
+/// Used for [`AggregationLevel::Gene`] in streaming mode, where only
+/// adjacent transcripts can be combined.
+pub(crate) struct GeneMergeStream<I> {
+    /// Transcript-level records.
+    inner: I,
+    /// Transcripts of the gene currently being collected.
+    pending: Vec<GenePred>,
+    /// Grouping key of `pending`.
+    pending_key: Option<(Vec<u8>, Vec<u8>)>,
+}
+
+impl<I: Iterator<Item = ReaderResult<GenePred>>> GeneMergeStream<I> {
+    /// Wraps a transcript-level record stream.
+    pub(crate) fn new(inner: I) -> Self {
+        Self {
+            inner,
+            pending: Vec::new(),
+            pending_key: None,
+        }
+    }
+
+    /// Emits the collected gene, if any.
+    fn flush(&mut self) -> Option<GenePred> {
+        self.pending_key = None;
+        (!self.pending.is_empty()).then(|| merge_gene(std::mem::take(&mut self.pending)))
+    }
+}
+
+impl<I: Iterator<Item = ReaderResult<GenePred>>> Iterator for GeneMergeStream<I> {
+    type Item = ReaderResult<GenePred>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let record = match self.inner.next() {
+                Some(Ok(record)) => record,
+                Some(Err(err)) => return Some(Err(err)),
+                None => return self.flush().map(Ok),
+            };
+
+            let key = gene_key(&record);
+            if key.is_some() && key == self.pending_key {
+                self.pending.push(record);
+                continue;
+            }
+
+            let done = self.flush();
+            self.pending.push(record);
+            self.pending_key = key;
+            if let Some(gene) = done {
+                return Some(Ok(gene));
+            }
+        }
     }
 }
 
@@ -762,6 +1970,35 @@ struct Interval {
     end: u64,
 }
 
+/// Returns `true` for UTR feature names (`UTR`, `five_prime_utr`, `3UTR`, ...).
+fn is_utr_feature(feature: &[u8]) -> bool {
+    [
+        b"utr".as_ref(),
+        b"five_prime_utr",
+        b"three_prime_utr",
+        b"5utr",
+        b"3utr",
+    ]
+    .iter()
+    .any(|name| eq_ignore_ascii(feature, name))
+}
+
+/// Sorts intervals and merges those that overlap or touch.
+///
+/// Touching half-open intervals (e.g. a CDS ending where its UTR begins)
+/// belong to the same exon and are merged.
+fn merge_intervals(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort_by_key(|interval| (interval.start, interval.end));
+    let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+            _ => merged.push(interval),
+        }
+    }
+    merged
+}
+
 /// Fast equality check that ignores ASCII case.
 ///
 /// This function compares two byte slices and returns `true` if they are of
@@ -867,16 +2104,9 @@ pub fn parse_attributes(line: &[u8], sep: u8) -> Result<Extras, ParseError> {
         let value;
         if line[pos] == b'"' {
             pos += 1;
-            match memchr(b'"', &line[pos..trimmed_len]) {
-                Some(close) => {
-                    value = line[pos..pos + close].to_vec();
-                    pos = pos + close + 1;
-                }
-                None => {
-                    value = line[pos..trimmed_len].to_vec();
-                    pos = trimmed_len;
-                }
-            }
+            let (quoted, consumed) = scan_quoted(&line[pos..trimmed_len]);
+            value = quoted;
+            pos += consumed;
         } else {
             match memchr(b';', &line[pos..trimmed_len]) {
                 Some(semi) => {
@@ -904,6 +2134,205 @@ pub fn parse_attributes(line: &[u8], sep: u8) -> Result<Extras, ParseError> {
     Ok(attributes)
 }
 
+/// Reads a quoted attribute value up to its closing quote.
+///
+/// `bytes` starts just after the opening quote. Semicolons inside the quotes
+/// belong to the value, and a backslash escapes the following byte, so `\"`
+/// yields a literal quote. Returns the unescaped value and the number of
+/// bytes consumed, including the closing quote; an unterminated value runs to
+/// the end of `bytes`.
+fn scan_quoted(bytes: &[u8]) -> (Vec<u8>, usize) {
+    match memchr2(b'"', b'\\', bytes) {
+        Some(close) if bytes[close] == b'"' => return (bytes[..close].to_vec(), close + 1),
+        None => return (bytes.to_vec(), bytes.len()),
+        Some(_) => {}
+    }
+
+    let mut value = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'"' => return (value, idx + 1),
+            b'\\' if idx + 1 < bytes.len() => {
+                value.push(bytes[idx + 1]);
+                idx += 2;
+            }
+            byte => {
+                value.push(byte);
+                idx += 1;
+            }
+        }
+    }
+    (value, bytes.len())
+}
+
+/// Appends `value` as a GTF quoted string, escaping quotes and backslashes.
+pub(crate) fn push_quoted(buf: &mut Vec<u8>, value: &[u8]) {
+    buf.push(b'"');
+    for &byte in value {
+        if matches!(byte, b'"' | b'\\') {
+            buf.push(b'\\');
+        }
+        buf.push(byte);
+    }
+    buf.push(b'"');
+}
+
+/// Renders an `Extras` map back into a GTF/GFF attribute column.
+///
+/// The inverse of [`parse_attributes`]: with a space separator, values are
+/// quoted GTF-style (escaping embedded quotes) and [`ExtraValue::Array`] entries repeat their key; with
+/// `=`, pairs are written GFF-style as `key=value` and arrays are joined by
+/// commas. Keys are emitted in sorted order so the output is deterministic.
+///
+/// A repeated GFF key that was parsed into an array re-parses as a single
+/// comma-joined value; use [`split_multi_value_attributes`] to recover it.
+///
+/// # Arguments
+///
+/// * `attributes` - The attribute map to render.
+/// * `sep` - The delimiter between key and value (space for GTF, '=' for GFF).
+///
+/// # Examples
+///
+/// ```
+/// use genepred::gxf::{parse_attributes, render_attributes};
+///
+/// let attrs = parse_attributes(b"gene_id \"g1\"; tag \"basic\"; tag \"CCDS\";", b' ').unwrap();
+/// assert_eq!(
+///     render_attributes(&attrs, b' '),
+///     b"gene_id \"g1\"; tag \"basic\"; tag \"CCDS\";".to_vec()
+/// );
+///
+/// let attrs = parse_attributes(b"ID=tx1;Name=Example", b'=').unwrap();
+/// assert_eq!(render_attributes(&attrs, b'='), b"ID=tx1;Name=Example;".to_vec());
+/// ```
+pub fn render_attributes(attributes: &Extras, sep: u8) -> Vec<u8> {
+    let mut pairs: Vec<_> = attributes.iter().collect();
+    pairs.sort_unstable_by(|lhs, rhs| lhs.0.cmp(rhs.0));
+
+    let mut buf = Vec::with_capacity(64);
+    for (key, value) in pairs {
+        if sep == b'=' {
+            buf.extend_from_slice(key);
+            buf.push(b'=');
+            for (idx, item) in value.iter().enumerate() {
+                if idx > 0 {
+                    buf.push(b',');
+                }
+                buf.extend_from_slice(item);
+            }
+            buf.push(b';');
+            continue;
+        }
+        for item in value.iter() {
+            if !buf.is_empty() {
+                buf.push(b' ');
+            }
+            buf.extend_from_slice(key);
+            buf.push(sep);
+            push_quoted(&mut buf, item);
+            buf.push(b';');
+        }
+    }
+    buf
+}
+
+/// Splits comma-separated values of the given attributes into arrays.
+///
+/// Every listed key that is present becomes an [`ExtraValue::Array`], even
+/// when it holds a single value; empty list items are dropped.
+///
+/// # Arguments
+///
+/// * `attributes` - Parsed attribute map to update in place.
+/// * `keys` - Attribute names to split.
+///
+/// # Examples
+///
+/// ```
+/// use genepred::gxf::{parse_attributes, split_multi_value_attributes};
+/// use genepred::genepred::ExtraValue;
+///
+/// let mut attrs = parse_attributes(b"ID=g1;Dbxref=GeneID:1,HGNC:2", b'=').unwrap();
+/// split_multi_value_attributes(&mut attrs, &[b"Dbxref".to_vec()]);
+/// assert_eq!(
+///     attrs.get(b"Dbxref".as_ref()),
+///     Some(&ExtraValue::Array(vec![b"GeneID:1".to_vec(), b"HGNC:2".to_vec()]))
+/// );
+/// ```
+pub fn split_multi_value_attributes(attributes: &mut Extras, keys: &[Vec<u8>]) {
+    for key in keys {
+        let Some(value) = attributes.get_mut(key.as_slice()) else {
+            continue;
+        };
+        let mut items = Vec::new();
+        for raw in value.iter() {
+            for item in raw.split(|byte| *byte == b',') {
+                if !item.is_empty() && !items.iter().any(|seen: &Vec<u8>| seen == item) {
+                    items.push(item.to_vec());
+                }
+            }
+        }
+        *value = ExtraValue::Array(items);
+    }
+}
+
+/// Decodes GFF3 percent-encoded characters in every attribute value.
+///
+/// Each `%XX` hex escape becomes the byte it encodes, so `%3B` yields `;` and
+/// `%2C` yields `,`. Malformed escapes are kept verbatim. Split list values
+/// with [`split_multi_value_attributes`] first, since encoded commas are not
+/// separators.
+///
+/// # Arguments
+///
+/// * `attributes` - Parsed attribute map to update in place.
+///
+/// # Examples
+///
+/// ```
+/// use genepred::gxf::{decode_percent_attributes, parse_attributes};
+/// use genepred::genepred::ExtraValue;
+///
+/// let mut attrs = parse_attributes(b"ID=g1;Note=a%3B b%2C c%ZZ", b'=').unwrap();
+/// decode_percent_attributes(&mut attrs);
+/// assert_eq!(
+///     attrs.get(b"Note".as_ref()),
+///     Some(&ExtraValue::Scalar(b"a; b, c%ZZ".to_vec()))
+/// );
+/// ```
+pub fn decode_percent_attributes(attributes: &mut Extras) {
+    for value in attributes.values_mut() {
+        match value {
+            ExtraValue::Scalar(item) => percent_decode(item),
+            ExtraValue::Array(items) => items.iter_mut().for_each(percent_decode),
+        }
+    }
+}
+
+/// Decodes `%XX` escapes in place.
+fn percent_decode(value: &mut Vec<u8>) {
+    if memchr(b'%', value).is_none() {
+        return;
+    }
+    let hex = |byte: u8| (byte as char).to_digit(16).map(|digit| digit as u8);
+    let mut decoded = Vec::with_capacity(value.len());
+    let mut idx = 0;
+    while idx < value.len() {
+        if value[idx] == b'%' && idx + 2 < value.len() {
+            if let (Some(high), Some(low)) = (hex(value[idx + 1]), hex(value[idx + 2])) {
+                decoded.push(high << 4 | low);
+                idx += 3;
+                continue;
+            }
+        }
+        decoded.push(value[idx]);
+        idx += 1;
+    }
+    *value = decoded;
+}
+
 /// Pushes an attribute key-value pair into the `Extras` map.
 ///
 /// If the key already exists, the value is appended to the existing `ExtraValue`.
@@ -935,6 +2364,45 @@ impl fmt::Display for ParseError {
 
 impl std::error::Error for ParseError {}
 
+/// Returns `true` if `line` opens the sequence section of a GFF3 file.
+///
+/// Feature lines end at a `##FASTA` directive or, when it is missing, at the
+/// first FASTA header.
+fn starts_fasta(line: &str) -> bool {
+    line.starts_with("##FASTA") || line.starts_with('>')
+}
+
+/// Collects FASTA records from the remainder of `reader`.
+///
+/// # Arguments
+///
+/// * `reader` - The reader positioned after `first_line`.
+/// * `first_line` - The line that opened the section; parsed if it is a header.
+/// * `sequences` - Destination for the parsed records.
+fn read_fasta<R: BufRead>(
+    reader: &mut R,
+    first_line: &str,
+    sequences: &mut Vec<FastaSequence>,
+) -> ReaderResult<()> {
+    let mut line = first_line.to_string();
+    loop {
+        let trimmed = line.trim();
+        if let Some(header) = trimmed.strip_prefix('>') {
+            sequences.push(FastaSequence {
+                id: header.split_whitespace().next().unwrap_or("").to_string(),
+                sequence: Vec::new(),
+            });
+        } else if let Some(current) = sequences.last_mut() {
+            current.sequence.extend_from_slice(trimmed.as_bytes());
+        }
+
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+    }
+}
+
 /// Determines if a line should be skipped during parsing.
 ///
 /// Lines are skipped if they are empty or start with a '#' character.
@@ -1018,6 +2486,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_gtf_quoted_semicolons_and_escapes() {
+        let raw =
+            br#"gene_id "g1"; note "contains; semicolon"; product "5\" end \\ tail"; tag "basic";"#;
+        let attrs = parse_attributes(raw, b' ').unwrap();
+        assert_eq!(
+            attrs.get(b"note".as_ref()),
+            Some(&ExtraValue::Scalar(b"contains; semicolon".to_vec()))
+        );
+        assert_eq!(
+            attrs.get(b"product".as_ref()),
+            Some(&ExtraValue::Scalar(br#"5" end \ tail"#.to_vec()))
+        );
+        assert_eq!(
+            attrs.get(b"tag".as_ref()),
+            Some(&ExtraValue::Scalar(b"basic".to_vec()))
+        );
+
+        let rendered = render_attributes(&attrs, b' ');
+        assert_eq!(parse_attributes(&rendered, b' ').unwrap(), attrs);
+    }
+
+    #[test]
+    fn render_attributes_round_trips() {
+        let raw =
+            b"gene_id \"g1\"; transcript_id \"t1\"; tag \"basic\"; tag \"CCDS\"; note \"a; b\"";
+        let parsed = parse_attributes(raw, b' ').unwrap();
+        let rendered = render_attributes(&parsed, b' ');
+        assert_eq!(parse_attributes(&rendered, b' ').unwrap(), parsed);
+        assert_eq!(
+            render_attributes(&parse_attributes(&rendered, b' ').unwrap(), b' '),
+            rendered
+        );
+
+        let raw = b"ID=tx1;Parent=g1;Dbxref=GeneID:1,HGNC:2;Name=Example";
+        let parsed = parse_attributes(raw, b'=').unwrap();
+        let rendered = render_attributes(&parsed, b'=');
+        assert_eq!(
+            rendered,
+            b"Dbxref=GeneID:1,HGNC:2;ID=tx1;Name=Example;Parent=g1;".to_vec()
+        );
+        assert_eq!(parse_attributes(&rendered, b'=').unwrap(), parsed);
+
+        let mut split = parsed.clone();
+        split_multi_value_attributes(&mut split, &[b"Dbxref".to_vec()]);
+        let reparsed = parse_attributes(&render_attributes(&split, b'='), b'=').unwrap();
+        assert_eq!(reparsed, parsed);
+    }
+
     #[test]
     fn parse_gff_attributes() {
         let raw = b"ID=tx1;Name=Example;biotype=protein_coding";
@@ -1036,6 +2553,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_gff_multi_value_attributes() {
+        let line = "chr1\tsrc\tmRNA\t1\t10\t.\t+\t.\tID=tx1;Dbxref=GeneID:1,HGNC:2;Note=a,b";
+        let keys: Vec<Vec<u8>> = Gff::MULTI_VALUE_ATTRIBUTES
+            .iter()
+            .map(|key| key.to_vec())
+            .collect();
+        let record = GxfRecord::parse(line, 1, b'=', &keys, false).unwrap();
+        match record.attributes.get(b"Dbxref".as_ref()) {
+            Some(ExtraValue::Array(values)) => {
+                assert_eq!(values, &vec![b"GeneID:1".to_vec(), b"HGNC:2".to_vec()])
+            }
+            other => panic!("unexpected Dbxref entry: {:?}", other),
+        }
+        match record.attributes.get(b"Note".as_ref()) {
+            Some(ExtraValue::Scalar(value)) => assert_eq!(value, b"a,b"),
+            other => panic!("unexpected Note entry: {:?}", other),
+        }
+    }
+
     #[test]
     fn parse_empty_attributes() {
         assert_eq!(parse_attributes(b"", b' '), Err(ParseError::Empty));
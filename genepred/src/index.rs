@@ -0,0 +1,155 @@
+// Copyright (c) 2026 Alejandro Gonzales-Irribarren <alejandrxgzi@gmail.com>
+// Distributed under the terms of the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+
+use crate::genepred::GenePred;
+
+/// Overlap index over a collection of records.
+///
+/// Records are grouped by chromosome and sorted by start, alongside a running
+/// maximum of their ends. A query binary searches both to bound the candidate
+/// run, then filters it, so only records starting after the earliest possible
+/// overlap are scanned.
+///
+/// # Example
+///
+/// ```
+/// use genepred::genepred::{Extras, GenePred};
+/// use genepred::index::IntervalIndex;
+///
+/// let index = IntervalIndex::new(vec![
+///     GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new()),
+///     GenePred::from_coords(b"chr1".to_vec(), 300, 400, Extras::new()),
+/// ]);
+/// assert_eq!(index.query(b"chr1", 150, 350).count(), 2);
+/// assert_eq!(index.query(b"chr1", 200, 300).count(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntervalIndex<T = GenePred> {
+    chroms: HashMap<Vec<u8>, ChromIntervals<T>>,
+}
+
+/// Items on one chromosome, sorted by span, with a running max end.
+#[derive(Debug, Clone)]
+struct ChromIntervals<T> {
+    spans: Vec<(u64, u64)>,
+    items: Vec<T>,
+    max_ends: Vec<u64>,
+}
+
+impl<T> Default for IntervalIndex<T> {
+    fn default() -> Self {
+        Self {
+            chroms: HashMap::new(),
+        }
+    }
+}
+
+impl<T> Default for ChromIntervals<T> {
+    fn default() -> Self {
+        Self {
+            spans: Vec::new(),
+            items: Vec::new(),
+            max_ends: Vec::new(),
+        }
+    }
+}
+
+impl IntervalIndex {
+    /// Builds an index from `records`.
+    pub fn new(records: Vec<GenePred>) -> Self {
+        Self::from_spans(
+            records
+                .into_iter()
+                .map(|record| (record.chrom.clone(), record.start, record.end, record)),
+        )
+    }
+
+    /// Returns the records on `chrom` overlapping the half-open interval
+    /// `[start, end)`, in ascending start order.
+    ///
+    /// Overlap follows [`GenePred::overlaps`], so book-ended features do not
+    /// match.
+    pub fn query<'a>(
+        &'a self,
+        chrom: &[u8],
+        start: u64,
+        end: u64,
+    ) -> impl Iterator<Item = &'a GenePred> + 'a {
+        self.query_spans(chrom, start, end)
+    }
+}
+
+impl<T> IntervalIndex<T> {
+    /// Builds an index over `(chrom, start, end, item)` entries.
+    pub(crate) fn from_spans<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (Vec<u8>, u64, u64, T)>,
+    {
+        let mut grouped: HashMap<Vec<u8>, Vec<(u64, u64, T)>> = HashMap::new();
+        for (chrom, start, end, item) in entries {
+            grouped.entry(chrom).or_default().push((start, end, item));
+        }
+
+        let chroms = grouped
+            .into_iter()
+            .map(|(chrom, mut entries)| {
+                entries.sort_by_key(|&(start, end, _)| (start, end));
+                let mut intervals = ChromIntervals::default();
+                let mut running = 0u64;
+                for (start, end, item) in entries {
+                    running = running.max(end);
+                    intervals.spans.push((start, end));
+                    intervals.items.push(item);
+                    intervals.max_ends.push(running);
+                }
+                (chrom, intervals)
+            })
+            .collect();
+        Self { chroms }
+    }
+
+    /// Returns the items on `chrom` whose span overlaps `[start, end)`, in
+    /// ascending start order.
+    pub(crate) fn query_spans<'a>(
+        &'a self,
+        chrom: &[u8],
+        start: u64,
+        end: u64,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        let (spans, items): (&[(u64, u64)], &[T]) = self
+            .chroms
+            .get(chrom)
+            .map(|entry| {
+                let lo = entry.max_ends.partition_point(|&max_end| max_end <= start);
+                let hi = entry
+                    .spans
+                    .partition_point(|&(span_start, _)| span_start < end);
+                let hi = hi.max(lo);
+                (&entry.spans[lo..hi], &entry.items[lo..hi])
+            })
+            .unwrap_or((&[], &[]));
+        spans
+            .iter()
+            .zip(items)
+            .filter(move |&(&(span_start, span_end), _)| span_start < end && span_end > start)
+            .map(|(_, item)| item)
+    }
+
+    /// Returns the total number of indexed records.
+    pub fn len(&self) -> usize {
+        self.chroms.values().map(|entry| entry.items.len()).sum()
+    }
+
+    /// Returns true if the index holds no records.
+    pub fn is_empty(&self) -> bool {
+        self.chroms.values().all(|entry| entry.items.is_empty())
+    }
+}
+
+impl FromIterator<GenePred> for IntervalIndex {
+    fn from_iter<I: IntoIterator<Item = GenePred>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
@@ -55,6 +55,7 @@
 //! - `mmap`: Enable memory-mapped file support (adds `memmap2` dependency)
 //! - `rayon`: Enable parallel processing (adds `rayon` dependency)
 //! - `gzip`: Enable gzip support (adds `flate2` dependency)
+//! - `bgzf`: Detect BGZF (`bgzip`) inputs behind `.gz` (implies `gzip`)
 //! - `zstd`: Enable zstd support (adds `zstd` dependency)
 //! - `bz2`: Enable bzip2 support (adds `bzip2` dependency)
 
@@ -68,16 +69,34 @@ pub mod cli;
 pub mod genepred;
 /// GTF/GFF reader and format marker types.
 pub mod gxf;
+/// Overlap queries over record collections.
+pub mod index;
 /// Input readers and reader configuration.
 pub mod reader;
+/// Conversion round-trip diagnostics.
+pub mod roundtrip;
+/// Record ordering helpers.
+pub mod sort;
 /// Strand representation and parsing.
 pub mod strand;
+/// UCSC genePred, refFlat and PSL table formats.
+pub mod table;
 /// Output writers and writer configuration.
 pub mod writer;
 
 pub use bed::*;
-pub use genepred::{ExtraValue, Extras, GenePred};
-pub use gxf::{Gff, Gtf};
-pub use reader::{Reader, ReaderBuilder, ReaderMode, ReaderOptions, ReaderResult};
-pub use strand::Strand;
-pub use writer::{Writer, WriterError, WriterOptions, WriterResult};
+pub use genepred::{
+    dedup_by_coords, promoter_overlaps, CoordKey, ExtraValue, Extras, ExtrasExt, GenePred,
+};
+pub use gxf::{
+    AggregationLevel, ConflictPolicy, ExonlessPolicy, FastaSequence, FeatureKind, FeatureSummary,
+    Gff, Gtf, GxfMetadata, ReappearPolicy, SequenceRegion,
+};
+pub use index::IntervalIndex;
+pub use reader::{
+    ChromStyle, ErrorPolicy, Reader, ReaderBuilder, ReaderMode, ReaderOptions, ReaderResult,
+};
+pub use roundtrip::{verify_roundtrip, RoundtripError, RoundtripReport};
+pub use strand::{Strand, StrandParseError};
+pub use table::{GenePredTable, Psl, RefFlat};
+pub use writer::{RecordWriter, WriteState, Writer, WriterError, WriterOptions, WriterResult};
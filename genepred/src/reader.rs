@@ -13,7 +13,6 @@ use std::path::{Path, PathBuf};
 use bzip2::read::BzDecoder;
 #[cfg(feature = "gzip")]
 use flate2::read::MultiGzDecoder;
-#[cfg(any(feature = "rayon", feature = "mmap"))]
 use memchr::memchr;
 #[cfg(feature = "rayon")]
 use memchr::memchr_iter;
@@ -23,15 +22,19 @@ use memmap2::MmapOptions;
 use rayon::iter::ParallelBridge;
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
-#[cfg(any(feature = "mmap", feature = "rayon"))]
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::{Arc, OnceLock};
 #[cfg(feature = "zstd")]
 use zstd::stream::read::Decoder as ZstdDecoder;
 
 use crate::{
-    bed::BedFormat,
+    bed::{__clamp_score, BedFormat},
     genepred::{ExtraValue, Extras, GenePred},
-    gxf::{self, Gff, Gtf, GxfFormat},
+    gxf::{
+        self, AggregationLevel, ConflictPolicy, ExonlessPolicy, FeatureKind, Gff, Gtf, GxfFormat,
+        GxfMetadata, ReappearPolicy,
+    },
+    strand::Strand,
 };
 
 /// Result alias for reader operations.
@@ -42,6 +45,13 @@ pub type ReaderResult<T> = Result<T, ReaderError>;
 pub enum ReaderError {
     /// An I/O error.
     Io(io::Error),
+    /// An input file could not be opened.
+    OpenFailed {
+        /// Path of the file.
+        path: PathBuf,
+        /// The underlying I/O error.
+        source: io::Error,
+    },
     /// An error that occurred when memory-mapping a file.
     #[cfg(feature = "mmap")]
     Mmap(io::Error),
@@ -72,12 +82,39 @@ pub enum ReaderError {
     },
     /// An error that occurred when building a reader.
     Builder(String),
+    /// An error raised by one input of a multi-file reader.
+    InFile {
+        /// Zero-based position of the input in the path list.
+        index: usize,
+        /// Path of the input.
+        path: PathBuf,
+        /// The error, with line numbers relative to that input.
+        source: Box<ReaderError>,
+    },
+}
+
+impl ReaderError {
+    /// Returns `true` for errors confined to a single malformed line.
+    pub(crate) fn is_recoverable(&self) -> bool {
+        match self {
+            ReaderError::InFile { source, .. } => source.is_recoverable(),
+            other => matches!(
+                other,
+                ReaderError::InvalidEncoding { .. }
+                    | ReaderError::InvalidField { .. }
+                    | ReaderError::UnexpectedFieldCount { .. }
+            ),
+        }
+    }
 }
 
 impl fmt::Display for ReaderError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ReaderError::Io(err) => write!(f, "I/O error: {err}"),
+            ReaderError::OpenFailed { path, source } => {
+                write!(f, "failed to open {}: {source}", path.display())
+            }
             #[cfg(feature = "mmap")]
             ReaderError::Mmap(err) => write!(f, "mmap error: {err}"),
             ReaderError::InvalidEncoding { line, message } => {
@@ -94,6 +131,11 @@ impl fmt::Display for ReaderError {
                 actual,
             } => write!(f, "line {line} had {actual} fields, expected {expected}"),
             ReaderError::Builder(msg) => write!(f, "builder error: {msg}"),
+            ReaderError::InFile {
+                index,
+                path,
+                source,
+            } => write!(f, "file {index} ({}): {source}", path.display()),
         }
     }
 }
@@ -103,8 +145,10 @@ impl std::error::Error for ReaderError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             ReaderError::Io(err) => Some(err),
+            ReaderError::OpenFailed { source, .. } => Some(source),
             #[cfg(feature = "mmap")]
             ReaderError::Mmap(err) => Some(err),
+            ReaderError::InFile { source, .. } => Some(source.as_ref()),
             _ => None,
         }
     }
@@ -141,7 +185,6 @@ impl ReaderError {
     }
 
     /// Creates a new `ReaderError` for an invalid encoding.
-    #[cfg_attr(not(feature = "mmap"), allow(dead_code))]
     fn invalid_encoding(line: usize, message: impl Into<String>) -> ReaderError {
         ReaderError::InvalidEncoding {
             line,
@@ -164,6 +207,65 @@ pub struct ReaderOptions<'a> {
     /// Overrides the attribute used to group parent records (GTF/GFF)
     parent_attribute: Option<Cow<'a, [u8]>>,
     child_attribute: Option<Cow<'a, [u8]>>,
+    /// Behaviour for transcripts without exon rows (GTF/GFF)
+    exonless: ExonlessPolicy,
+    /// Behaviour for transcripts spanning several chromosomes or strands (GTF/GFF)
+    strand_conflict: ConflictPolicy,
+    /// Emit records as transcripts close instead of preloading (GTF/GFF)
+    streaming: bool,
+    /// Number of transcripts kept open while streaming (GTF/GFF)
+    streaming_window: usize,
+    /// Behaviour for transcripts reappearing after being closed while streaming (GTF/GFF)
+    reappear: ReappearPolicy,
+    /// Sort preloaded records by coordinates (GTF/GFF)
+    sort_output: bool,
+    /// Defer parsing of additional fields until first access (BED)
+    lazy_extras: bool,
+    /// Leading byte marking comment lines (BED)
+    comment_char: u8,
+    /// Skip UCSC `track` and `browser` lines (BED)
+    skip_track_lines: bool,
+    /// Byte separating fields (BED)
+    delimiter: u8,
+    /// Treat start coordinates as 1-based closed (BED)
+    one_based: bool,
+    /// Handling of malformed records during iteration (BED)
+    error_policy: ErrorPolicy,
+    /// Check block layout against the feature span (BED)
+    validate_blocks: bool,
+    /// Clamp scores above 1000 instead of rejecting them (BED)
+    lenient_score: bool,
+    /// Chromosome naming convention records must follow (BED/GTF/GFF)
+    chrom_style: Option<ChromStyle>,
+    /// Inclusive bounds on record length outside which records are dropped (BED/GTF/GFF)
+    length_range: Option<(u64, Option<u64>)>,
+    /// Keep the source text of the most recent record (BED/GTF/GFF)
+    retain_raw: bool,
+    /// Skip records sharing the previous record's coordinates (BED/GTF/GFF)
+    dedup_consecutive: bool,
+    /// Rebuild exons from CDS and UTR rows when exon rows are absent (GTF/GFF)
+    derive_exons: bool,
+    /// Aggregate records per transcript or per gene (GTF/GFF)
+    aggregation: AggregationLevel,
+    /// Overrides the attributes parsed as comma-separated lists (GTF/GFF)
+    multi_value_attributes: Option<Vec<Cow<'a, [u8]>>>,
+    /// Overrides the attribute priority used to name transcripts (GTF/GFF)
+    name_attributes: Option<Vec<Cow<'a, [u8]>>>,
+    /// Additional feature types aggregated as exon or CDS rows (GTF/GFF)
+    extra_feature_types: Vec<(Cow<'a, [u8]>, FeatureKind)>,
+    /// Decode `%XX` escapes in attribute values (GFF)
+    decode_percent: bool,
+    /// Keep sequences from a trailing `##FASTA` section (GFF)
+    capture_fasta: bool,
+    /// Parse and aggregate rows on the rayon thread pool (GTF/GFF)
+    parallel: bool,
+    /// Reading mode, when overriding the builder's (BED)
+    mode: Option<ReaderMode>,
+    /// Buffer capacity in bytes, when overriding the builder's (BED)
+    buffer_capacity: Option<usize>,
+    /// Input compression, when overriding the builder's (BED)
+    #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+    compression: Option<Compression>,
 }
 
 impl<'a> Default for ReaderOptions<'a> {
@@ -174,6 +276,36 @@ impl<'a> Default for ReaderOptions<'a> {
             parent_attribute: None,
             child_attribute: None,
             child_features: Some(default_child_features()),
+            exonless: ExonlessPolicy::default(),
+            strand_conflict: ConflictPolicy::default(),
+            streaming: false,
+            streaming_window: 1,
+            reappear: ReappearPolicy::default(),
+            sort_output: true,
+            lazy_extras: false,
+            comment_char: b'#',
+            skip_track_lines: true,
+            delimiter: b'\t',
+            one_based: false,
+            error_policy: ErrorPolicy::default(),
+            validate_blocks: false,
+            lenient_score: false,
+            chrom_style: None,
+            length_range: None,
+            retain_raw: false,
+            dedup_consecutive: false,
+            derive_exons: false,
+            aggregation: AggregationLevel::default(),
+            multi_value_attributes: None,
+            name_attributes: None,
+            extra_feature_types: Vec::new(),
+            decode_percent: false,
+            capture_fasta: false,
+            parallel: false,
+            mode: None,
+            buffer_capacity: None,
+            #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+            compression: None,
         }
     }
 }
@@ -190,6 +322,34 @@ impl<'a> ReaderOptions<'a> {
         self
     }
 
+    /// Sets the reading mode.
+    ///
+    /// Unset by default, which keeps [`ReaderMode::Default`] or the mode chosen
+    /// on the [`ReaderBuilder`].
+    pub fn mode(mut self, mode: ReaderMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Sets the buffer capacity for streamed input.
+    ///
+    /// Unset by default, which keeps the builder's capacity (64 KB unless
+    /// changed). Values below 8 KB are raised to 8 KB.
+    pub fn buffer_capacity(mut self, capacity: usize) -> Self {
+        self.buffer_capacity = Some(capacity.max(8 * 1024));
+        self
+    }
+
+    /// Sets the compression format of the input.
+    ///
+    /// Unset by default, which keeps [`Compression::Auto`] or the builder's
+    /// choice.
+    #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
     /// Overrides the feature used to identify parent records.
     pub fn parent_feature<P>(mut self, feature: P) -> Self
     where
@@ -246,11 +406,311 @@ impl<'a> ReaderOptions<'a> {
         self
     }
 
+    /// Defers building the extras map for additional BED fields.
+    ///
+    /// Trailing columns are kept as raw bytes on each record and only turned
+    /// into an `Extras` map when [`GenePred::extras`] or a getter is first
+    /// called, which saves the per-line map allocation for wide files whose
+    /// extra columns are rarely read. Column counts and UTF-8 are still
+    /// validated up front. Applies to sequential reads; parallel iterators
+    /// parse extras eagerly.
+    pub fn lazy_extras(mut self, enabled: bool) -> Self {
+        self.lazy_extras = enabled;
+        self
+    }
+
+    /// Sets the byte that marks comment lines.
+    ///
+    /// Lines whose first non-blank byte is `comment` are skipped. Defaults to
+    /// `#`; use e.g. `b';'` or `b'/'` for files with other comment styles.
+    pub fn comment_char(mut self, comment: u8) -> Self {
+        self.comment_char = comment;
+        self
+    }
+
+    /// Controls whether UCSC `track` and `browser` lines are skipped.
+    ///
+    /// Enabled by default. Disable it when `track` or `browser` is a genuine
+    /// chromosome name.
+    pub fn skip_track_lines(mut self, enabled: bool) -> Self {
+        self.skip_track_lines = enabled;
+        self
+    }
+
+    /// Sets the byte separating BED fields.
+    ///
+    /// Defaults to `\t`. When the delimiter is ASCII whitespace other than a
+    /// tab (e.g. `b' '`), runs of it are collapsed so columns aligned with
+    /// several spaces still split cleanly; tabs and other delimiters keep empty
+    /// fields between adjacent separators. Lazy extras only apply to
+    /// tab-delimited input.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Reads BED coordinates as 1-based closed intervals.
+    ///
+    /// Some tools emit BED-like files whose starts are 1-based. When enabled,
+    /// `chromStart`, `thickStart` and the blocks (placed relative to
+    /// `chromStart`) are shifted down by one so records end up 0-based
+    /// half-open, the same conversion GTF/GFF input gets. A `thickStart` equal
+    /// to `thickEnd` (no coding region) is kept.
+    pub fn one_based(mut self, enabled: bool) -> Self {
+        self.one_based = enabled;
+        self
+    }
+
+    /// Sets how malformed records are handled during iteration.
+    ///
+    /// Defaults to [`ErrorPolicy::Fail`]. See [`ErrorPolicy`] for the other
+    /// modes.
+    pub fn on_error(mut self, policy: ErrorPolicy) -> Self {
+        self.error_policy = policy;
+        self
+    }
+
+    /// Validates BED12 blocks against the feature span.
+    ///
+    /// When enabled, a record is rejected with an `InvalidField` error unless
+    /// its first block starts at 0, its last block ends at `end - start`, and
+    /// its blocks are ascending and non-overlapping. Disabled by default to
+    /// keep parsing fast.
+    pub fn validate_blocks(mut self, enabled: bool) -> Self {
+        self.validate_blocks = enabled;
+        self
+    }
+
+    /// Accepts BED scores above the spec maximum of 1000.
+    ///
+    /// Peak callers and similar tools often write arbitrary integers to the
+    /// score column. When enabled, such scores are clamped to 1000 rather than
+    /// failing the record; non-integer scores are still rejected. Strict by
+    /// default.
+    pub fn lenient_score(mut self, enabled: bool) -> Self {
+        self.lenient_score = enabled;
+        self
+    }
+
+    /// Requires every record's chromosome name to follow `style`.
+    ///
+    /// A violating record yields an `InvalidField` error for its line, which
+    /// [`ReaderOptions::on_error`] can skip or collect. Off by default.
+    pub fn enforce_chrom_style(mut self, style: ChromStyle) -> Self {
+        self.chrom_style = Some(style);
+        self
+    }
+
+    /// Drops records whose length falls outside `min..=max`.
+    ///
+    /// Length is [`GenePred::len`], the span from start to end. A `max` of
+    /// `None` leaves the range open-ended. Dropped records are not errors;
+    /// [`Reader::filtered_count`] reports how many were skipped.
+    pub fn length_range(mut self, min: u64, max: Option<u64>) -> Self {
+        self.length_range = Some((min, max));
+        self
+    }
+
+    /// Keeps the source text of each record for debugging.
+    ///
+    /// When enabled, [`Reader::raw_line`] returns the line the most recent
+    /// record was parsed from. GTF/GFF records span several lines, so for
+    /// them it holds the transcript identifier instead. Off by default, since
+    /// it copies every line.
+    pub fn retain_raw(mut self, enabled: bool) -> Self {
+        self.retain_raw = enabled;
+        self
+    }
+
+    /// Skips a record when it repeats the coordinates of the one before it.
+    ///
+    /// Records are compared by [`GenePred::coord_key`], so only runs of
+    /// adjacent duplicates collapse; a repeat further down the input is kept.
+    /// Unlike [`crate::dedup_by_coords`] this needs no buffering.
+    pub fn dedup_consecutive(mut self, enabled: bool) -> Self {
+        self.dedup_consecutive = enabled;
+        self
+    }
+
+    /// Rebuilds exon blocks from CDS and UTR rows when a transcript has no exons.
+    ///
+    /// Minimal annotations sometimes list only `CDS` and `UTR` (or
+    /// `five_prime_utr`/`three_prime_utr`) rows. When enabled, such transcripts
+    /// get exons from the union of those rows (plus start/stop codons), with
+    /// overlapping or touching pieces merged into one block. Transcripts with
+    /// explicit exon rows are unaffected. Off by default; transcripts whose only
+    /// children are UTR rows take their exons from those rows either way.
+    pub fn derive_exons_from_cds_utr(mut self, enabled: bool) -> Self {
+        self.derive_exons = enabled;
+        self
+    }
+
+    /// Sets whether GTF/GFF rows are aggregated per transcript or per gene.
+    ///
+    /// With [`AggregationLevel::Gene`], transcripts sharing a gene identifier
+    /// (`gene_id`, `gene`, or the GFF `Parent`) on the same chromosome are
+    /// merged into one record whose blocks are the union of their exons and
+    /// whose thick bounds span every transcript's CDS. Streaming readers merge
+    /// consecutive transcripts only, so the input must be grouped by gene.
+    pub fn aggregate_by(mut self, level: AggregationLevel) -> Self {
+        self.aggregation = level;
+        self
+    }
+
+    /// Overrides the attributes whose values are split on commas into arrays.
+    ///
+    /// Defaults to the format's spec-defined list, which is
+    /// [`crate::gxf::GFF3_MULTI_VALUE_ATTRIBUTES`] for GFF and empty for GTF.
+    /// Pass an empty list to keep every attribute as written.
+    pub fn multi_value_attributes<I, K>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<Cow<'a, [u8]>>,
+    {
+        self.multi_value_attributes = Some(keys.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Overrides the attributes tried, in order, to name each transcript.
+    ///
+    /// Defaults to [`crate::gxf::DEFAULT_NAME_ATTRIBUTES`]. The first listed
+    /// attribute found on the transcript's rows becomes the record name; the
+    /// parent identifier is used when none are present.
+    pub fn name_attributes<I, K>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<Cow<'a, [u8]>>,
+    {
+        self.name_attributes = Some(keys.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Aggregates additional GTF/GFF feature types as exon or CDS rows.
+    ///
+    /// Rows of an unrecognized type are otherwise ignored, which can leave a
+    /// transcript without exons. Registered types (matched case-insensitively)
+    /// are kept regardless of [`ReaderOptions::child_features`]. Repeated
+    /// calls add to the registered types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::{FeatureKind, ReaderOptions};
+    ///
+    /// let options = ReaderOptions::new()
+    ///     .extra_feature_types(FeatureKind::Exon, [b"pseudogenic_exon".as_ref()]);
+    /// ```
+    pub fn extra_feature_types<I, K>(mut self, kind: FeatureKind, features: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<Cow<'a, [u8]>>,
+    {
+        self.extra_feature_types
+            .extend(features.into_iter().map(|feature| (feature.into(), kind)));
+        self
+    }
+
+    /// Decodes GFF3 percent-encoding (`%3B` for `;`, `%2C` for `,`, ...) in
+    /// attribute values.
+    ///
+    /// Decoding runs after [`ReaderOptions::multi_value_attributes`] splitting,
+    /// so encoded commas stay inside their list item. Off by default.
+    pub fn decode_percent(mut self, enabled: bool) -> Self {
+        self.decode_percent = enabled;
+        self
+    }
+
+    /// Keeps the sequences of a trailing GFF3 `##FASTA` section.
+    ///
+    /// Feature parsing always stops at `##FASTA` (or at a first `>` header
+    /// line). When enabled, the sequences that follow are collected into
+    /// [`GxfMetadata::sequences`](crate::GxfMetadata::sequences), available
+    /// through [`Reader::metadata`]. Streaming readers skip the section.
+    pub fn capture_fasta(mut self, enabled: bool) -> Self {
+        self.capture_fasta = enabled;
+        self
+    }
+
+    /// Parses GTF/GFF inputs on the rayon thread pool.
+    ///
+    /// The whole file is read into memory, rows are parsed in parallel and
+    /// transcripts are built independently, which speeds up large annotations
+    /// such as GENCODE. Records come back sorted by chromosome, start, end and
+    /// name. Ignored when [`ReaderOptions::streaming`] is enabled.
+    #[cfg(feature = "rayon")]
+    pub fn parallel(mut self, enabled: bool) -> Self {
+        self.parallel = enabled;
+        self
+    }
+
+    /// Sets how transcripts without exon rows are handled.
+    pub fn on_exonless(mut self, policy: ExonlessPolicy) -> Self {
+        self.exonless = policy;
+        self
+    }
+
+    /// Sets how transcripts whose rows span several strands or chromosomes
+    /// are handled.
+    ///
+    /// Defaults to [`ConflictPolicy::Error`], which aborts the read.
+    pub fn on_strand_conflict(mut self, policy: ConflictPolicy) -> Self {
+        self.strand_conflict = policy;
+        self
+    }
+
     /// Returns the number of additional fields expected in each record.
     pub(crate) fn additional_fields_count(&self) -> usize {
         self.additional_fields
     }
 
+    /// Returns `true` if additional BED fields are parsed on first access.
+    pub(crate) fn is_lazy_extras(&self) -> bool {
+        self.lazy_extras
+    }
+
+    /// Returns the per-line BED parsing settings.
+    pub(crate) fn line_format(&self) -> LineFormat {
+        LineFormat {
+            delimiter: self.delimiter,
+            one_based: self.one_based,
+            validate_blocks: self.validate_blocks,
+            lenient_score: self.lenient_score,
+        }
+    }
+
+    /// Returns the policy for malformed records.
+    pub(crate) fn error_policy(&self) -> ErrorPolicy {
+        self.error_policy
+    }
+
+    /// Returns the enforced chromosome naming convention, if any.
+    pub(crate) fn chrom_style(&self) -> Option<ChromStyle> {
+        self.chrom_style
+    }
+
+    /// Returns the inclusive record length bounds, if any.
+    pub(crate) fn length_bounds(&self) -> Option<(u64, Option<u64>)> {
+        self.length_range
+    }
+
+    /// Returns `true` if the source text of records is kept.
+    pub(crate) fn retains_raw(&self) -> bool {
+        self.retain_raw
+    }
+
+    /// Returns `true` if adjacent records with equal coordinates collapse.
+    pub(crate) fn dedups_consecutive(&self) -> bool {
+        self.dedup_consecutive
+    }
+
+    /// Returns the rules for skipping non-record lines.
+    pub(crate) fn line_filter(&self) -> LineFilter {
+        LineFilter {
+            comment: self.comment_char,
+            skip_track_lines: self.skip_track_lines,
+        }
+    }
+
     /// Returns the parent feature name.
     pub(crate) fn resolved_parent_feature<'b, F: GxfFormat>(&'b self) -> Cow<'b, [u8]> {
         self.parent_feature
@@ -275,6 +735,143 @@ impl<'a> ReaderOptions<'a> {
             .unwrap_or_else(|| Cow::Borrowed(F::DEFAULT_CHILD_ATTRIBUTE))
     }
 
+    /// Enables streaming aggregation for GTF/GFF inputs.
+    ///
+    /// By default the whole file is aggregated before the first record is
+    /// returned, which works for any line order but keeps every transcript in
+    /// memory. When streaming, a record is emitted as soon as its transcript is
+    /// closed (see [`ReaderOptions::streaming_window`]), so only the open
+    /// transcripts are held in full. Streaming requires input grouped by
+    /// transcript; what happens to a transcript that reappears after being
+    /// closed is set by [`ReaderOptions::on_reappear`]. By default it yields
+    /// an error, and the ID of every closed transcript is remembered to detect
+    /// this, so memory still grows with the number of transcript IDs in the file.
+    /// Streaming applies to buffered and memory-mapped readers alike; mapped
+    /// inputs are aggregated straight from the mapping.
+    pub fn streaming(mut self, enabled: bool) -> Self {
+        self.streaming = enabled;
+        self
+    }
+
+    /// Sets how many transcripts may stay open at once while streaming.
+    ///
+    /// A transcript is closed once more than `window` other transcripts have
+    /// been touched since its last line. The default of `1` suits sorted
+    /// input; widen it for annotations whose transcripts are interleaved
+    /// (e.g. overlapping genes). Values below `1` are treated as `1`.
+    pub fn streaming_window(mut self, window: usize) -> Self {
+        self.streaming_window = window;
+        self
+    }
+
+    /// Sets how a streaming reader handles a transcript whose rows reappear
+    /// after it was closed.
+    ///
+    /// Defaults to [`ReappearPolicy::Error`]. [`ReappearPolicy::Split`] emits
+    /// the later rows as a separate record and keeps memory bounded by the
+    /// streaming window. Ignored unless [`ReaderOptions::streaming`] is enabled.
+    pub fn on_reappear(mut self, policy: ReappearPolicy) -> Self {
+        self.reappear = policy;
+        self
+    }
+
+    /// Sorts preloaded GTF/GFF records by chromosome, start, end and name.
+    ///
+    /// Enabled by default, so reading the same file always yields the same
+    /// order. When disabled, records come back in an unspecified order,
+    /// which saves the sort on large annotations. Streamed records are
+    /// always emitted in the order their transcripts close.
+    pub fn sort_output(mut self, enabled: bool) -> Self {
+        self.sort_output = enabled;
+        self
+    }
+
+    /// Returns `true` if GTF/GFF records should be streamed.
+    pub(crate) fn is_streaming(&self) -> bool {
+        self.streaming
+    }
+
+    /// Returns `true` if preloaded GTF/GFF records are sorted.
+    pub(crate) fn sorts_output(&self) -> bool {
+        self.sort_output
+    }
+
+    /// Returns the effective streaming window.
+    pub(crate) fn streaming_window_size(&self) -> usize {
+        self.streaming_window.max(1)
+    }
+
+    /// Returns the policy for transcripts reappearing after being closed.
+    pub(crate) fn reappear_policy(&self) -> ReappearPolicy {
+        self.reappear
+    }
+
+    /// Returns the policy for transcripts without exon rows.
+    pub(crate) fn exonless_policy(&self) -> ExonlessPolicy {
+        self.exonless
+    }
+
+    /// Returns the policy for transcripts spanning several strands or chromosomes.
+    pub(crate) fn strand_conflict_policy(&self) -> ConflictPolicy {
+        self.strand_conflict
+    }
+
+    /// Returns `true` if exons are rebuilt from CDS and UTR rows.
+    pub(crate) fn derives_exons_from_cds_utr(&self) -> bool {
+        self.derive_exons
+    }
+
+    /// Returns the GXF aggregation level.
+    pub(crate) fn aggregation_level(&self) -> AggregationLevel {
+        self.aggregation
+    }
+
+    /// Returns `true` if GTF/GFF rows are parsed in parallel.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn is_parallel(&self) -> bool {
+        self.parallel
+    }
+
+    /// Returns `true` if `##FASTA` sequences are collected.
+    pub(crate) fn captures_fasta(&self) -> bool {
+        self.capture_fasta
+    }
+
+    /// Returns the attributes split on commas into arrays.
+    pub(crate) fn resolved_multi_value_attributes<F: GxfFormat>(&self) -> Vec<Vec<u8>> {
+        match &self.multi_value_attributes {
+            Some(keys) => keys.iter().map(|key| key.to_vec()).collect(),
+            None => F::MULTI_VALUE_ATTRIBUTES
+                .iter()
+                .map(|key| key.to_vec())
+                .collect(),
+        }
+    }
+
+    /// Returns the attributes tried, in order, to name a transcript.
+    pub(crate) fn resolved_name_attributes(&self) -> Vec<Vec<u8>> {
+        match &self.name_attributes {
+            Some(keys) => keys.iter().map(|key| key.to_vec()).collect(),
+            None => gxf::DEFAULT_NAME_ATTRIBUTES
+                .iter()
+                .map(|key| key.to_vec())
+                .collect(),
+        }
+    }
+
+    /// Returns the additional feature types and the rows they count as.
+    pub(crate) fn resolved_extra_feature_types(&self) -> Vec<(Vec<u8>, FeatureKind)> {
+        self.extra_feature_types
+            .iter()
+            .map(|(feature, kind)| (feature.to_vec(), *kind))
+            .collect()
+    }
+
+    /// Returns `true` if `%XX` escapes in attribute values are decoded.
+    pub(crate) fn decodes_percent(&self) -> bool {
+        self.decode_percent
+    }
+
     /// Returns the child feature names.
     pub(crate) fn child_features_ref(&self) -> Option<&[Cow<'a, [u8]>]> {
         self.child_features.as_deref()
@@ -299,6 +896,48 @@ impl<'a> ReaderOptions<'a> {
                     .map(|feature| Cow::Owned(feature.into_owned()))
                     .collect()
             }),
+            exonless: self.exonless,
+            strand_conflict: self.strand_conflict,
+            streaming: self.streaming,
+            streaming_window: self.streaming_window,
+            reappear: self.reappear,
+            sort_output: self.sort_output,
+            lazy_extras: self.lazy_extras,
+            comment_char: self.comment_char,
+            skip_track_lines: self.skip_track_lines,
+            delimiter: self.delimiter,
+            one_based: self.one_based,
+            error_policy: self.error_policy,
+            validate_blocks: self.validate_blocks,
+            lenient_score: self.lenient_score,
+            chrom_style: self.chrom_style,
+            length_range: self.length_range,
+            retain_raw: self.retain_raw,
+            dedup_consecutive: self.dedup_consecutive,
+            derive_exons: self.derive_exons,
+            aggregation: self.aggregation,
+            multi_value_attributes: self.multi_value_attributes.map(|keys| {
+                keys.into_iter()
+                    .map(|key| Cow::Owned(key.into_owned()))
+                    .collect()
+            }),
+            name_attributes: self.name_attributes.map(|keys| {
+                keys.into_iter()
+                    .map(|key| Cow::Owned(key.into_owned()))
+                    .collect()
+            }),
+            extra_feature_types: self
+                .extra_feature_types
+                .into_iter()
+                .map(|(feature, kind)| (Cow::Owned(feature.into_owned()), kind))
+                .collect(),
+            decode_percent: self.decode_percent,
+            capture_fasta: self.capture_fasta,
+            parallel: self.parallel,
+            mode: self.mode,
+            buffer_capacity: self.buffer_capacity,
+            #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+            compression: self.compression,
         }
     }
 }
@@ -316,6 +955,32 @@ fn default_child_features<'a>() -> Vec<Cow<'a, [u8]>> {
     ]
 }
 
+/// Controls how malformed records are handled during sequential iteration.
+///
+/// Only per-line parse errors are affected; I/O failures always end
+/// iteration with an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorPolicy {
+    /// Yield the error and let the caller decide (default).
+    #[default]
+    Fail,
+    /// Skip malformed lines silently.
+    Skip,
+    /// Skip malformed lines and keep their errors for [`Reader::errors`].
+    Collect,
+}
+
+/// Chromosome naming convention enforced while reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChromStyle {
+    /// UCSC style: every name carries a `chr` prefix (`chr1`, `chrX`).
+    Ucsc,
+    /// Ensembl style: no name carries a `chr` prefix (`1`, `X`).
+    Ensembl,
+    /// Whichever style the first record uses applies to the rest.
+    Consistent,
+}
+
 /// The mode to use when reading a BED file.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReaderMode {
@@ -330,7 +995,8 @@ pub enum ReaderMode {
 #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Compression {
-    /// Automatically detect the compression format from the file extension.
+    /// Automatically detect the compression format from the file extension,
+    /// or from the leading magic bytes for `from_reader` inputs.
     ///
     /// This is the default.
     Auto,
@@ -338,6 +1004,11 @@ pub enum Compression {
     None,
     /// Gzip compression.
     Gzip,
+    /// Blocked gzip (BGZF), as produced by `bgzip` for tabix-indexed files.
+    ///
+    /// Detected for `.gz` inputs when the `bgzf` feature is enabled and
+    /// decoded block by block like a multi-member gzip stream.
+    Bgzf,
     /// Zstandard compression.
     Zstd,
     /// Bzip2 compression.
@@ -363,13 +1034,56 @@ impl Default for Compression {
 fn detect_compression_from_extension(path: &Path) -> Compression {
     let ext = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
     match ext {
-        "gz" => Compression::Gzip,
+        "gz" => gzip_flavor(path),
         "zst" | "zstd" => Compression::Zstd,
         "bz2" | "bzip2" => Compression::Bzip2,
         _ => Compression::None,
     }
 }
 
+/// Distinguishes BGZF from plain gzip for a `.gz` path.
+///
+/// Without the `bgzf` feature every `.gz` input is treated as plain gzip.
+///
+/// # Arguments
+///
+/// * `path` - The `.gz` file to inspect.
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+pub(crate) fn gzip_flavor(path: &Path) -> Compression {
+    #[cfg(feature = "bgzf")]
+    {
+        let mut header = [0u8; 16];
+        let read = File::open(path).and_then(|mut file| file.read_exact(&mut header));
+        if read.is_ok() && is_bgzf_header(&header) {
+            return Compression::Bgzf;
+        }
+    }
+    #[cfg(not(feature = "bgzf"))]
+    let _ = path;
+    Compression::Gzip
+}
+
+/// Returns `true` when `header` starts a BGZF block.
+///
+/// BGZF is gzip with `FLG.FEXTRA` set and a leading `BC` extra subfield of
+/// length 2 holding the block size.
+#[cfg(feature = "bgzf")]
+fn is_bgzf_header(header: &[u8; 16]) -> bool {
+    header[..4] == [0x1f, 0x8b, 0x08, 0x04] && header[12..16] == [b'B', b'C', 2, 0]
+}
+
+/// Opens a file, attaching its path to any failure.
+///
+/// # Arguments
+///
+/// * `path` - The file to open.
+pub(crate) fn open_file(path: &Path) -> ReaderResult<File> {
+    File::open(path).map_err(|source| ReaderError::OpenFailed {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
 /// Opens a filesystem path as a raw or decompressed stream.
 ///
 /// # Arguments
@@ -378,10 +1092,10 @@ fn detect_compression_from_extension(path: &Path) -> Compression {
 pub(crate) fn open_path_stream(path: &Path) -> ReaderResult<Box<dyn Read + Send>> {
     #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
     {
-        let file = File::open(path)?;
+        let file = open_file(path)?;
         return match detect_compression_from_extension(path) {
             Compression::None | Compression::Auto => Ok(Box::new(file)),
-            Compression::Gzip => {
+            Compression::Gzip | Compression::Bgzf => {
                 #[cfg(feature = "gzip")]
                 {
                     Ok(Box::new(MultiGzDecoder::new(file)))
@@ -429,37 +1143,177 @@ pub(crate) fn open_path_stream(path: &Path) -> ReaderResult<Box<dyn Read + Send>
                 "ERROR: enable compression features to read compressed inputs".into(),
             ));
         }
-        Ok(Box::new(File::open(path)?))
+        Ok(Box::new(open_file(path)?))
     }
 }
 
-/// A builder for creating a `Reader`.
+/// Detects compression from the leading magic bytes of a stream.
 ///
-/// # Example
+/// Up to four bytes are consumed and then chained back in front of the
+/// returned reader, so no input is lost. Streams shorter than a magic
+/// number are reported as uncompressed.
 ///
-/// ```rust,no_run,ignore
-/// use genepred::{Reader, Bed3};
+/// # Arguments
 ///
-/// fn main() -> Result<(), Box<dyn std::error::Error>> {
-///     let reader = Reader::<Bed3>::builder()
-///         .from_path("tests/data/simple.bed")?
-///         .build()?;
+/// * `reader` - The raw input stream.
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+fn detect_compression_from_magic(
+    mut reader: Box<dyn Read + Send>,
+) -> io::Result<(Compression, Box<dyn Read + Send>)> {
+    let mut magic = [0u8; 4];
+    let mut len = 0;
+    while len < magic.len() {
+        match reader.read(&mut magic[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    let head = &magic[..len];
+    let compression = if head.starts_with(&[0x1f, 0x8b]) {
+        Compression::Gzip
+    } else if head == [0x28, 0xb5, 0x2f, 0xfd] {
+        Compression::Zstd
+    } else if head.starts_with(b"BZh") {
+        Compression::Bzip2
+    } else {
+        Compression::None
+    };
+
+    let prefix = io::Cursor::new(magic).take(len as u64);
+    Ok((compression, Box::new(prefix.chain(reader))))
+}
+
+/// Wraps a raw input stream in the decoder for `compression`.
 ///
-///     for record in reader {
-///         let record = record?;
-///         // ...
-///     }
+/// `Compression::Auto` sniffs the stream with [`detect_compression_from_magic`].
 ///
-///     Ok(())
-/// }
-/// ```
-pub struct ReaderBuilder<R: BedFormat + Into<GenePred>> {
-    source: Option<ReaderSource>,
-    options: ReaderOptions<'static>,
-    mode: ReaderMode,
-    buffer_capacity: usize,
+/// # Arguments
+///
+/// * `reader` - The raw input stream.
+/// * `compression` - The requested compression.
+/// * `dictionary` - The zstd dictionary, if any.
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+fn decode_stream(
+    reader: Box<dyn Read + Send>,
+    compression: Compression,
+    dictionary: Option<&[u8]>,
+) -> ReaderResult<Box<dyn Read + Send>> {
+    let (compression, reader) = match compression {
+        Compression::Auto => detect_compression_from_magic(reader)?,
+        other => (other, reader),
+    };
+    check_zstd_dictionary(compression, dictionary)?;
+
+    match compression {
+        Compression::None | Compression::Auto => Ok(reader),
+        Compression::Gzip | Compression::Bgzf => {
+            #[cfg(feature = "gzip")]
+            {
+                Ok(Box::new(MultiGzDecoder::new(reader)))
+            }
+            #[cfg(not(feature = "gzip"))]
+            {
+                Err(ReaderError::Builder(
+                    "ERROR: enable the `gzip` feature to read gzip input".into(),
+                ))
+            }
+        }
+        Compression::Zstd => {
+            #[cfg(feature = "zstd")]
+            {
+                zstd_decoder(reader, dictionary)
+            }
+            #[cfg(not(feature = "zstd"))]
+            {
+                Err(ReaderError::Builder(
+                    "ERROR: enable the `zstd` feature to read zstd input".into(),
+                ))
+            }
+        }
+        Compression::Bzip2 => {
+            #[cfg(feature = "bz2")]
+            {
+                Ok(Box::new(BzDecoder::new(reader)))
+            }
+            #[cfg(not(feature = "bz2"))]
+            {
+                Err(ReaderError::Builder(
+                    "ERROR: enable the `bz2` feature to read bzip2 input".into(),
+                ))
+            }
+        }
+    }
+}
+
+/// Rejects a zstd dictionary paired with input that is not zstd-compressed.
+///
+/// # Arguments
+///
+/// * `compression` - The resolved compression of the input.
+/// * `dictionary` - The zstd dictionary, if any.
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+fn check_zstd_dictionary(compression: Compression, dictionary: Option<&[u8]>) -> ReaderResult<()> {
+    if dictionary.is_none() || compression == Compression::Zstd {
+        return Ok(());
+    }
+    Err(ReaderError::Builder(format!(
+        "ERROR: a zstd dictionary was supplied but the input compression is {compression:?}"
+    )))
+}
+
+/// Builds a zstd decoder, using `dictionary` when one is supplied.
+///
+/// # Arguments
+///
+/// * `source` - The compressed stream.
+/// * `dictionary` - The zstd dictionary, if any.
+#[cfg(feature = "zstd")]
+fn zstd_decoder<S>(source: S, dictionary: Option<&[u8]>) -> ReaderResult<Box<dyn Read + Send>>
+where
+    S: Read + Send + 'static,
+{
+    Ok(match dictionary {
+        Some(dictionary) => Box::new(ZstdDecoder::with_dictionary(
+            BufReader::new(source),
+            dictionary,
+        )?),
+        None => Box::new(ZstdDecoder::new(source)?),
+    })
+}
+
+/// A builder for creating a `Reader`.
+///
+/// # Example
+///
+/// ```rust,no_run,ignore
+/// use genepred::{Reader, Bed3};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let reader = Reader::<Bed3>::builder()
+///         .from_path("tests/data/simple.bed")?
+///         .build()?;
+///
+///     for record in reader {
+///         let record = record?;
+///         // ...
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub struct ReaderBuilder<R: BedFormat + Into<GenePred>> {
+    source: Option<ReaderSource>,
+    options: ReaderOptions<'static>,
+    mode: ReaderMode,
+    buffer_capacity: usize,
     #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
     compression: Compression,
+    #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+    zstd_dictionary: Option<Arc<[u8]>>,
+    progress: Option<ProgressFn>,
     _marker: PhantomData<R>,
 }
 
@@ -472,6 +1326,9 @@ impl<R: BedFormat + Into<GenePred>> Default for ReaderBuilder<R> {
             buffer_capacity: 64 * 1024,
             #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
             compression: Compression::default(),
+            #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+            zstd_dictionary: None,
+            progress: None,
             _marker: PhantomData,
         }
     }
@@ -493,14 +1350,134 @@ impl<R: BedFormat + Into<GenePred>> ReaderBuilder<R> {
         self
     }
 
+    /// Creates a new `ReaderBuilder` over uncompressed bytes held in memory.
+    ///
+    /// See [`Reader::from_bytes`], including why `bytes` must be `'static`.
+    pub fn from_bytes<B>(mut self, bytes: B) -> Self
+    where
+        B: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        self.source = Some(ReaderSource::Bytes(Arc::new(bytes)));
+        self
+    }
+
     /// Sets the number of additional fields to expect in each record.
     pub fn additional_fields(mut self, count: usize) -> Self {
         self.options = self.options.additional_fields(count);
         self
     }
 
+    /// Defers building extras for additional fields until first access.
+    ///
+    /// See [`ReaderOptions::lazy_extras`].
+    pub fn lazy_extras(mut self, enabled: bool) -> Self {
+        self.options = self.options.lazy_extras(enabled);
+        self
+    }
+
+    /// Sets the byte that marks comment lines.
+    ///
+    /// See [`ReaderOptions::comment_char`].
+    pub fn comment_char(mut self, comment: u8) -> Self {
+        self.options = self.options.comment_char(comment);
+        self
+    }
+
+    /// Controls whether UCSC `track` and `browser` lines are skipped.
+    ///
+    /// See [`ReaderOptions::skip_track_lines`].
+    pub fn skip_track_lines(mut self, enabled: bool) -> Self {
+        self.options = self.options.skip_track_lines(enabled);
+        self
+    }
+
+    /// Sets the byte separating BED fields.
+    ///
+    /// See [`ReaderOptions::delimiter`].
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.options = self.options.delimiter(delimiter);
+        self
+    }
+
+    /// Reads BED coordinates as 1-based closed intervals.
+    ///
+    /// See [`ReaderOptions::one_based`].
+    pub fn one_based(mut self, enabled: bool) -> Self {
+        self.options = self.options.one_based(enabled);
+        self
+    }
+
+    /// Sets how malformed records are handled during iteration.
+    ///
+    /// See [`ReaderOptions::on_error`].
+    pub fn on_error(mut self, policy: ErrorPolicy) -> Self {
+        self.options = self.options.on_error(policy);
+        self
+    }
+
+    /// Validates BED12 blocks against the feature span.
+    ///
+    /// See [`ReaderOptions::validate_blocks`].
+    pub fn validate_blocks(mut self, enabled: bool) -> Self {
+        self.options = self.options.validate_blocks(enabled);
+        self
+    }
+
+    /// Accepts BED scores above the spec maximum of 1000.
+    ///
+    /// See [`ReaderOptions::lenient_score`].
+    pub fn lenient_score(mut self, enabled: bool) -> Self {
+        self.options = self.options.lenient_score(enabled);
+        self
+    }
+
+    /// Requires every record's chromosome name to follow `style`.
+    ///
+    /// See [`ReaderOptions::enforce_chrom_style`].
+    pub fn enforce_chrom_style(mut self, style: ChromStyle) -> Self {
+        self.options = self.options.enforce_chrom_style(style);
+        self
+    }
+
+    /// Drops records whose length falls outside `min..=max`.
+    ///
+    /// See [`ReaderOptions::length_range`].
+    pub fn length_range(mut self, min: u64, max: Option<u64>) -> Self {
+        self.options = self.options.length_range(min, max);
+        self
+    }
+
+    /// Keeps the source text of each record for debugging.
+    ///
+    /// See [`ReaderOptions::retain_raw`].
+    pub fn retain_raw(mut self, enabled: bool) -> Self {
+        self.options = self.options.retain_raw(enabled);
+        self
+    }
+
+    /// Skips records repeating the coordinates of the previous one.
+    ///
+    /// See [`ReaderOptions::dedup_consecutive`].
+    pub fn dedup_consecutive(mut self, enabled: bool) -> Self {
+        self.options = self.options.dedup_consecutive(enabled);
+        self
+    }
+
     /// Replaces the reader options.
+    ///
+    /// A mode, buffer capacity or compression set on `options` overrides the
+    /// builder's; unset ones keep it.
     pub fn options(mut self, options: ReaderOptions<'_>) -> Self {
+        if let Some(mode) = options.mode {
+            self.mode = mode;
+        }
+        if let Some(capacity) = options.buffer_capacity {
+            self.buffer_capacity = capacity;
+        }
+        #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+        if let Some(compression) = options.compression {
+            self.compression = compression;
+        }
         self.options = options.into_owned();
         self
     }
@@ -526,6 +1503,30 @@ impl<R: BedFormat + Into<GenePred>> ReaderBuilder<R> {
         self
     }
 
+    /// Decodes zstd input with a shared dictionary.
+    ///
+    /// Needed for archives compressed with `zstd -D`, which the plain decoder
+    /// cannot read. Building fails if the input turns out not to be zstd, or
+    /// is not a BED-like stream read in buffered mode.
+    #[cfg(feature = "zstd")]
+    pub fn zstd_dictionary(mut self, dictionary: Vec<u8>) -> Self {
+        self.zstd_dictionary = Some(dictionary.into());
+        self
+    }
+
+    /// Registers a callback reporting [`Reader::bytes_consumed`] while reading.
+    ///
+    /// The callback runs during sequential iteration each time roughly
+    /// another MiB of input has been consumed, and once more at end of input
+    /// with the final count.
+    pub fn progress<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(u64) + Send + 'static,
+    {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
     /// Builds the `Reader`.
     pub fn build(mut self) -> ReaderResult<Reader<R>> {
         let source = self
@@ -533,6 +1534,66 @@ impl<R: BedFormat + Into<GenePred>> ReaderBuilder<R> {
             .take()
             .ok_or_else(|| ReaderError::Builder("ERROR: no input source configured".into()))?;
 
+        let reopen = source.reopenable();
+        let mut reader = self.open_source(source)?;
+        reader.lazy_extras = self.options.is_lazy_extras();
+        reader.filter = self.options.line_filter();
+        reader.format = self.options.line_format();
+        reader.error_policy = self.options.error_policy();
+        reader.chrom_style = self.options.chrom_style();
+        reader.length_range = self.options.length_bounds();
+        reader.retain_raw = self.options.retains_raw();
+        reader.dedup_consecutive = self.options.dedups_consecutive();
+        reader.progress = self.progress.take();
+        if let Some(source) = reopen {
+            reader.rewind = Some(self.rewind_fn(source));
+        }
+        Ok(reader)
+    }
+
+    /// Captures the current configuration to reopen `source` on rewind.
+    fn rewind_fn(&self, source: ReaderSource) -> RewindFn<R> {
+        let options = self.options.clone();
+        let mode = self.mode;
+        let buffer_capacity = self.buffer_capacity;
+        #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+        let compression = self.compression;
+        #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+        let zstd_dictionary = self.zstd_dictionary.clone();
+
+        Box::new(move || {
+            let builder = ReaderBuilder {
+                source: None,
+                options: options.clone(),
+                mode,
+                buffer_capacity,
+                #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+                compression,
+                #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+                zstd_dictionary: zstd_dictionary.clone(),
+                progress: None,
+                _marker: PhantomData,
+            };
+            let source = source.reopenable().ok_or_else(|| {
+                ReaderError::Builder("ERROR: cannot rewind a non-seekable stream source".into())
+            })?;
+            builder.open_source(source)
+        })
+    }
+
+    /// Opens the configured source with the selected mode.
+    fn open_source(&self, source: ReaderSource) -> ReaderResult<Reader<R>> {
+        #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+        if self.zstd_dictionary.is_some()
+            && (!R::SUPPORTS_STANDARD_READER
+                || matches!(self.mode, ReaderMode::Mmap)
+                || matches!(source, ReaderSource::Bytes(_)))
+        {
+            return Err(ReaderError::Builder(
+                "ERROR: a zstd dictionary requires a BED-like stream in buffered mode".into(),
+            ));
+        }
+
         match source {
             ReaderSource::Path(path) => {
                 if !R::SUPPORTS_STANDARD_READER {
@@ -541,12 +1602,14 @@ impl<R: BedFormat + Into<GenePred>> ReaderBuilder<R> {
 
                 match self.mode {
                     ReaderMode::Default => {
-                        let reader = self.open_path_stream(&path)?;
-                        Reader::from_stream(
+                        let (reader, source_bytes) = self.open_path_stream(&path)?;
+                        let mut reader = Reader::from_stream(
                             reader,
                             self.options.additional_fields_count(),
                             self.buffer_capacity,
-                        )
+                        )?;
+                        reader.source_bytes = source_bytes;
+                        Ok(reader)
                     }
                     ReaderMode::Mmap => {
                         #[cfg(feature = "mmap")]
@@ -569,6 +1632,14 @@ impl<R: BedFormat + Into<GenePred>> ReaderBuilder<R> {
                     ));
                 }
 
+                #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+                let reader = match self.mode {
+                    ReaderMode::Default => {
+                        decode_stream(reader, self.compression, self.zstd_dictionary.as_deref())?
+                    }
+                    ReaderMode::Mmap => reader,
+                };
+
                 match self.mode {
                     ReaderMode::Default => Reader::from_stream(
                         reader,
@@ -580,14 +1651,31 @@ impl<R: BedFormat + Into<GenePred>> ReaderBuilder<R> {
                     )),
                 }
             }
+            ReaderSource::Bytes(data) => {
+                if !R::SUPPORTS_STANDARD_READER {
+                    return self.build_gxf_from_bytes(data);
+                }
+
+                Ok(Reader::from_shared_bytes(
+                    data,
+                    self.options.additional_fields_count(),
+                ))
+            }
         }
     }
 
     /// Opens a path as a stream.
-    fn open_path_stream(&self, path: &Path) -> ReaderResult<Box<dyn Read + Send>> {
+    ///
+    /// Compressed inputs also return a counter of the compressed bytes read
+    /// from the file, used by [`Reader::bytes_consumed`].
+    fn open_path_stream(
+        &self,
+        path: &Path,
+    ) -> ReaderResult<(Box<dyn Read + Send>, Option<ByteCounter>)> {
         #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
         {
-            let file = File::open(path)?;
+            let file = open_file(path)?;
+            let counter = ByteCounter::default();
             let compression = match self.compression {
                 Compression::Auto => detect_compression_from_extension(path),
                 other => other,
@@ -600,13 +1688,17 @@ impl<R: BedFormat + Into<GenePred>> ReaderBuilder<R> {
                     "compression is only supported in buffered mode".into(),
                 ));
             }
+            check_zstd_dictionary(compression, self.zstd_dictionary.as_deref())?;
 
             return match compression {
-                Compression::None | Compression::Auto => Ok(Box::new(file)),
-                Compression::Gzip => {
+                Compression::None | Compression::Auto => Ok((Box::new(file), None)),
+                Compression::Gzip | Compression::Bgzf => {
                     #[cfg(feature = "gzip")]
                     {
-                        Ok(Box::new(MultiGzDecoder::new(file)))
+                        Ok((
+                            Box::new(MultiGzDecoder::new(CountingReader::new(file, &counter))),
+                            Some(counter),
+                        ))
                     }
                     #[cfg(not(feature = "gzip"))]
                     {
@@ -618,7 +1710,13 @@ impl<R: BedFormat + Into<GenePred>> ReaderBuilder<R> {
                 Compression::Zstd => {
                     #[cfg(feature = "zstd")]
                     {
-                        Ok(Box::new(ZstdDecoder::new(file)?))
+                        Ok((
+                            zstd_decoder(
+                                CountingReader::new(file, &counter),
+                                self.zstd_dictionary.as_deref(),
+                            )?,
+                            Some(counter),
+                        ))
                     }
                     #[cfg(not(feature = "zstd"))]
                     {
@@ -630,7 +1728,10 @@ impl<R: BedFormat + Into<GenePred>> ReaderBuilder<R> {
                 Compression::Bzip2 => {
                     #[cfg(feature = "bz2")]
                     {
-                        Ok(Box::new(BzDecoder::new(file)))
+                        Ok((
+                            Box::new(BzDecoder::new(CountingReader::new(file, &counter))),
+                            Some(counter),
+                        ))
                     }
                     #[cfg(not(feature = "bz2"))]
                     {
@@ -651,64 +1752,52 @@ impl<R: BedFormat + Into<GenePred>> ReaderBuilder<R> {
                     "ERROR: enable compression features to read compressed inputs".into(),
                 ));
             }
-            Ok(Box::new(File::open(path)?))
+            Ok((Box::new(open_file(path)?), None))
         }
     }
 
     /// Builds a `Reader` from a memory-mapped file.
     #[cfg(feature = "mmap")]
     fn build_mmap(&self, path: PathBuf, additional_fields: usize) -> ReaderResult<Reader<R>> {
-        if additional_fields == 0 {
-            Reader::from_mmap(path)
-        } else {
-            let map = unsafe { MmapOptions::new().map(&File::open(&path)?) }
-                .map_err(ReaderError::Mmap)?;
-
-            Ok(Reader {
-                inner: InnerSource::Mmap(MmapInner {
-                    data: map.into(),
-                    cursor: 0,
-                }),
-                buffer: String::with_capacity(1024),
-                additional_fields,
-                line_number: 0,
-                extra_keys: build_extra_keys(R::FIELD_COUNT, additional_fields),
-                preloaded: None,
-                _marker: PhantomData,
-            })
-        }
+        Reader::map_path(&path, additional_fields)
     }
 
-    /// Builds a `Reader` for GXF formats (GTF/GFF) from a filesystem path.
-    fn build_gxf_from_path(&self, path: PathBuf) -> ReaderResult<Reader<R>> {
+    /// Builds a `Reader` for GXF formats (GTF/GFF) over bytes in memory.
+    fn build_gxf_from_bytes(&self, data: SharedData) -> ReaderResult<Reader<R>> {
         if self.options.additional_fields_count() != 0 {
             return Err(ReaderError::Builder(
                 "ERROR: additional fields are not supported for this format".into(),
             ));
         }
 
-        if matches!(self.mode, ReaderMode::Mmap)
-            && path.extension().is_some_and(|ext| {
-                matches!(ext.to_str(), Some("gz" | "zst" | "zstd" | "bz2" | "bzip2"))
-            })
-        {
+        if TypeId::of::<R>() == TypeId::of::<Gtf>() {
+            return read_gxf_shared::<Gtf, R>(data, &self.options);
+        }
+        if TypeId::of::<R>() == TypeId::of::<Gff>() {
+            return read_gxf_shared::<Gff, R>(data, &self.options);
+        }
+
+        Err(ReaderError::Builder(
+            "ERROR: unsupported format for this reader".into(),
+        ))
+    }
+
+    /// Builds a `Reader` for GXF formats (GTF/GFF) from a filesystem path.
+    fn build_gxf_from_path(&self, path: PathBuf) -> ReaderResult<Reader<R>> {
+        if self.options.additional_fields_count() != 0 {
             return Err(ReaderError::Builder(
-                "ERROR: compression is only supported in buffered mode".into(),
+                "ERROR: additional fields are not supported for this format".into(),
             ));
         }
 
         let options = &self.options;
         if TypeId::of::<R>() == TypeId::of::<Gtf>() {
             return match self.mode {
-                ReaderMode::Default => {
-                    let records = gxf::read_gxf_file::<Gtf, _>(&path, options)?;
-                    Reader::from_preloaded_records(records)
-                }
+                ReaderMode::Default => read_gxf_path::<Gtf, R>(&path, options),
                 ReaderMode::Mmap => {
                     #[cfg(feature = "mmap")]
                     {
-                        let records = gxf::read_gxf_mmap::<Gtf, _>(&path, options)?;
-                        Reader::from_preloaded_records(records)
+                        read_gxf_mmap_path::<Gtf, R>(&path, options)
                     }
                     #[cfg(not(feature = "mmap"))]
                     {
@@ -722,15 +1811,11 @@ impl<R: BedFormat + Into<GenePred>> ReaderBuilder<R> {
 
         if TypeId::of::<R>() == TypeId::of::<Gff>() {
             return match self.mode {
-                ReaderMode::Default => {
-                    let records = gxf::read_gxf_file::<Gff, _>(&path, options)?;
-                    Reader::from_preloaded_records(records)
-                }
+                ReaderMode::Default => read_gxf_path::<Gff, R>(&path, options),
                 ReaderMode::Mmap => {
                     #[cfg(feature = "mmap")]
                     {
-                        let records = gxf::read_gxf_mmap::<Gff, _>(&path, options)?;
-                        Reader::from_preloaded_records(records)
+                        read_gxf_mmap_path::<Gff, R>(&path, options)
                     }
                     #[cfg(not(feature = "mmap"))]
                     {
@@ -748,30 +1833,170 @@ impl<R: BedFormat + Into<GenePred>> ReaderBuilder<R> {
     }
 }
 
+/// Builds a buffered GXF reader, streaming or preloading according to `options`.
+fn read_gxf_path<F, R>(path: &Path, options: &ReaderOptions<'_>) -> ReaderResult<Reader<R>>
+where
+    F: GxfFormat + Send + 'static,
+    R: BedFormat + Into<GenePred>,
+{
+    if options.is_streaming() {
+        let stream = gxf::stream_gxf_file::<F, _>(path, options)?;
+        read_gxf_stream(stream, options)
+    } else {
+        let (records, metadata) = gxf::read_gxf_file::<F, _>(path, options)?;
+        Reader::from_gxf_records(records, metadata)
+    }
+}
+
+/// Builds a GXF reader over in-memory bytes, streaming or preloading
+/// according to `options`.
+fn read_gxf_shared<F, R>(data: SharedData, options: &ReaderOptions<'_>) -> ReaderResult<Reader<R>>
+where
+    F: GxfFormat + Send + 'static,
+    R: BedFormat + Into<GenePred>,
+{
+    if options.is_streaming() {
+        let stream = gxf::GxfStream::<F, _>::new(io::Cursor::new(SharedSlice(data)), options);
+        read_gxf_stream(stream, options)
+    } else {
+        let (records, metadata) = gxf::read_gxf_bytes::<F>((*data).as_ref(), options)?;
+        Reader::from_gxf_records(records, metadata)
+    }
+}
+
+#[cfg(feature = "mmap")]
+/// Builds a memory-mapped GXF reader, streaming or preloading according to
+/// `options`.
+fn read_gxf_mmap_path<F, R>(path: &Path, options: &ReaderOptions<'_>) -> ReaderResult<Reader<R>>
+where
+    F: GxfFormat + Send + 'static,
+    R: BedFormat + Into<GenePred>,
+{
+    if options.is_streaming() {
+        let stream = gxf::stream_gxf_mmap::<F, _>(path, options)?;
+        read_gxf_stream(stream, options)
+    } else {
+        let (records, metadata) = gxf::read_gxf_mmap::<F, _>(path, options)?;
+        Reader::from_gxf_records(records, metadata)
+    }
+}
+
+/// Wraps a streaming GXF aggregator in a `Reader`, after consuming its header.
+fn read_gxf_stream<F, B, R>(
+    mut stream: gxf::GxfStream<F, B>,
+    options: &ReaderOptions<'_>,
+) -> ReaderResult<Reader<R>>
+where
+    F: GxfFormat + Send + 'static,
+    B: BufRead + Send + 'static,
+    R: BedFormat + Into<GenePred>,
+{
+    let metadata = stream.read_header()?;
+    let stream: RecordStream = match options.aggregation_level() {
+        AggregationLevel::Transcript => Box::new(stream),
+        AggregationLevel::Gene => Box::new(gxf::GeneMergeStream::new(stream)),
+    };
+    let mut reader = Reader::from_record_stream(stream)?;
+    reader.metadata = Some(metadata);
+    Ok(reader)
+}
+
+/// Identifies an aggregated GTF/GFF record for [`Reader::raw_line`].
+///
+/// Uses the transcript (or, for gene-level records, gene) identifier the
+/// record was grouped by, falling back to its name.
+fn record_label(record: &GenePred) -> String {
+    [b"transcript_id".as_ref(), b"ID", b"gene_id"]
+        .iter()
+        .find_map(|key| record.extras().get(*key).and_then(ExtraValue::first))
+        .or(record.name.as_deref())
+        .map(|label| String::from_utf8_lossy(label).into_owned())
+        .unwrap_or_default()
+}
+
 /// Source of data for the reader.
 enum ReaderSource {
     /// A filesystem path.
     Path(PathBuf),
     /// A generic reader.
     Reader(Box<dyn Read + Send>),
+    /// Uncompressed bytes already in memory.
+    Bytes(SharedData),
+}
+
+impl ReaderSource {
+    /// Returns a second handle to the same input, if it can be reopened.
+    fn reopenable(&self) -> Option<ReaderSource> {
+        match self {
+            ReaderSource::Path(path) => Some(ReaderSource::Path(path.clone())),
+            ReaderSource::Bytes(data) => Some(ReaderSource::Bytes(Arc::clone(data))),
+            ReaderSource::Reader(_) => None,
+        }
+    }
+}
+
+/// Shared in-memory bytes viewed as a slice, so they can back a `Cursor`.
+struct SharedSlice(SharedData);
+
+impl AsRef<[u8]> for SharedSlice {
+    fn as_ref(&self) -> &[u8] {
+        (*self.0).as_ref()
+    }
 }
 
 /// Internal reader source wrapping different input types.
 enum InnerSource {
     /// Buffered reader for streaming input.
     Buffered(BufReader<Box<dyn Read + Send>>),
-    /// Memory-mapped file input.
-    #[cfg(feature = "mmap")]
-    Mmap(MmapInner),
+    /// In-memory input: a memory-mapped file or caller-provided bytes.
+    Mapped(MappedInner),
 }
 
-/// Memory-mapped file input with cursor position.
-#[cfg(feature = "mmap")]
-struct MmapInner {
-    /// The memory-mapped data.
-    data: Arc<memmap2::Mmap>,
-    /// Current position in the mapped data.
+/// Shared, immutable input bytes.
+type SharedData = Arc<dyn AsRef<[u8]> + Send + Sync>;
+
+/// In-memory input with cursor position.
+struct MappedInner {
+    /// The mapped or borrowed data.
+    data: SharedData,
+    /// Current position in the data.
     cursor: usize,
+    /// Record lines read since the start of the data.
+    records_read: usize,
+    /// Record lines in the whole input, counted on first request.
+    record_lines: OnceLock<usize>,
+}
+
+impl MappedInner {
+    /// Creates a cursor over `data`, skipping a leading byte-order mark.
+    fn new(data: SharedData) -> Self {
+        let cursor = bom_len((*data).as_ref());
+        Self {
+            data,
+            cursor,
+            records_read: 0,
+            record_lines: OnceLock::new(),
+        }
+    }
+
+    /// Returns the full input.
+    fn bytes(&self) -> &[u8] {
+        (*self.data).as_ref()
+    }
+
+    /// Moves the cursor back to the first line.
+    fn restart(&mut self) {
+        self.cursor = bom_len(self.bytes());
+        self.records_read = 0;
+    }
+
+    /// Returns the record lines not yet read, counting the input only once.
+    fn remaining_records(&self, filter: LineFilter) -> usize {
+        let total = *self
+            .record_lines
+            .get_or_init(|| count_record_lines(&self.bytes()[bom_len(self.bytes())..], filter).1);
+        total.saturating_sub(self.records_read)
+    }
 }
 
 /// A reader for BED files.
@@ -806,19 +2031,128 @@ pub struct Reader<R: BedFormat + Into<GenePred>> {
     additional_fields: usize,
     line_number: usize,
     extra_keys: Vec<Vec<u8>>,
-    preloaded: Option<std::vec::IntoIter<GenePred>>,
+    lazy_extras: bool,
+    filter: LineFilter,
+    format: LineFormat,
+    error_policy: ErrorPolicy,
+    errors: Vec<ReaderError>,
+    chrom_style: Option<ChromStyle>,
+    chrom_prefixed: Option<bool>,
+    length_range: Option<(u64, Option<u64>)>,
+    filtered: usize,
+    retain_raw: bool,
+    raw: Option<String>,
+    dedup_consecutive: bool,
+    last_key: Option<(Vec<u8>, u64, u64, Option<Strand>)>,
+    bytes_read: u64,
+    source_bytes: Option<ByteCounter>,
+    progress: Option<ProgressFn>,
+    progress_reported: u64,
+    metadata: Option<GxfMetadata>,
+    preloaded: Option<PreloadedRecords>,
+    streamed: Option<RecordStream>,
+    rewind: Option<RewindFn<R>>,
     _marker: PhantomData<R>,
 }
 
-impl<R: BedFormat + Into<GenePred>> Reader<R> {
-    /// Creates a new `ReaderBuilder` to configure a `Reader`.
-    ///
-    /// # Example
-    ///
-    /// ```rust,no_run,ignore
-    /// use genepred::{Reader, Bed3};
-    ///
-    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// Records held in memory by a reader, yielded in order.
+enum PreloadedRecords {
+    /// Records owned by the reader and moved out as they are yielded.
+    Owned(std::vec::IntoIter<GenePred>),
+    /// Records shared with the reader's rewind closure, cloned one at a time
+    /// so rewinding only resets the cursor.
+    Shared {
+        records: Arc<[GenePred]>,
+        next: usize,
+    },
+}
+
+impl Iterator for PreloadedRecords {
+    type Item = GenePred;
+
+    fn next(&mut self) -> Option<GenePred> {
+        match self {
+            PreloadedRecords::Owned(iter) => iter.next(),
+            PreloadedRecords::Shared { records, next } => {
+                let record = records.get(*next)?.clone();
+                *next += 1;
+                Some(record)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = match self {
+            PreloadedRecords::Owned(iter) => iter.len(),
+            PreloadedRecords::Shared { records, next } => records.len() - next,
+        };
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for PreloadedRecords {}
+
+impl PreloadedRecords {
+    /// Skips the remaining records without cloning shared ones.
+    fn exhaust(&mut self) {
+        match self {
+            PreloadedRecords::Owned(iter) => iter.for_each(drop),
+            PreloadedRecords::Shared { records, next } => *next = records.len(),
+        }
+    }
+}
+
+/// Boxed record generator used by streaming GXF readers.
+type RecordStream = Box<dyn Iterator<Item = ReaderResult<GenePred>> + Send>;
+
+/// Reopens a reader's source from the beginning for [`Reader::rewind`].
+type RewindFn<R> = Box<dyn Fn() -> ReaderResult<Reader<R>> + Send>;
+
+/// Callback receiving the number of input bytes consumed so far.
+type ProgressFn = Box<dyn FnMut(u64) + Send>;
+
+/// Shared count of bytes read from an underlying file.
+type ByteCounter = Arc<AtomicU64>;
+
+/// Input bytes consumed between two progress callbacks.
+const PROGRESS_INTERVAL: u64 = 1 << 20;
+
+/// Counts the bytes read through an inner reader.
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+struct CountingReader<T> {
+    inner: T,
+    count: ByteCounter,
+}
+
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+impl<T> CountingReader<T> {
+    /// Wraps `inner`, adding every read to `count`.
+    fn new(inner: T, count: &ByteCounter) -> Self {
+        Self {
+            inner,
+            count: Arc::clone(count),
+        }
+    }
+}
+
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+impl<T: Read> Read for CountingReader<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.count.fetch_add(n as u64, AtomicOrdering::Relaxed);
+        Ok(n)
+    }
+}
+
+impl<R: BedFormat + Into<GenePred>> Reader<R> {
+    /// Creates a new `ReaderBuilder` to configure a `Reader`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run,ignore
+    /// use genepred::{Reader, Bed3};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let reader = Reader::builder()
     ///         .from_path("tests/data/simple.bed")?
     ///         .build()?;
@@ -881,6 +2215,50 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
         Self::builder().from_path(path).options(options).build()
     }
 
+    /// Creates a new `Reader` from a path, taking every setting from `options`.
+    ///
+    /// One `ReaderOptions` value (additional fields, mode, buffer capacity,
+    /// compression, ...) can be cloned and reused across files.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run,ignore
+    /// use genepred::{Reader, Bed3, ReaderMode, ReaderOptions};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let options = ReaderOptions::new().additional_fields(1).mode(ReaderMode::Mmap);
+    ///     let a = Reader::<Bed3>::from_path_custom_fields("a.bed", options.clone())?;
+    ///     let b = Reader::<Bed3>::from_path_custom_fields("b.bed", options)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_path_custom_fields<P: AsRef<Path>>(
+        path: P,
+        options: ReaderOptions<'_>,
+    ) -> ReaderResult<Self> {
+        Self::from_path_with_custom_fields(path, options)
+    }
+
+    /// Creates a new `Reader` from a reader, taking every setting from `options`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run,ignore
+    /// use genepred::{Reader, Bed3, ReaderOptions};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let options = ReaderOptions::new().additional_fields(1);
+    ///     let reader = Reader::<Bed3>::from_reader_custom_fields(std::io::stdin(), options)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_reader_custom_fields<T>(reader: T, options: ReaderOptions<'_>) -> ReaderResult<Self>
+    where
+        T: Read + Send + 'static,
+    {
+        Self::builder().from_reader(reader).options(options).build()
+    }
+
     /// Creates a new `Reader` from a reader.
     ///
     /// # Example
@@ -905,6 +2283,118 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
         Self::builder().from_reader(reader).build()
     }
 
+    /// Creates a new `Reader` over uncompressed bytes already in memory.
+    ///
+    /// Lines are scanned with `memchr` and parsed straight out of `bytes`,
+    /// like the mmap path but without a file, so nothing is copied through a
+    /// `BufReader`. Any owner of the bytes works: a `&'static [u8]`, a
+    /// `Vec<u8>` or an `Arc<[u8]>`. The reader can be rewound and iterated in
+    /// parallel. GTF/GFF bytes are aggregated like a GTF/GFF file, honouring
+    /// the GXF options passed through [`ReaderBuilder::options`].
+    ///
+    /// Unlike a `from_bytes(&'a [u8])` signature, `bytes` must be `'static`:
+    /// `Reader` has no lifetime parameter, so it cannot borrow a slice of a
+    /// shorter-lived buffer. Move the owning `Vec<u8>` (or `Arc<[u8]>`) in
+    /// rather than lending a slice of it; copying a borrowed slice with
+    /// `to_vec()` is the only option when the owner cannot be given up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::{Bed3, Reader};
+    ///
+    /// let mut reader = Reader::<Bed3>::from_bytes(&b"chr1\t10\t20\nchr1\t30\t40\n"[..]).unwrap();
+    /// let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    /// assert_eq!(records.len(), 2);
+    /// assert_eq!(records[1].start(), 30);
+    /// ```
+    pub fn from_bytes<B>(bytes: B) -> ReaderResult<Self>
+    where
+        B: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        Self::builder().from_bytes(bytes).build()
+    }
+
+    /// Creates a new `Reader` that reads several files as one stream.
+    ///
+    /// Files are opened one at a time, in order, each with its own
+    /// compression detection as in [`Reader::from_path`]. Errors are wrapped
+    /// in [`ReaderError::InFile`], carrying the file index alongside the line
+    /// number within that file. GTF/GFF files are aggregated per file and
+    /// the results concatenated, so a transcript split across files yields
+    /// one record per file. The reader can be rewound.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run,ignore
+    /// use genepred::{Reader, Bed6};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let reader = Reader::<Bed6>::from_paths(["chr1.bed", "chr2.bed.gz"])?;
+    ///     for record in reader {
+    ///         let record = record?;
+    ///         // ...
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_paths<I, P>(paths: I) -> ReaderResult<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+        R: Send + 'static,
+    {
+        let paths: Vec<PathBuf> = paths.into_iter().map(Into::into).collect();
+        for (index, path) in paths.iter().enumerate() {
+            std::fs::metadata(path).map_err(|err| ReaderError::InFile {
+                index,
+                path: path.clone(),
+                source: Box::new(err.into()),
+            })?;
+        }
+
+        let stream = MultiPathStream::<R> {
+            paths: paths.clone().into_iter().enumerate(),
+            current: None,
+        };
+        let mut reader = Self::from_record_stream(Box::new(stream))?;
+        reader.rewind = Some(Box::new(move || Self::from_paths(paths.clone())));
+        Ok(reader)
+    }
+
+    /// Creates a new `Reader` over records that are already in memory.
+    ///
+    /// The records are yielded unchanged and in order by `records()`,
+    /// `par_records()` and `par_chunks()`, so in-memory data can share the
+    /// same iteration and writing code as file input. The records are kept
+    /// once behind an `Arc`; each yielded record is a clone, and
+    /// [`Reader::rewind`] only resets the cursor.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run,ignore
+    /// use genepred::{Reader, Bed12, GenePred, Extras};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let records = vec![GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new())];
+    ///     let mut reader = Reader::<Bed12>::from_records(records)?;
+    ///
+    ///     for record in reader.records() {
+    ///         // ...
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_records(records: Vec<GenePred>) -> ReaderResult<Self> {
+        let records: Arc<[GenePred]> = records.into();
+        let mut reader = Self::from_shared_records(Arc::clone(&records))?;
+        reader.rewind = Some(Box::new(move || {
+            Self::from_shared_records(Arc::clone(&records))
+        }));
+        Ok(reader)
+    }
+
     /// Creates a new Reader from a stream.
     ///
     /// # Example
@@ -936,10 +2426,66 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
             line_number: 0,
             extra_keys,
             preloaded: None,
+            lazy_extras: false,
+            filter: LineFilter::default(),
+            format: LineFormat::default(),
+            error_policy: ErrorPolicy::default(),
+            errors: Vec::new(),
+            chrom_style: None,
+            chrom_prefixed: None,
+            length_range: None,
+            filtered: 0,
+            retain_raw: false,
+            raw: None,
+            dedup_consecutive: false,
+            last_key: None,
+            bytes_read: 0,
+            source_bytes: None,
+            progress: None,
+            progress_reported: 0,
+            metadata: None,
+            streamed: None,
+            rewind: None,
             _marker: PhantomData,
         })
     }
 
+    /// Creates a new `Reader` over shared in-memory bytes.
+    ///
+    /// Lines are located with `memchr` and parsed in place, as for
+    /// memory-mapped files.
+    fn from_shared_bytes(data: SharedData, additional_fields: usize) -> Self {
+        Self {
+            inner: InnerSource::Mapped(MappedInner::new(data)),
+            buffer: String::with_capacity(1024),
+            additional_fields,
+            line_number: 0,
+            extra_keys: build_extra_keys(R::FIELD_COUNT, additional_fields),
+            preloaded: None,
+            lazy_extras: false,
+            filter: LineFilter::default(),
+            format: LineFormat::default(),
+            error_policy: ErrorPolicy::default(),
+            errors: Vec::new(),
+            chrom_style: None,
+            chrom_prefixed: None,
+            length_range: None,
+            filtered: 0,
+            retain_raw: false,
+            raw: None,
+            dedup_consecutive: false,
+            last_key: None,
+            bytes_read: 0,
+            source_bytes: None,
+            progress: None,
+            progress_reported: 0,
+            metadata: None,
+            streamed: None,
+            rewind: None,
+            _marker: PhantomData,
+        }
+    }
+
     /// Creates a new `Reader` from preloaded `GenePred` records.
     ///
     /// This internal function is used to create readers that iterate over
@@ -972,7 +2518,33 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
     /// ```
     pub(crate) fn from_preloaded_records(records: Vec<GenePred>) -> ReaderResult<Self> {
         let mut reader = Self::from_stream(Box::new(io::empty()), 0, 1)?;
-        reader.preloaded = Some(records.into_iter());
+        reader.preloaded = Some(PreloadedRecords::Owned(records.into_iter()));
+        reader.extra_keys = Vec::new();
+        Ok(reader)
+    }
+
+    /// Creates a new `Reader` that yields clones of shared in-memory records.
+    fn from_shared_records(records: Arc<[GenePred]>) -> ReaderResult<Self> {
+        let mut reader = Self::from_stream(Box::new(io::empty()), 0, 1)?;
+        reader.preloaded = Some(PreloadedRecords::Shared { records, next: 0 });
+        reader.extra_keys = Vec::new();
+        Ok(reader)
+    }
+
+    /// Creates a new `Reader` over aggregated GXF records and their header pragmas.
+    pub(crate) fn from_gxf_records(
+        records: Vec<GenePred>,
+        metadata: GxfMetadata,
+    ) -> ReaderResult<Self> {
+        let mut reader = Self::from_preloaded_records(records)?;
+        reader.metadata = Some(metadata);
+        Ok(reader)
+    }
+
+    /// Creates a new `Reader` that pulls records from a streaming GXF aggregator.
+    pub(crate) fn from_record_stream(stream: RecordStream) -> ReaderResult<Self> {
+        let mut reader = Self::from_stream(Box::new(io::empty()), 0, 1)?;
+        reader.streamed = Some(stream);
         reader.extra_keys = Vec::new();
         Ok(reader)
     }
@@ -1015,29 +2587,24 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
 
         if TypeId::of::<R>() == TypeId::of::<Gtf>() {
             let options = ReaderOptions::default();
-            let records = gxf::read_gxf_mmap::<Gtf, _>(path, &options)?;
-            return Reader::from_preloaded_records(records);
+            let (records, metadata) = gxf::read_gxf_mmap::<Gtf, _>(path, &options)?;
+            return Reader::from_gxf_records(records, metadata);
         } else if TypeId::of::<R>() == TypeId::of::<Gff>() {
             let options = ReaderOptions::default();
-            let records = gxf::read_gxf_mmap::<Gff, _>(path, &options)?;
-            return Reader::from_preloaded_records(records);
+            let (records, metadata) = gxf::read_gxf_mmap::<Gff, _>(path, &options)?;
+            return Reader::from_gxf_records(records, metadata);
         }
 
-        let map =
-            unsafe { MmapOptions::new().map(&File::open(path)?) }.map_err(ReaderError::Mmap)?;
+        Self::map_path(path, 0)
+    }
 
-        Ok(Self {
-            inner: InnerSource::Mmap(MmapInner {
-                data: map.into(),
-                cursor: 0,
-            }),
-            buffer: String::with_capacity(1024),
-            additional_fields: 0,
-            line_number: 0,
-            extra_keys: Vec::new(),
-            preloaded: None,
-            _marker: PhantomData,
-        })
+    /// Maps a BED-like file and reads `additional_fields` trailing columns
+    /// from each line.
+    #[cfg(feature = "mmap")]
+    fn map_path(path: &Path, additional_fields: usize) -> ReaderResult<Self> {
+        let map =
+            unsafe { MmapOptions::new().map(&open_file(path)?) }.map_err(ReaderError::Mmap)?;
+        Ok(Self::from_shared_bytes(Arc::new(map), additional_fields))
     }
 
     /// Creates a new `Reader` with custom reader options from a memory-mapped file.
@@ -1086,25 +2653,255 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
             .build()
     }
 
-    /// Returns the number of additional fields expected in each record.
+    /// Returns the number of additional fields expected in each record.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run,ignore
+    /// use genepred::{Reader, Bed3};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let reader = Reader::<Bed3>::from_path("tests/data/simple.bed")?;
+    ///     let additional_fields = reader.additional_fields();
+    ///     assert_eq!(additional_fields, 0);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn additional_fields(&self) -> usize {
+        self.additional_fields
+    }
+
+    /// Returns the current line number of the reader.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run,ignore
+    /// use genepred::{Reader, Bed3};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let reader = Reader::<Bed3>::from_path("tests/data/simple.bed")?;
+    ///     let line_number = reader.current_line();
+    ///     assert_eq!(line_number, 0);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn current_line(&self) -> usize {
+        self.line_number
+    }
+
+    /// Returns the header pragmas of a GTF/GFF input.
+    ///
+    /// `None` for BED readers. Streaming GXF readers only capture the
+    /// pragmas preceding the first feature line.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run,ignore
+    /// use genepred::{Gff, Reader};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let reader = Reader::<Gff>::from_gxf("tests/data/pragmas.gff")?;
+    ///     if let Some(metadata) = reader.metadata() {
+    ///         println!("{:?}", metadata.genome_build);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn metadata(&self) -> Option<&GxfMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns the number of input bytes consumed so far.
+    ///
+    /// Memory-mapped and in-memory sources report the cursor offset, and
+    /// buffered sources the length of the lines read. Compressed files opened
+    /// by path report the compressed bytes read from disk, which runs ahead
+    /// of parsing by the decoder's buffering. At end of an uncompressed input
+    /// this equals its size. Readers that preload GTF/GFF records report `0`.
+    pub fn bytes_consumed(&self) -> u64 {
+        match (&self.inner, &self.source_bytes) {
+            (InnerSource::Mapped(inner), _) => inner.cursor as u64,
+            (InnerSource::Buffered(_), Some(counter)) => counter.load(AtomicOrdering::Relaxed),
+            (InnerSource::Buffered(_), None) => self.bytes_read,
+        }
+    }
+
+    /// Returns the errors of lines skipped under [`ErrorPolicy::Collect`].
+    ///
+    /// The buffer grows as iteration proceeds and is cleared by
+    /// [`Reader::rewind`].
+    pub fn errors(&self) -> &[ReaderError] {
+        &self.errors
+    }
+
+    /// Returns how many records [`ReaderOptions::length_range`] has dropped.
+    ///
+    /// The count grows as iteration proceeds and is reset by
+    /// [`Reader::rewind`].
+    pub fn filtered_count(&self) -> usize {
+        self.filtered
+    }
+
+    /// Returns the source text of the most recently read record.
+    ///
+    /// Only available with [`ReaderOptions::retain_raw`]; for GTF/GFF
+    /// readers this is the transcript identifier rather than a line.
+    pub fn raw_line(&self) -> Option<&str> {
+        self.raw.as_deref()
+    }
+
+    /// Resets the reader to the first record so the input can be read again.
+    ///
+    /// Memory-mapped inputs move their cursor back to the start. Readers built
+    /// from a path reopen it with the same options, and in-memory readers from
+    /// [`Reader::from_records`] restart over their retained records. Readers
+    /// over an arbitrary `Read` stream cannot be rewound.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run,ignore
+    /// use genepred::{Reader, Bed3};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut reader = Reader::<Bed3>::from_path("tests/data/simple.bed")?;
+    ///     let total = reader.records().count();
+    ///     reader.rewind()?;
+    ///     assert_eq!(reader.records().count(), total);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn rewind(&mut self) -> ReaderResult<()> {
+        if let InnerSource::Mapped(inner) = &mut self.inner {
+            inner.restart();
+            self.line_number = 0;
+            self.errors.clear();
+            self.chrom_prefixed = None;
+            self.filtered = 0;
+            self.raw = None;
+            self.last_key = None;
+            self.progress_reported = 0;
+            return Ok(());
+        }
+
+        let rewind = self.rewind.take().ok_or_else(|| {
+            ReaderError::Builder("ERROR: cannot rewind a non-seekable stream source".into())
+        })?;
+        let mut fresh = match rewind() {
+            Ok(fresh) => fresh,
+            Err(err) => {
+                self.rewind = Some(rewind);
+                return Err(err);
+            }
+        };
+        fresh.lazy_extras = self.lazy_extras;
+        fresh.filter = self.filter;
+        fresh.format = self.format;
+        fresh.error_policy = self.error_policy;
+        fresh.chrom_style = self.chrom_style;
+        fresh.length_range = self.length_range;
+        fresh.retain_raw = self.retain_raw;
+        fresh.dedup_consecutive = self.dedup_consecutive;
+        fresh.progress = self.progress.take();
+        fresh.rewind = Some(rewind);
+        *self = fresh;
+        Ok(())
+    }
+
+    /// Returns an iterator over the records in the reader.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run,ignore
+    /// use genepred::{Reader, Bed3};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let reader = Reader::from_path("tests/data/simple.bed")?;
+    ///     for record in reader.records() {
+    ///         let record = record?;
+    ///         // ...
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn records(&mut self) -> Records<'_, R> {
+        Records { reader: self }
+    }
+
+    /// Consumes the reader into an owning iterator over its records.
+    ///
+    /// Unlike [`Reader::records`], the iterator does not borrow the reader,
+    /// so it can be returned from a function or moved to another thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run,ignore
+    /// use genepred::{Reader, Bed3};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let records = Reader::<Bed3>::from_path("tests/data/simple.bed")?.into_records();
+    ///     let handle = std::thread::spawn(move || records.count());
+    ///     let total = handle.join().unwrap();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn into_records(self) -> IntoRecords<R> {
+        IntoRecords { reader: self }
+    }
+
+    /// Returns an iterator pairing each record with its source text.
+    ///
+    /// Turns on [`ReaderOptions::retain_raw`] for this reader. See
+    /// [`Reader::raw_line`] for what the text holds per format.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run,ignore
+    /// use genepred::{Reader, Bed6};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut reader = Reader::<Bed6>::from_path("tests/data/bed6.bed")?;
+    ///     for result in reader.records_with_raw() {
+    ///         let (record, line) = result?;
+    ///         // ...
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn records_with_raw(&mut self) -> RawRecords<'_, R> {
+        self.retain_raw = true;
+        RawRecords { reader: self }
+    }
+
+    /// Returns an iterator that rewrites each record's chromosome name to
+    /// follow `style`.
+    ///
+    /// See [`GenePred::normalize_chrom`] for the applied mappings.
     ///
     /// # Example
     ///
     /// ```rust,no_run,ignore
-    /// use genepred::{Reader, Bed3};
+    /// use genepred::{Bed3, ChromStyle, Reader};
     ///
     /// fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let reader = Reader::<Bed3>::from_path("tests/data/simple.bed")?;
-    ///     let additional_fields = reader.additional_fields();
-    ///     assert_eq!(additional_fields, 0);
+    ///     let mut reader = Reader::<Bed3>::from_path("tests/data/ensembl.bed")?;
+    ///     for record in reader.normalize_chroms(ChromStyle::Ucsc) {
+    ///         let record = record?;
+    ///         // ...
+    ///     }
     ///     Ok(())
     /// }
     /// ```
-    pub fn additional_fields(&self) -> usize {
-        self.additional_fields
+    pub fn normalize_chroms(&mut self, style: ChromStyle) -> NormalizedRecords<'_, R> {
+        NormalizedRecords {
+            reader: self,
+            style,
+        }
     }
 
-    /// Returns the current line number of the reader.
+    /// Folds every record into an accumulator, stopping at the first error.
+    ///
+    /// This consumes the reader and works the same for line-based and
+    /// preloaded (GTF/GFF or in-memory) sources.
     ///
     /// # Example
     ///
@@ -1113,16 +2910,31 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
     ///
     /// fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let reader = Reader::<Bed3>::from_path("tests/data/simple.bed")?;
-    ///     let line_number = reader.current_line();
-    ///     assert_eq!(line_number, 0);
+    ///     let total = reader.fold_records(0u64, |acc, record| acc + record.len())?;
+    ///     println!("{total} bp");
     ///     Ok(())
     /// }
     /// ```
-    pub fn current_line(&self) -> usize {
-        self.line_number
+    pub fn fold_records<B, F>(mut self, init: B, mut f: F) -> ReaderResult<B>
+    where
+        F: FnMut(B, GenePred) -> B,
+    {
+        let mut acc = init;
+        while let Some(record) = self.next_record() {
+            acc = f(acc, record?);
+        }
+        Ok(acc)
     }
 
-    /// Returns an iterator over the records in the reader.
+    /// Counts the remaining records, leaving the reader at end of input.
+    ///
+    /// BED lines are counted without being parsed, skipping the same blank,
+    /// comment, `track` and `browser` lines as iteration. When the reader can
+    /// drop records while iterating ([`ReaderOptions::length_range`],
+    /// [`ReaderOptions::dedup_consecutive`] or an [`ErrorPolicy`] other than
+    /// `Fail`), records are parsed and counted as iteration would yield them
+    /// instead. This is O(n) in the remaining input; call [`Reader::rewind`]
+    /// to read the records afterwards.
     ///
     /// # Example
     ///
@@ -1130,21 +2942,89 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
     /// use genepred::{Reader, Bed3};
     ///
     /// fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let reader = Reader::from_path("tests/data/simple.bed")?;
-    ///     for record in reader.records() {
-    ///         let record = record?;
-    ///         // ...
-    ///     }
+    ///     let mut reader = Reader::<Bed3>::from_path("tests/data/simple.bed")?;
+    ///     let total = reader.count_records()?;
+    ///     println!("{total} records");
     ///     Ok(())
     /// }
     /// ```
-    pub fn records(&mut self) -> Records<'_, R> {
-        Records { reader: self }
+    pub fn count_records(&mut self) -> ReaderResult<usize> {
+        let mut count = 0usize;
+        if self.drops_records() {
+            while let Some(record) = self.next_record() {
+                record?;
+                count += 1;
+            }
+            return Ok(count);
+        }
+
+        if let Some(stream) = self.streamed.take() {
+            for record in stream {
+                record?;
+                count += 1;
+            }
+        }
+        if let Some(iter) = self.preloaded.as_mut() {
+            count += iter.len();
+            iter.exhaust();
+        }
+
+        if let InnerSource::Mapped(inner) = &mut self.inner {
+            let (lines, records) = count_record_lines(&inner.bytes()[inner.cursor..], self.filter);
+            inner.cursor = inner.bytes().len();
+            inner.records_read += records;
+            self.line_number += lines;
+            return Ok(count + records);
+        }
+
+        while self.fill_buffer()? {
+            self.line_number += 1;
+            if !self.filter.skips(self.buffer.as_bytes()) {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Returns true if iteration can drop records that are present in the input.
+    fn drops_records(&self) -> bool {
+        self.length_range.is_some()
+            || self.dedup_consecutive
+            || self.error_policy != ErrorPolicy::Fail
+    }
+
+    /// Returns an estimate of the remaining records when one is cheap to get.
+    ///
+    /// Answers for preloaded sources (GTF/GFF and [`Reader::from_records`])
+    /// and in-memory BED input (memory-mapped files and [`Reader::from_bytes`]),
+    /// without consuming any input. In-memory BED input is scanned once, on
+    /// the first call, and the count is reused afterwards.
+    ///
+    /// The value is a hint: record lines are counted without being parsed, so
+    /// malformed lines are included. Readers that drop records while
+    /// iterating (see [`Reader::count_records`]), and buffered or streaming
+    /// sources, return `None`; use [`Reader::count_records`] there.
+    pub fn record_count_hint(&self) -> Option<usize> {
+        if self.streamed.is_some() || self.drops_records() {
+            return None;
+        }
+        if let Some(iter) = &self.preloaded {
+            return Some(iter.len());
+        }
+
+        if let InnerSource::Mapped(inner) = &self.inner {
+            return Some(inner.remaining_records(self.filter));
+        }
+        None
     }
 
     /// Returns a parallel iterator over the records in the reader.
     ///
-    /// This requires the `rayon` feature.
+    /// This requires the `rayon` feature. Fails with [`ReaderError::Builder`]
+    /// when [`ReaderOptions::on_error`], [`ReaderOptions::length_range`],
+    /// [`ReaderOptions::enforce_chrom_style`] or
+    /// [`ReaderOptions::dedup_consecutive`] is set, since those are only
+    /// applied by sequential iteration.
     ///
     /// # Example
     ///
@@ -1164,6 +3044,7 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
     /// ```
     #[cfg(feature = "rayon")]
     pub fn par_records(self) -> ReaderResult<ParallelRecords<R>> {
+        self.check_parallel_options()?;
         let (input, additional_fields) = self.into_parallel_input()?;
         Ok(ParallelRecords {
             input,
@@ -1174,7 +3055,8 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
 
     /// Returns a parallel iterator over chunks of the records in the reader.
     ///
-    /// This requires the `rayon` feature.
+    /// This requires the `rayon` feature. Rejects the same sequential-only
+    /// options as [`Reader::par_records`].
     ///
     /// # Example
     ///
@@ -1200,8 +3082,13 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
                 "ERROR: chunk_size must be greater than 0".into(),
             ));
         }
+        self.check_parallel_options()?;
 
         let mut reader = self;
+        if let Some(stream) = reader.streamed.take() {
+            let records = stream.collect::<ReaderResult<Vec<_>>>()?;
+            reader.preloaded = Some(PreloadedRecords::Owned(records.into_iter()));
+        }
         if let Some(iter) = reader.preloaded.take() {
             let input = ParallelInput::Preloaded(iter.collect());
             return Ok(ParallelChunks {
@@ -1219,6 +3106,8 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
                     additional_fields: reader.additional_fields,
                     extra_keys: Arc::new(reader.extra_keys.clone()),
                     line_number: reader.line_number,
+                    filter: reader.filter,
+                    format: reader.format,
                     chunk_idx: 0,
                     buf: Vec::with_capacity(1024),
                     _marker: PhantomData,
@@ -1230,17 +3119,21 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
                     _marker: PhantomData,
                 })
             }
-            #[cfg(feature = "mmap")]
-            InnerSource::Mmap(inner) => {
+            InnerSource::Mapped(inner) => {
                 let extra_keys = Arc::new(reader.extra_keys.clone());
                 let base = inner.cursor;
-                let data = inner.data.clone();
-                let spans = build_line_spans(&data[base..], base, reader.line_number);
+                let spans = build_line_spans(
+                    &inner.bytes()[base..],
+                    base,
+                    reader.line_number,
+                    reader.filter,
+                );
 
                 let input = ParallelInput::Bytes {
-                    data: SharedBytes::Mmap(data),
+                    data: SharedBytes::Mapped(inner.data),
                     spans,
                     extra_keys,
+                    format: reader.format,
                 };
 
                 Ok(ParallelChunks {
@@ -1252,11 +3145,34 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
         }
     }
 
+    /// Rejects options that are only applied by sequential iteration.
+    #[cfg(feature = "rayon")]
+    fn check_parallel_options(&self) -> ReaderResult<()> {
+        let option = if self.error_policy != ErrorPolicy::Fail {
+            "on_error"
+        } else if self.length_range.is_some() {
+            "length_range"
+        } else if self.chrom_style.is_some() {
+            "enforce_chrom_style"
+        } else if self.dedup_consecutive {
+            "dedup_consecutive"
+        } else {
+            return Ok(());
+        };
+        Err(ReaderError::Builder(format!(
+            "ERROR: {option} is not supported by parallel iteration; use records() instead"
+        )))
+    }
+
     /// Convert the reader into a parallel reader.
     #[cfg(feature = "rayon")]
     fn into_parallel_input(mut self) -> ReaderResult<(ParallelInput, usize)> {
         let additional_fields = self.additional_fields;
         let extra_keys = Arc::new(self.extra_keys.clone());
+        if let Some(stream) = self.streamed.take() {
+            let records = stream.collect::<ReaderResult<Vec<_>>>()?;
+            return Ok((ParallelInput::Preloaded(records), additional_fields));
+        }
         if let Some(iter) = self.preloaded.take() {
             return Ok((ParallelInput::Preloaded(iter.collect()), additional_fields));
         }
@@ -1266,26 +3182,32 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
                 let mut data = Vec::new();
                 reader.read_to_end(&mut data)?;
                 let data = Arc::new(data);
-                let spans = build_line_spans(&data, 0, self.line_number);
+                let base = if self.line_number == 0 {
+                    bom_len(&data)
+                } else {
+                    0
+                };
+                let spans = build_line_spans(&data[base..], base, self.line_number, self.filter);
                 Ok((
                     ParallelInput::Bytes {
                         data: SharedBytes::Owned(data),
                         spans,
                         extra_keys: extra_keys.clone(),
+                        format: self.format,
                     },
                     additional_fields,
                 ))
             }
-            #[cfg(feature = "mmap")]
-            InnerSource::Mmap(inner) => {
+            InnerSource::Mapped(inner) => {
                 let base = inner.cursor;
-                let data = inner.data.clone();
-                let spans = build_line_spans(&data[base..], base, self.line_number);
+                let spans =
+                    build_line_spans(&inner.bytes()[base..], base, self.line_number, self.filter);
                 Ok((
                     ParallelInput::Bytes {
-                        data: SharedBytes::Mmap(data),
+                        data: SharedBytes::Mapped(inner.data),
                         spans,
                         extra_keys: extra_keys.clone(),
+                        format: self.format,
                     },
                     additional_fields,
                 ))
@@ -1314,40 +3236,158 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
     /// ```
     fn next_record(&mut self) -> Option<ReaderResult<GenePred>> {
         loop {
-            if let Some(iter) = self.preloaded.as_mut() {
-                if let Some(record) = iter.next() {
-                    return Some(Ok(record));
+            let next = self
+                .read_record()
+                .map(|record| record.and_then(|record| self.check_chrom_style(record)));
+            self.report_progress(next.is_none());
+            match next {
+                Some(Err(err))
+                    if self.error_policy != ErrorPolicy::Fail && err.is_recoverable() =>
+                {
+                    if self.error_policy == ErrorPolicy::Collect {
+                        self.errors.push(err);
+                    }
+                }
+                Some(Ok(record)) if !self.in_length_range(&record) => self.filtered += 1,
+                Some(Ok(record)) if self.repeats_last(&record) => {}
+                other => return other,
+            }
+        }
+    }
+
+    /// Returns true if `record` has the coordinates of the previous record
+    /// and consecutive duplicates are being skipped.
+    fn repeats_last(&mut self, record: &GenePred) -> bool {
+        if !self.dedup_consecutive {
+            return false;
+        }
+        let key = (record.start, record.end, record.strand);
+        match &mut self.last_key {
+            Some((chrom, start, end, strand))
+                if chrom.as_slice() == record.chrom.as_slice()
+                    && (*start, *end, *strand) == key =>
+            {
+                true
+            }
+            Some((chrom, start, end, strand)) => {
+                chrom.clear();
+                chrom.extend_from_slice(&record.chrom);
+                (*start, *end, *strand) = key;
+                false
+            }
+            None => {
+                self.last_key = Some((record.chrom.clone(), key.0, key.1, key.2));
+                false
+            }
+        }
+    }
+
+    /// Returns true if `record` passes the configured length bounds.
+    fn in_length_range(&self, record: &GenePred) -> bool {
+        let Some((min, max)) = self.length_range else {
+            return true;
+        };
+        let len = record.len();
+        len >= min && max.is_none_or(|max| len <= max)
+    }
+
+    /// Invokes the progress callback once enough input has been consumed,
+    /// or with the final count at end of input.
+    fn report_progress(&mut self, finished: bool) {
+        if self.progress.is_none() {
+            return;
+        }
+        let consumed = self.bytes_consumed();
+        let due = consumed >= self.progress_reported.saturating_add(PROGRESS_INTERVAL)
+            || (finished && consumed != self.progress_reported);
+        if let (true, Some(progress)) = (due, self.progress.as_mut()) {
+            progress(consumed);
+            self.progress_reported = consumed;
+        }
+    }
+
+    /// Rejects a record whose chromosome name breaks the enforced style.
+    fn check_chrom_style(&mut self, record: GenePred) -> ReaderResult<GenePred> {
+        let Some(style) = self.chrom_style else {
+            return Ok(record);
+        };
+        let prefixed = record.chrom.starts_with(b"chr");
+        let expected = match style {
+            ChromStyle::Ucsc => true,
+            ChromStyle::Ensembl => false,
+            ChromStyle::Consistent => *self.chrom_prefixed.get_or_insert(prefixed),
+        };
+        if prefixed == expected {
+            return Ok(record);
+        }
+
+        let chrom = String::from_utf8_lossy(&record.chrom);
+        let rule = if expected {
+            "a `chr` prefix"
+        } else {
+            "no `chr` prefix"
+        };
+        Err(ReaderError::invalid_field(
+            self.line_number,
+            "chrom",
+            format!(
+                "ERROR: chromosome '{chrom}' breaks the {style:?} naming style, expected {rule} in {}:chrom",
+                self.line_number
+            ),
+        ))
+    }
+
+    /// Reads the next record, returning malformed lines as errors.
+    fn read_record(&mut self) -> Option<ReaderResult<GenePred>> {
+        loop {
+            if let Some(stream) = self.streamed.as_mut() {
+                if let Some(record) = stream.next() {
+                    if let (true, Ok(record)) = (self.retain_raw, &record) {
+                        self.raw = Some(record_label(record));
+                    }
+                    return Some(record);
                 }
-                self.preloaded = None;
+                self.streamed = None;
                 continue;
             }
 
+            if let Some(iter) = self.preloaded.as_mut() {
+                let record = iter.next()?;
+                if self.retain_raw {
+                    self.raw = Some(record_label(&record));
+                }
+                return Some(Ok(record));
+            }
+
             match &mut self.inner {
                 InnerSource::Buffered(_) => match self.fill_buffer() {
                     Ok(true) => {
                         self.line_number += 1;
-                        if should_skip(&self.buffer) {
+                        if self.filter.skips(self.buffer.as_bytes()) {
                             continue;
                         }
-                        let parsed = parse_line_bytes::<R>(
+                        if self.retain_raw {
+                            self.raw = Some(self.buffer.clone());
+                        }
+                        let parsed = parse_record::<R>(
                             self.buffer.as_bytes(),
                             self.additional_fields,
                             &self.extra_keys,
                             self.line_number,
-                        )
-                        .map(Into::into);
+                            self.lazy_extras,
+                            self.format,
+                        );
                         return Some(parsed);
                     }
                     Ok(false) => return None,
                     Err(err) => return Some(Err(err)),
                 },
-                #[cfg(feature = "mmap")]
-                InnerSource::Mmap(inner) => {
-                    if inner.cursor >= inner.data.len() {
+                InnerSource::Mapped(inner) => {
+                    let data = (*inner.data).as_ref();
+                    if inner.cursor >= data.len() {
                         return None;
                     }
 
-                    let data = &inner.data;
                     let start = inner.cursor;
                     let rel_end = memchr(b'\n', &data[start..]).map(|idx| start + idx);
                     let line_end = rel_end.unwrap_or(data.len());
@@ -1362,17 +3402,22 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
                     self.line_number += 1;
 
                     let line_bytes = &data[start..end];
-                    if should_skip_bytes(line_bytes) {
+                    if self.filter.skips(line_bytes) {
                         continue;
                     }
+                    inner.records_read += 1;
+                    if self.retain_raw {
+                        self.raw = Some(String::from_utf8_lossy(line_bytes).into_owned());
+                    }
 
-                    let parsed = parse_line_bytes::<R>(
+                    let parsed = parse_record::<R>(
                         line_bytes,
                         self.additional_fields,
                         &self.extra_keys,
                         self.line_number,
-                    )
-                    .map(Into::into);
+                        self.lazy_extras,
+                        self.format,
+                    );
 
                     return Some(parsed);
                 }
@@ -1402,16 +3447,19 @@ impl<R: BedFormat + Into<GenePred>> Reader<R> {
                 if bytes == 0 {
                     return Ok(false);
                 }
+                self.bytes_read += bytes as u64;
                 trim_line(&mut self.buffer);
+                if self.line_number == 0 && self.buffer.starts_with('\u{FEFF}') {
+                    self.buffer.drain(..'\u{FEFF}'.len_utf8());
+                }
                 Ok(true)
             }
-            #[cfg(feature = "mmap")]
-            InnerSource::Mmap(inner) => {
-                if inner.cursor >= inner.data.len() {
+            InnerSource::Mapped(inner) => {
+                let data = &(*inner.data).as_ref()[inner.cursor..];
+                if data.is_empty() {
                     return Ok(false);
                 }
 
-                let data = &inner.data[inner.cursor..];
                 let mut len = 0usize;
 
                 for byte in data {
@@ -1454,8 +3502,11 @@ impl Reader<Gtf> {
         path: P,
         options: ReaderOptions<'a>,
     ) -> ReaderResult<Self> {
-        let records = gxf::read_gxf_file::<Gtf, _>(path, &options)?;
-        Reader::from_preloaded_records(records)
+        let path = path.as_ref().to_path_buf();
+        let options = options.into_owned();
+        let mut reader = read_gxf_path::<Gtf, Gtf>(&path, &options)?;
+        reader.rewind = Some(Box::new(move || read_gxf_path::<Gtf, Gtf>(&path, &options)));
+        Ok(reader)
     }
 
     #[cfg(feature = "mmap")]
@@ -1464,8 +3515,7 @@ impl Reader<Gtf> {
         path: P,
         options: ReaderOptions<'a>,
     ) -> ReaderResult<Self> {
-        let records = gxf::read_gxf_mmap::<Gtf, _>(path, &options)?;
-        Reader::from_preloaded_records(records)
+        read_gxf_mmap_path::<Gtf, Gtf>(path.as_ref(), &options)
     }
 }
 
@@ -1480,8 +3530,11 @@ impl Reader<Gff> {
         path: P,
         options: ReaderOptions<'a>,
     ) -> ReaderResult<Self> {
-        let records = gxf::read_gxf_file::<Gff, _>(path, &options)?;
-        Reader::from_preloaded_records(records)
+        let path = path.as_ref().to_path_buf();
+        let options = options.into_owned();
+        let mut reader = read_gxf_path::<Gff, Gff>(&path, &options)?;
+        reader.rewind = Some(Box::new(move || read_gxf_path::<Gff, Gff>(&path, &options)));
+        Ok(reader)
     }
 
     #[cfg(feature = "mmap")]
@@ -1490,8 +3543,7 @@ impl Reader<Gff> {
         path: P,
         options: ReaderOptions<'a>,
     ) -> ReaderResult<Self> {
-        let records = gxf::read_gxf_mmap::<Gff, _>(path, &options)?;
-        Reader::from_preloaded_records(records)
+        read_gxf_mmap_path::<Gff, Gff>(path.as_ref(), &options)
     }
 }
 
@@ -1503,6 +3555,48 @@ impl<R: BedFormat + Into<GenePred>> Iterator for Reader<R> {
     }
 }
 
+/// Record stream chaining the readers of several files.
+struct MultiPathStream<R: BedFormat + Into<GenePred>> {
+    /// Remaining inputs with their position in the path list.
+    paths: std::iter::Enumerate<std::vec::IntoIter<PathBuf>>,
+    /// The input currently being read.
+    current: Option<(usize, PathBuf, Reader<R>)>,
+}
+
+impl<R: BedFormat + Into<GenePred>> Iterator for MultiPathStream<R> {
+    type Item = ReaderResult<GenePred>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((index, path, reader)) = self.current.as_mut() {
+                match reader.next_record() {
+                    Some(Ok(record)) => return Some(Ok(record)),
+                    Some(Err(err)) => {
+                        return Some(Err(ReaderError::InFile {
+                            index: *index,
+                            path: path.clone(),
+                            source: Box::new(err),
+                        }))
+                    }
+                    None => self.current = None,
+                }
+            }
+
+            let (index, path) = self.paths.next()?;
+            match Reader::<R>::from_path(&path) {
+                Ok(reader) => self.current = Some((index, path, reader)),
+                Err(err) => {
+                    return Some(Err(ReaderError::InFile {
+                        index,
+                        path,
+                        source: Box::new(err),
+                    }))
+                }
+            }
+        }
+    }
+}
+
 /// Iterator over records from a `Reader`.
 ///
 /// Created by the [`Reader::records`] method.
@@ -1518,6 +3612,65 @@ impl<'a, R: BedFormat + Into<GenePred>> Iterator for Records<'a, R> {
     }
 }
 
+/// Iterator over records with normalized chromosome names.
+///
+/// Created by the [`Reader::normalize_chroms`] method.
+pub struct NormalizedRecords<'a, R: BedFormat + Into<GenePred>> {
+    reader: &'a mut Reader<R>,
+    style: ChromStyle,
+}
+
+impl<'a, R: BedFormat + Into<GenePred>> Iterator for NormalizedRecords<'a, R> {
+    type Item = ReaderResult<GenePred>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record = self.reader.next_record()?;
+        if let Ok(record) = record.as_mut() {
+            record.normalize_chrom(self.style);
+        }
+        Some(record)
+    }
+}
+
+/// Iterator over records paired with their source text.
+///
+/// Created by the [`Reader::records_with_raw`] method.
+pub struct RawRecords<'a, R: BedFormat + Into<GenePred>> {
+    reader: &'a mut Reader<R>,
+}
+
+impl<'a, R: BedFormat + Into<GenePred>> Iterator for RawRecords<'a, R> {
+    type Item = ReaderResult<(GenePred, String)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = self.reader.next_record()?;
+        Some(record.map(|record| (record, self.reader.raw.take().unwrap_or_default())))
+    }
+}
+
+/// Owning iterator over records from a `Reader`.
+///
+/// Created by the [`Reader::into_records`] method. It is `Send` whenever the
+/// record format is, so it can feed a parsing thread.
+pub struct IntoRecords<R: BedFormat + Into<GenePred>> {
+    reader: Reader<R>,
+}
+
+impl<R: BedFormat + Into<GenePred>> IntoRecords<R> {
+    /// Returns the underlying reader, e.g. to inspect [`Reader::errors`].
+    pub fn reader(&self) -> &Reader<R> {
+        &self.reader
+    }
+}
+
+impl<R: BedFormat + Into<GenePred>> Iterator for IntoRecords<R> {
+    type Item = ReaderResult<GenePred>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.next_record()
+    }
+}
+
 /// Represents a line range for parallel parsing.
 #[cfg(feature = "rayon")]
 #[derive(Clone)]
@@ -1534,9 +3687,8 @@ struct LineSpan {
 #[cfg(feature = "rayon")]
 #[derive(Clone)]
 enum SharedBytes {
-    /// Memory-mapped data.
-    #[cfg(feature = "mmap")]
-    Mmap(Arc<memmap2::Mmap>),
+    /// Memory-mapped or caller-provided data.
+    Mapped(SharedData),
     /// Owned buffer data.
     Owned(Arc<Vec<u8>>),
 }
@@ -1546,8 +3698,7 @@ impl SharedBytes {
     /// Get bytes as slice
     fn as_slice(&self) -> &[u8] {
         match self {
-            #[cfg(feature = "mmap")]
-            SharedBytes::Mmap(map) => map.as_ref(),
+            SharedBytes::Mapped(data) => (**data).as_ref(),
             SharedBytes::Owned(bytes) => bytes.as_slice(),
         }
     }
@@ -1568,6 +3719,7 @@ enum ParallelInput {
         data: SharedBytes,
         spans: Vec<LineSpan>,
         extra_keys: Arc<Vec<Vec<u8>>>,
+        format: LineFormat,
     },
 }
 
@@ -1615,6 +3767,8 @@ struct StreamChunkIter<R: BedFormat + Into<GenePred>> {
     additional_fields: usize,
     extra_keys: Arc<Vec<Vec<u8>>>,
     line_number: usize,
+    filter: LineFilter,
+    format: LineFormat,
     chunk_idx: usize,
     buf: Vec<u8>,
     _marker: PhantomData<R>,
@@ -1637,6 +3791,7 @@ impl<R: BedFormat + Into<GenePred> + Send> ParallelIterator for ParallelRecords<
                 data,
                 spans,
                 extra_keys,
+                format,
             } => {
                 let additional = self.additional_fields;
                 spans
@@ -1647,8 +3802,9 @@ impl<R: BedFormat + Into<GenePred> + Send> ParallelIterator for ParallelRecords<
                             additional,
                             extra_keys.as_slice(),
                             span.line_no,
+                            format,
                         )
-                        .map(Into::into)
+                        .and_then(|record| finish_record(record, format, span.line_no))
                     })
                     .drive_unindexed(consumer)
             }
@@ -1699,6 +3855,7 @@ impl<R: BedFormat + Into<GenePred> + Send> ParallelIterator for ParallelChunks<R
                     data,
                     spans,
                     extra_keys,
+                    format,
                 } => {
                     let additional = self.additional_fields;
                     spans
@@ -1714,8 +3871,9 @@ impl<R: BedFormat + Into<GenePred> + Send> ParallelIterator for ParallelChunks<R
                                         additional,
                                         extra_keys.as_slice(),
                                         span.line_no,
+                                        format,
                                     )
-                                    .map(Into::into);
+                                    .and_then(|record| finish_record(record, format, span.line_no));
                                     out.push(parsed);
                                 }
                                 (chunk_idx, out)
@@ -1750,9 +3908,15 @@ impl<R: BedFormat + Into<GenePred>> Iterator for StreamChunkIter<R> {
                         end -= 1;
                     }
 
+                    let start = if self.line_number == 0 {
+                        bom_len(&self.buf[..end])
+                    } else {
+                        0
+                    };
+
                     self.line_number += 1;
-                    let line = &self.buf[..end];
-                    if should_skip_bytes(line) {
+                    let line = &self.buf[start..end];
+                    if self.filter.skips(line) {
                         continue;
                     }
 
@@ -1761,8 +3925,9 @@ impl<R: BedFormat + Into<GenePred>> Iterator for StreamChunkIter<R> {
                         self.additional_fields,
                         &self.extra_keys,
                         self.line_number,
+                        self.format,
                     )
-                    .map(Into::into);
+                    .and_then(|record| finish_record(record, self.format, self.line_number));
                     out.push(parsed);
                 }
                 Err(err) => {
@@ -1814,12 +3979,18 @@ fn _parse_line<R: BedFormat>(
     line_number: usize,
 ) -> ReaderResult<R> {
     let keys = build_extra_keys(R::FIELD_COUNT, additional_fields);
-    parse_line_bytes::<R>(line.as_bytes(), additional_fields, &keys, line_number)
+    parse_line_bytes::<R>(
+        line.as_bytes(),
+        additional_fields,
+        &keys,
+        line_number,
+        LineFormat::default(),
+    )
 }
 
 /// Parses a line from a BED file (bytes version).
 ///
-/// Converts tab-separated bytes to a BedFormat record.
+/// Converts delimited bytes to a BedFormat record.
 ///
 /// # Arguments
 ///
@@ -1827,12 +3998,135 @@ fn _parse_line<R: BedFormat>(
 /// * `additional_fields` - Number of extra columns.
 /// * `extra_keys` - Keys for extra columns.
 /// * `line_number` - Current line number for errors.
+/// * `format` - Delimiter and score settings.
 fn parse_line_bytes<R: BedFormat>(
     line: &[u8],
     additional_fields: usize,
     extra_keys: &[Vec<u8>],
     line_number: usize,
+    format: LineFormat,
 ) -> ReaderResult<R> {
+    parse_line_parts::<R>(
+        line,
+        additional_fields,
+        extra_keys,
+        line_number,
+        format,
+        false,
+    )
+    .map(|(record, _)| record)
+}
+
+/// Parses one BED line into a `GenePred`, optionally deferring its extras.
+///
+/// # Arguments
+///
+/// * `line` - Input line bytes.
+/// * `additional_fields` - Number of extra columns.
+/// * `extra_keys` - Keys for extra columns.
+/// * `line_number` - Current line number for errors.
+/// * `lazy_extras` - Keep trailing columns raw until first access.
+/// * `format` - Delimiter and coordinate settings.
+fn parse_record<R: BedFormat + Into<GenePred>>(
+    line: &[u8],
+    additional_fields: usize,
+    extra_keys: &[Vec<u8>],
+    line_number: usize,
+    lazy_extras: bool,
+    format: LineFormat,
+) -> ReaderResult<GenePred> {
+    // Deferred extras are re-split on tabs, so other delimiters parse eagerly.
+    let defer = lazy_extras && additional_fields > 0 && format.delimiter == b'\t';
+    let (record, tail) = parse_line_parts::<R>(
+        line,
+        additional_fields,
+        extra_keys,
+        line_number,
+        format,
+        defer,
+    )?;
+    let mut gene = finish_record(record, format, line_number)?;
+    if let Some(tail) = tail {
+        gene.defer_extras(tail, R::FIELD_COUNT + 1);
+    }
+    Ok(gene)
+}
+
+/// Validates a parsed BED record's blocks when requested, then converts it.
+fn finish_record<R: BedFormat + Into<GenePred>>(
+    record: R,
+    format: LineFormat,
+    line_number: usize,
+) -> ReaderResult<GenePred> {
+    if format.validate_blocks {
+        record.validate_blocks(line_number)?;
+    }
+    check_span(into_genepred(record, format.one_based), line_number)
+}
+
+/// Rejects a record whose end lies before its start.
+fn check_span(gene: GenePred, line_number: usize) -> ReaderResult<GenePred> {
+    if gene.end < gene.start {
+        return Err(ReaderError::invalid_field(
+            line_number,
+            "coordinates",
+            format!(
+                "ERROR: end ({}) must be >= start ({}) in {line_number}:coordinates",
+                gene.end, gene.start
+            ),
+        ));
+    }
+    Ok(gene)
+}
+
+/// Converts a parsed BED record, shifting 1-based starts when requested.
+///
+/// `thick_start` is left alone when it equals `thick_end`, which marks a
+/// record without a coding region.
+fn into_genepred<R: Into<GenePred>>(record: R, one_based: bool) -> GenePred {
+    let mut gene: GenePred = record.into();
+    if one_based {
+        gene.start = gene.start.saturating_sub(1);
+        if let (Some(thick_start), Some(thick_end)) = (gene.thick_start, gene.thick_end) {
+            if thick_start != thick_end {
+                gene.thick_start = Some(thick_start.saturating_sub(1));
+            }
+        }
+        // Blocks are placed from chromStart, so both of their bounds move.
+        for blocks in [gene.block_starts.as_mut(), gene.block_ends.as_mut()]
+            .into_iter()
+            .flatten()
+        {
+            for position in blocks {
+                *position = position.saturating_sub(1);
+            }
+        }
+    }
+    gene
+}
+
+/// Splits and validates a BED line.
+///
+/// When `defer_extras` is set, the record is built without extras and the
+/// raw trailing columns are returned alongside it.
+///
+/// # Arguments
+///
+/// * `line` - Input line bytes.
+/// * `additional_fields` - Number of extra columns.
+/// * `extra_keys` - Keys for extra columns.
+/// * `line_number` - Current line number for errors.
+/// * `delimiter` - Byte separating fields; non-tab whitespace runs collapse.
+/// * `defer_extras` - Return trailing columns raw instead of building extras.
+fn parse_line_parts<R: BedFormat>(
+    line: &[u8],
+    additional_fields: usize,
+    extra_keys: &[Vec<u8>],
+    line_number: usize,
+    format: LineFormat,
+    defer_extras: bool,
+) -> ReaderResult<(R, Option<Vec<u8>>)> {
+    let delimiter = format.delimiter;
     let mut start = 0usize;
     let mut end = line.len();
 
@@ -1857,14 +4151,23 @@ fn parse_line_bytes<R: BedFormat>(
     let expected_fields = R::FIELD_COUNT + additional_fields;
     fields.reserve(expected_fields.max(4));
 
+    let mut tail_start = end;
+    let collapse = delimiter.is_ascii_whitespace() && delimiter != b'\t';
+
     for i in start..=end {
-        if i == end || line[i] == b'\t' {
-            if i > field_start {
-                let slice = &line[field_start..i];
-                let text = std::str::from_utf8(slice)
-                    .map_err(|err| ReaderError::invalid_encoding(line_number, err.to_string()))?;
-                fields.push(text);
+        if i == end || line[i] == delimiter {
+            if collapse && i == field_start {
+                field_start = i + 1;
+                continue;
+            }
+            // Empty interior columns are kept so later fields stay in place.
+            if fields.len() == R::FIELD_COUNT {
+                tail_start = field_start;
             }
+            let slice = &line[field_start..i];
+            let text = std::str::from_utf8(slice)
+                .map_err(|err| ReaderError::invalid_encoding(line_number, err.to_string()))?;
+            fields.push(text);
 
             field_start = i + 1;
         }
@@ -1886,6 +4189,17 @@ fn parse_line_bytes<R: BedFormat>(
         ));
     }
 
+    if format.lenient_score {
+        if let Some(score) = R::SCORE_FIELD.and_then(|idx| fields.get_mut(idx)) {
+            *score = __clamp_score(score);
+        }
+    }
+
+    if defer_extras {
+        let record = R::from_fields(&fields[..R::FIELD_COUNT], Extras::new(), line_number)?;
+        return Ok((record, Some(line[tail_start..end].to_vec())));
+    }
+
     let extras = if additional_fields == 0 {
         Extras::new()
     } else {
@@ -1903,7 +4217,7 @@ fn parse_line_bytes<R: BedFormat>(
         extras
     };
 
-    R::from_fields(&fields[..R::FIELD_COUNT], extras, line_number)
+    R::from_fields(&fields[..R::FIELD_COUNT], extras, line_number).map(|record| (record, None))
 }
 
 /// Builds numeric extra keys for a BED layout.
@@ -1932,7 +4246,14 @@ pub(crate) fn parse_bed_line_bytes<R>(
 where
     R: BedFormat + Into<GenePred>,
 {
-    parse_line_bytes::<R>(line, additional_fields, extra_keys, line_number).map(Into::into)
+    parse_line_bytes::<R>(
+        line,
+        additional_fields,
+        extra_keys,
+        line_number,
+        LineFormat::default(),
+    )
+    .and_then(|record| check_span(record.into(), line_number))
 }
 
 /// Converts a number to a buffer of ASCII digits.
@@ -2002,6 +4323,21 @@ fn build_extra_keys(base_field_count: usize, additional_fields: usize) -> Vec<Ve
     keys
 }
 
+/// UTF-8 byte-order mark emitted by some Windows tools.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Returns the length of a leading UTF-8 byte-order mark, or `0` if absent.
+///
+/// Only the very start of the input is inspected; callers are responsible
+/// for calling this before the first line is consumed.
+fn bom_len(data: &[u8]) -> usize {
+    if data.starts_with(UTF8_BOM) {
+        UTF8_BOM.len()
+    } else {
+        0
+    }
+}
+
 /// Trim a line of a BED file.
 ///
 /// This function is used by BED line parsing.
@@ -2011,38 +4347,83 @@ fn trim_line(line: &mut String) {
     }
 }
 
-/// Returns `true` if the line should be skipped.
-///
-/// This function is used by BED line parsing.
-fn should_skip(line: &str) -> bool {
-    let trimmed = line.trim();
-    trimmed.is_empty()
-        || trimmed.starts_with('#')
-        || trimmed.starts_with("track ")
-        || trimmed.starts_with("browser ")
+/// Per-line BED parsing settings shared by the sequential and parallel paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LineFormat {
+    /// Byte separating fields.
+    delimiter: u8,
+    /// Whether starts are shifted from 1-based to 0-based.
+    one_based: bool,
+    /// Whether blocks are checked against the feature span.
+    validate_blocks: bool,
+    /// Whether scores above 1000 are clamped instead of rejected.
+    lenient_score: bool,
 }
 
-/// Returns `true` if the line should be skipped.
-///
-/// This function is used by [`Reader::parse_line`] and [`Reader::parse_lines`].
-#[cfg(any(feature = "rayon", feature = "mmap"))]
-fn should_skip_bytes(line: &[u8]) -> bool {
-    let mut start = 0usize;
-    let mut end = line.len();
-
-    while start < end && line[start].is_ascii_whitespace() {
-        start += 1;
+impl Default for LineFormat {
+    fn default() -> Self {
+        Self {
+            delimiter: b'\t',
+            one_based: false,
+            validate_blocks: false,
+            lenient_score: false,
+        }
     }
-    while start < end && line[end - 1].is_ascii_whitespace() {
-        end -= 1;
+}
+
+/// Rules deciding which input lines are not records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LineFilter {
+    /// Leading byte marking comment lines.
+    comment: u8,
+    /// Whether `track` and `browser` lines are skipped.
+    skip_track_lines: bool,
+}
+
+impl Default for LineFilter {
+    fn default() -> Self {
+        Self {
+            comment: b'#',
+            skip_track_lines: true,
+        }
     }
+}
 
-    if start == end {
-        return true;
+impl LineFilter {
+    /// Returns `true` if the line is blank, a comment, or (when enabled) a
+    /// UCSC `track`/`browser` line.
+    fn skips(&self, line: &[u8]) -> bool {
+        let trimmed = line.trim_ascii();
+        match trimmed.first() {
+            None => true,
+            Some(byte) if *byte == self.comment => true,
+            Some(_) => {
+                self.skip_track_lines
+                    && (trimmed.starts_with(b"track ") || trimmed.starts_with(b"browser "))
+            }
+        }
     }
+}
 
-    let trimmed = &line[start..end];
-    trimmed.starts_with(b"#") || trimmed.starts_with(b"track ") || trimmed.starts_with(b"browser ")
+/// Counts lines and non-skipped record lines in a byte buffer.
+///
+/// # Arguments
+///
+/// * `data` - Newline-separated BED content.
+/// * `filter` - Rules for lines that are not records.
+fn count_record_lines(data: &[u8], filter: LineFilter) -> (usize, usize) {
+    let mut lines = 0usize;
+    let mut records = 0usize;
+    for line in data.split(|byte| *byte == b'\n') {
+        lines += 1;
+        if !filter.skips(line) {
+            records += 1;
+        }
+    }
+    if data.is_empty() || data.ends_with(b"\n") {
+        lines -= 1;
+    }
+    (lines, records)
 }
 
 /// Build line spans for parallel parsing
@@ -2056,12 +4437,17 @@ fn should_skip_bytes(line: &[u8]) -> bool {
 ///
 /// fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let mut reader = Reader::<Bed3>::from_path("tests/data/simple.bed")?;
-///     let line_spans = build_line_spans(&reader.buffer, 0, reader.line_number);
+///     let line_spans = build_line_spans(&reader.buffer, 0, reader.line_number, reader.filter);
 ///     Ok(())
 /// }
 /// ```
 #[cfg(feature = "rayon")]
-fn build_line_spans(data: &[u8], base_offset: usize, starting_line: usize) -> Vec<LineSpan> {
+fn build_line_spans(
+    data: &[u8],
+    base_offset: usize,
+    starting_line: usize,
+    filter: LineFilter,
+) -> Vec<LineSpan> {
     let mut spans = Vec::with_capacity(memchr_iter(b'\n', data).count() + 1);
     let mut offset = 0usize;
     let mut line_no = starting_line;
@@ -2078,7 +4464,7 @@ fn build_line_spans(data: &[u8], base_offset: usize, starting_line: usize) -> Ve
         line_no += 1;
         let next_offset = rel_end.map(|pos| pos + 1).unwrap_or(data.len());
 
-        if !should_skip_bytes(&data[line_start..end]) {
+        if !filter.skips(&data[line_start..end]) {
             spans.push(LineSpan {
                 line_no,
                 start: base_offset + line_start,
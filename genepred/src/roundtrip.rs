@@ -0,0 +1,171 @@
+// Copyright (c) 2026 Alejandro Gonzales-Irribarren <alejandrxgzi@gmail.com>
+// Distributed under the terms of the Apache License, Version 2.0.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::Path;
+
+use crate::{
+    bed::BedFormat,
+    genepred::GenePred,
+    reader::{Reader, ReaderError, ReaderResult},
+    writer::{TargetFormat, Writer, WriterError},
+};
+
+/// Errors that can occur while verifying a round trip.
+#[derive(Debug)]
+pub enum RoundtripError {
+    /// Reading the input or the re-read output failed.
+    Reader(ReaderError),
+    /// Writing the intermediate output failed.
+    Writer(WriterError),
+}
+
+impl fmt::Display for RoundtripError {
+    /// Formats the roundtrip error for display.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundtripError::Reader(err) => write!(f, "roundtrip read failed: {err}"),
+            RoundtripError::Writer(err) => write!(f, "roundtrip write failed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RoundtripError {
+    /// Returns the source error.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RoundtripError::Reader(err) => Some(err),
+            RoundtripError::Writer(err) => Some(err),
+        }
+    }
+}
+
+impl From<ReaderError> for RoundtripError {
+    fn from(err: ReaderError) -> Self {
+        RoundtripError::Reader(err)
+    }
+}
+
+impl From<WriterError> for RoundtripError {
+    fn from(err: WriterError) -> Self {
+        RoundtripError::Writer(err)
+    }
+}
+
+/// Fields of a single record that changed during a round trip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordDifference {
+    /// Zero-based index of the record in input order.
+    pub index: usize,
+    /// Names of the `GenePred` fields that differ, in declaration order.
+    pub fields: Vec<&'static str>,
+}
+
+/// Outcome of [`verify_roundtrip`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoundtripReport {
+    /// Number of records read from the input.
+    pub input_records: usize,
+    /// Number of records read back from the written output.
+    pub output_records: usize,
+    /// Records whose fields did not survive the round trip.
+    pub differences: Vec<RecordDifference>,
+}
+
+impl RoundtripReport {
+    /// Returns `true` when every record survived unchanged.
+    pub fn is_lossless(&self) -> bool {
+        self.input_records == self.output_records && self.differences.is_empty()
+    }
+
+    /// Returns the distinct field names that differ in any record.
+    pub fn lost_fields(&self) -> BTreeSet<&'static str> {
+        self.differences
+            .iter()
+            .flat_map(|diff| diff.fields.iter().copied())
+            .collect()
+    }
+}
+
+/// Checks whether converting `input` from `R` to `F` is lossless.
+///
+/// The input is read as `R`, written as `F` into memory, read back as `F`,
+/// and both record sets are compared field by field in order. Extras are
+/// compared as in [`GenePred::structurally_eq`], so the order of array
+/// values (e.g. merged GTF `tag`s) does not count as a loss.
+///
+/// # Example
+///
+/// ```
+/// use genepred::{verify_roundtrip, Bed12, Bed6};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let path = std::path::Path::new("tests/data/bed12.bed");
+/// assert!(verify_roundtrip::<Bed12, Bed12>(path)?.is_lossless());
+///
+/// let report = verify_roundtrip::<Bed12, Bed6>(path)?;
+/// assert!(report.lost_fields().contains("thick_start"));
+/// # Ok(())
+/// # }
+/// ```
+pub fn verify_roundtrip<R, F>(input: &Path) -> Result<RoundtripReport, RoundtripError>
+where
+    R: BedFormat + Into<GenePred>,
+    F: BedFormat + Into<GenePred> + TargetFormat + 'static,
+{
+    let mut reader = Reader::<R>::from_path(input)?;
+    let original = reader.records().collect::<ReaderResult<Vec<_>>>()?;
+
+    let mut buffer = Vec::new();
+    Writer::<F>::from_records(&original, &mut buffer)?;
+    let reread = read_back::<F>(buffer)?;
+
+    let differences = original
+        .iter()
+        .zip(&reread)
+        .enumerate()
+        .filter_map(|(index, (before, after))| {
+            let fields = differing_fields(before, after);
+            (!fields.is_empty()).then_some(RecordDifference { index, fields })
+        })
+        .collect();
+
+    Ok(RoundtripReport {
+        input_records: original.len(),
+        output_records: reread.len(),
+        differences,
+    })
+}
+
+/// Parses a written buffer back into records of format `F`.
+fn read_back<F>(buffer: Vec<u8>) -> ReaderResult<Vec<GenePred>>
+where
+    F: BedFormat + Into<GenePred> + 'static,
+{
+    let mut reader = Reader::<F>::from_bytes(buffer)?;
+    reader.records().collect()
+}
+
+/// Lists the `GenePred` fields that differ between two records.
+fn differing_fields(a: &GenePred, b: &GenePred) -> Vec<&'static str> {
+    let checks = [
+        ("chrom", a.chrom() == b.chrom()),
+        ("start", a.start() == b.start()),
+        ("end", a.end() == b.end()),
+        ("name", a.name() == b.name()),
+        ("strand", a.strand() == b.strand()),
+        ("thick_start", a.thick_start() == b.thick_start()),
+        ("thick_end", a.thick_end() == b.thick_end()),
+        ("score", a.score() == b.score()),
+        ("item_rgb", a.item_rgb() == b.item_rgb()),
+        ("block_count", a.block_count() == b.block_count()),
+        ("block_starts", a.block_starts() == b.block_starts()),
+        ("block_ends", a.block_ends() == b.block_ends()),
+        ("extras", a.extras_structurally_eq(b)),
+    ];
+    checks
+        .into_iter()
+        .filter_map(|(field, same)| (!same).then_some(field))
+        .collect()
+}
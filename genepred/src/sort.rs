@@ -0,0 +1,73 @@
+// Copyright (c) 2026 Alejandro Gonzales-Irribarren <alejandrxgzi@gmail.com>
+// Distributed under the terms of the Apache License, Version 2.0.
+
+use std::cmp::Ordering;
+
+use crate::genepred::GenePred;
+
+/// Compares records by chromosome in natural (version-style) order.
+///
+/// Digit runs in chromosome names compare numerically, so `chr2` sorts
+/// before `chr10`. Records on the same chromosome fall back to the
+/// [`Ord`] implementation of [`GenePred`] (start, end, then name).
+///
+/// # Example
+///
+/// ```
+/// use genepred::genepred::{Extras, GenePred};
+/// use genepred::sort::natural_chrom_cmp;
+///
+/// let mut records = vec![
+///     GenePred::from_coords(b"chr10".to_vec(), 0, 10, Extras::new()),
+///     GenePred::from_coords(b"chr2".to_vec(), 0, 10, Extras::new()),
+/// ];
+/// records.sort_by(natural_chrom_cmp);
+/// assert_eq!(records[0].chrom(), b"chr2");
+/// ```
+pub fn natural_chrom_cmp(a: &GenePred, b: &GenePred) -> Ordering {
+    natural_cmp(a.chrom(), b.chrom()).then_with(|| a.cmp(b))
+}
+
+/// Compares two byte strings, treating digit runs as numbers.
+///
+/// Names that compare equal numerically but differ in leading zeros
+/// (`chr01` vs `chr1`) are ordered bytewise to keep the order total.
+pub fn natural_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i].is_ascii_digit() && b[j].is_ascii_digit() {
+            let a_end = digit_run_end(a, i);
+            let b_end = digit_run_end(b, j);
+            let a_num = trim_leading_zeros(&a[i..a_end]);
+            let b_num = trim_leading_zeros(&b[j..b_end]);
+            let ord = a_num.len().cmp(&b_num.len()).then(a_num.cmp(b_num));
+            if ord != Ordering::Equal {
+                return ord;
+            }
+            i = a_end;
+            j = b_end;
+        } else {
+            let ord = a[i].cmp(&b[j]);
+            if ord != Ordering::Equal {
+                return ord;
+            }
+            i += 1;
+            j += 1;
+        }
+    }
+    (a.len() - i).cmp(&(b.len() - j)).then_with(|| a.cmp(b))
+}
+
+/// Returns the index one past the digit run starting at `start`.
+fn digit_run_end(bytes: &[u8], start: usize) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|byte| !byte.is_ascii_digit())
+        .map_or(bytes.len(), |offset| start + offset)
+}
+
+/// Strips leading `0`s, keeping at least one digit.
+fn trim_leading_zeros(digits: &[u8]) -> &[u8] {
+    let zeros = digits.iter().take_while(|byte| **byte == b'0').count();
+    &digits[zeros.min(digits.len() - 1)..]
+}
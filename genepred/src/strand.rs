@@ -2,6 +2,7 @@
 // Distributed under the terms of the Apache License, Version 2.0.
 
 use std::fmt;
+use std::str::FromStr;
 
 use crate::reader::{ReaderError, ReaderResult};
 
@@ -17,7 +18,7 @@ use crate::reader::{ReaderError, ReaderResult};
 /// let strand = Strand::Forward;
 /// assert_eq!(strand, Strand::Forward);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Strand {
     /// Positive strand (`+`).
     Forward,
@@ -28,6 +29,45 @@ pub enum Strand {
 }
 
 impl Strand {
+    /// Returns the complementary strand.
+    ///
+    /// `Forward` and `Reverse` swap; `Unknown` stays `Unknown`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::strand::Strand;
+    ///
+    /// assert_eq!(Strand::Forward.opposite(), Strand::Reverse);
+    /// assert_eq!(Strand::Unknown.opposite(), Strand::Unknown);
+    /// ```
+    pub fn opposite(self) -> Strand {
+        match self {
+            Strand::Forward => Strand::Reverse,
+            Strand::Reverse => Strand::Forward,
+            Strand::Unknown => Strand::Unknown,
+        }
+    }
+
+    /// Returns the strand as its single-character symbol (`+`, `-` or `.`).
+    pub fn as_char(self) -> char {
+        match self {
+            Strand::Forward => '+',
+            Strand::Reverse => '-',
+            Strand::Unknown => '.',
+        }
+    }
+
+    /// Returns `true` for the positive strand.
+    pub fn is_forward(self) -> bool {
+        self == Strand::Forward
+    }
+
+    /// Returns `true` for the negative strand.
+    pub fn is_reverse(self) -> bool {
+        self == Strand::Reverse
+    }
+
     /// Parses a string into a `Strand`.
     ///
     /// # Errors
@@ -47,25 +87,61 @@ impl Strand {
     /// }
     /// ```
     pub(crate) fn parse(raw: &str, line: usize) -> ReaderResult<Self> {
+        raw.parse().map_err(|err: StrandParseError| {
+            ReaderError::invalid_field(line, "strand", format!("ERROR: {err} in {line}:strand"))
+        })
+    }
+}
+
+impl FromStr for Strand {
+    type Err = StrandParseError;
+
+    /// Parses `+`, `-`, `.` or `?` into a `Strand`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genepred::strand::Strand;
+    ///
+    /// assert_eq!("+".parse::<Strand>(), Ok(Strand::Forward));
+    /// assert_eq!("?".parse::<Strand>(), Ok(Strand::Unknown));
+    /// assert!("x".parse::<Strand>().is_err());
+    /// ```
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
         match raw {
             "+" => Ok(Strand::Forward),
             "-" => Ok(Strand::Reverse),
             "." | "?" => Ok(Strand::Unknown),
-            other => Err(ReaderError::invalid_field(
-                line,
-                "strand",
-                format!("ERROR: expected '+', '-', '.', or '?', got '{other}' in {line}:strand"),
-            )),
+            other => Err(StrandParseError {
+                value: other.to_string(),
+            }),
         }
     }
 }
 
+/// Error returned when a string is not a valid strand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrandParseError {
+    value: String,
+}
+
+impl StrandParseError {
+    /// Returns the rejected input.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for StrandParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected '+', '-', '.', or '?', got '{}'", self.value)
+    }
+}
+
+impl std::error::Error for StrandParseError {}
+
 impl fmt::Display for Strand {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Strand::Forward => f.write_str("+"),
-            Strand::Reverse => f.write_str("-"),
-            Strand::Unknown => f.write_str("."),
-        }
+        write!(f, "{}", self.as_char())
     }
 }
@@ -0,0 +1,192 @@
+// Copyright (c) 2026 Alejandro Gonzales-Irribarren <alejandrxgzi@gmail.com>
+// Distributed under the terms of the Apache License, Version 2.0.
+
+use crate::{
+    bed::{__to_u32, __to_u64, BedFormat},
+    genepred::{ExtraValue, Extras, GenePred},
+    reader::{ReaderError, ReaderResult},
+    strand::Strand,
+};
+
+const TX_START: &str = "txStart";
+const TX_END: &str = "txEnd";
+const CDS_START: &str = "cdsStart";
+const CDS_END: &str = "cdsEnd";
+const EXON_COUNT: &str = "exonCount";
+const EXON_STARTS: &str = "exonStarts";
+const EXON_ENDS: &str = "exonEnds";
+
+/// Extras key used to carry the refFlat `geneName` column.
+pub const GENE_NAME_KEY: &[u8] = b"gene_name";
+
+/// A UCSC genePred table record (10 columns).
+///
+/// `name chrom strand txStart txEnd cdsStart cdsEnd exonCount exonStarts exonEnds`
+///
+/// Coordinates are 0-based half-open like BED, and the exon lists hold
+/// absolute, comma-terminated positions. Non-coding transcripts use
+/// `cdsStart == cdsEnd`.
+///
+/// # Example
+///
+/// ```
+/// use genepred::bed::BedFormat;
+/// use genepred::genepred::Extras;
+/// use genepred::table::GenePredTable;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let fields = &[
+///     "tx1", "chr1", "+", "100", "400", "120", "360", "2", "100,300,", "180,400,",
+/// ];
+/// let record = GenePredTable::from_fields(fields, Extras::new(), 1)?;
+/// assert_eq!(record.exon_starts, vec![100, 300]);
+/// assert_eq!(record.exon_ends, vec![180, 400]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenePredTable {
+    /// The transcript name.
+    pub name: Vec<u8>,
+    /// The chromosome or scaffold of the transcript.
+    pub chrom: Vec<u8>,
+    /// The strand of the transcript.
+    pub strand: Strand,
+    /// The 0-based transcription start position.
+    pub tx_start: u64,
+    /// The transcription end position.
+    pub tx_end: u64,
+    /// The 0-based coding start position.
+    pub cds_start: u64,
+    /// The coding end position.
+    pub cds_end: u64,
+    /// The number of exons.
+    pub exon_count: u32,
+    /// Absolute exon start positions.
+    pub exon_starts: Vec<u64>,
+    /// Absolute exon end positions.
+    pub exon_ends: Vec<u64>,
+    /// Any extra fields beyond the standard genePred columns.
+    pub extras: Extras,
+}
+
+impl BedFormat for GenePredTable {
+    const FIELD_COUNT: usize = 10;
+
+    fn from_fields(fields: &[&str], extras: Extras, line: usize) -> ReaderResult<Self> {
+        parse_table(fields, extras, line)
+    }
+}
+
+/// A UCSC refFlat record (11 columns).
+///
+/// Identical to [`GenePredTable`] with a leading `geneName` column. When
+/// converted into a [`GenePred`], the gene name is stored in the extras under
+/// [`GENE_NAME_KEY`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefFlat {
+    /// The gene name.
+    pub gene_name: Vec<u8>,
+    /// The remaining genePred columns.
+    pub record: GenePredTable,
+}
+
+/// Marker type for UCSC PSL output (21 columns).
+///
+/// PSL is write-only here: records are written through
+/// [`Writer`](crate::Writer) as alignments of the spliced transcript (query)
+/// against the genome (target), one block per exon. Since a `GenePred`
+/// carries no per-base alignment data, the match columns are approximations:
+/// `matches` is the total block size, mismatch, repeat and `N` counts are
+/// `0`, and `tSize` is the record end because chromosome sizes are unknown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Psl;
+
+impl BedFormat for RefFlat {
+    const FIELD_COUNT: usize = 11;
+
+    fn from_fields(fields: &[&str], extras: Extras, line: usize) -> ReaderResult<Self> {
+        Ok(Self {
+            gene_name: fields[0].as_bytes().to_vec(),
+            record: parse_table(&fields[1..], extras, line)?,
+        })
+    }
+}
+
+/// Parses the ten genePred columns.
+///
+/// # Arguments
+///
+/// * `fields` - The ten genePred columns.
+/// * `extras` - Extra columns beyond the table layout.
+/// * `line` - Line number for errors.
+fn parse_table(fields: &[&str], extras: Extras, line: usize) -> ReaderResult<GenePredTable> {
+    let exon_count = __to_u32(fields[7], line, EXON_COUNT)?;
+    let exon_starts = parse_positions(fields[8], line, EXON_STARTS)?;
+    let exon_ends = parse_positions(fields[9], line, EXON_ENDS)?;
+
+    for (label, values) in [(EXON_STARTS, &exon_starts), (EXON_ENDS, &exon_ends)] {
+        if values.len() != exon_count as usize {
+            return Err(ReaderError::invalid_field(
+                line,
+                label,
+                format!(
+                    "ERROR: expected {exon_count} entries, got {} in {line}:{label}",
+                    values.len()
+                ),
+            ));
+        }
+    }
+
+    Ok(GenePredTable {
+        name: fields[0].as_bytes().to_vec(),
+        chrom: fields[1].as_bytes().to_vec(),
+        strand: Strand::parse(fields[2], line)?,
+        tx_start: __to_u64(fields[3], line, TX_START)?,
+        tx_end: __to_u64(fields[4], line, TX_END)?,
+        cds_start: __to_u64(fields[5], line, CDS_START)?,
+        cds_end: __to_u64(fields[6], line, CDS_END)?,
+        exon_count,
+        exon_starts,
+        exon_ends,
+        extras,
+    })
+}
+
+/// Parses a comma-separated list of absolute positions.
+///
+/// # Arguments
+///
+/// * `list` - Comma-separated (optionally comma-terminated) string.
+/// * `line` - Line number for errors.
+/// * `label` - Field label for error messages.
+fn parse_positions(list: &str, line: usize, label: &'static str) -> ReaderResult<Vec<u64>> {
+    list.split(',')
+        .filter(|s| !s.is_empty())
+        .map(|item| __to_u64(item, line, label))
+        .collect()
+}
+
+impl From<GenePredTable> for GenePred {
+    fn from(record: GenePredTable) -> Self {
+        let mut gene =
+            GenePred::from_coords(record.chrom, record.tx_start, record.tx_end, record.extras);
+        gene.name = Some(record.name);
+        gene.strand = Some(record.strand);
+        gene.thick_start = Some(record.cds_start);
+        gene.thick_end = Some(record.cds_end);
+        gene.block_count = Some(record.exon_count);
+        gene.block_starts = Some(record.exon_starts);
+        gene.block_ends = Some(record.exon_ends);
+        gene
+    }
+}
+
+impl From<RefFlat> for GenePred {
+    fn from(record: RefFlat) -> Self {
+        let mut gene: GenePred = record.record.into();
+        gene.extras_mut()
+            .insert(GENE_NAME_KEY.to_vec(), ExtraValue::Scalar(record.gene_name));
+        gene
+    }
+}
@@ -1,7 +1,8 @@
 // Copyright (c) 2026 Alejandro Gonzales-Irribarren <alejandrxgzi@gmail.com>
 // Distributed under the terms of the Apache License, Version 2.0.
 
-use std::collections::HashSet;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::io::{self, BufWriter, Write};
 use std::marker::PhantomData;
@@ -15,14 +16,18 @@ use bzip2::Compression as BzCompression;
 use flate2::write::GzEncoder;
 #[cfg(feature = "gzip")]
 use flate2::Compression as GzCompression;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 #[cfg(feature = "zstd")]
 use zstd::stream::write::Encoder as ZstdEncoder;
 
-use crate::bed::{Bed12, Bed3, Bed4, Bed5, Bed6, Bed8, Bed9, Rgb};
-use crate::genepred::{ExtraValue, Extras, GenePred};
+use crate::bed::{Bed12, Bed3, Bed4, Bed5, Bed6, Bed8, Bed9, BedGraph, Rgb};
+use crate::genepred::{transcript_exon_number, ExtraValue, Extras, GenePred};
+use crate::gxf::push_quoted;
 #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
 use crate::reader::Compression;
 use crate::strand::Strand;
+use crate::table::{GenePredTable, Psl, RefFlat, GENE_NAME_KEY};
 
 /// Result alias for writer operations.
 pub type WriterResult<T> = Result<T, WriterError>;
@@ -73,10 +78,89 @@ impl From<io::Error> for WriterError {
 ///
 /// The format is specified by the type parameter `F`, which must implement
 /// [`TargetFormat`].
+///
+/// BED writers emit only the columns of `F`, so writing with a narrower
+/// format projects records onto it: a BED12-derived record written through
+/// `Writer::<Bed6>` loses its thick, color and block columns. Extras are
+/// still appended unless [`WriterOptions::extras_passthrough`] is disabled.
 pub struct Writer<F> {
     _marker: PhantomData<F>,
 }
 
+/// A stateful writer that emits `GenePred` records one at a time.
+///
+/// Created by [`Writer::new`]. Records are written as they arrive, so a
+/// streamed `Reader` can be transformed into output without collecting it.
+/// Call [`RecordWriter::finish`] to flush and recover the underlying sink.
+///
+/// # Example
+///
+/// ```
+/// use genepred::{Bed3, Extras, GenePred, Writer};
+///
+/// let mut writer = Writer::<Bed3>::new(Vec::new());
+/// let gene = GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new());
+/// writer.write(&gene).unwrap();
+/// let bytes = writer.finish().unwrap();
+/// assert_eq!(bytes, b"chr1\t10\t20\n");
+/// ```
+pub struct RecordWriter<F, W: Write> {
+    writer: W,
+    options: WriterOptions,
+    header_written: bool,
+    state: WriteState,
+    _marker: PhantomData<F>,
+}
+
+impl<F, W> RecordWriter<F, W>
+where
+    F: TargetFormat,
+    W: Write,
+{
+    /// Writes a single record.
+    ///
+    /// The first call also writes any configured browser and track lines.
+    pub fn write(&mut self, record: &GenePred) -> WriterResult<()> {
+        self.write_header()?;
+        F::write_stream_record(record, &mut self.writer, &self.options, &mut self.state)
+    }
+
+    /// Writes the browser and track lines unless already written.
+    fn write_header(&mut self) -> WriterResult<()> {
+        if !self.header_written {
+            write_header(&mut self.writer, &self.options)?;
+            self.header_written = true;
+        }
+        Ok(())
+    }
+
+    /// Writes every record yielded by `records`, stopping at the first error.
+    pub fn write_all<'a, I>(&mut self, records: I) -> WriterResult<()>
+    where
+        I: IntoIterator<Item = &'a GenePred>,
+    {
+        for record in records {
+            self.write(record)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the options used for every record.
+    pub fn options(&self) -> &WriterOptions {
+        &self.options
+    }
+
+    /// Flushes the underlying writer and returns it.
+    ///
+    /// Browser and track lines are written here if no record was, so the
+    /// output matches [`Writer::from_records_with_options`].
+    pub fn finish(mut self) -> WriterResult<W> {
+        self.write_header()?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
 /// Configuration for writer behaviour.
 #[derive(Debug, Clone)]
 pub struct WriterOptions {
@@ -86,6 +170,36 @@ pub struct WriterOptions {
     pub include_numeric_extras: bool,
     /// Optional allowlist of extras to emit for all formats.
     extras_allowlist: Option<HashSet<Vec<u8>>>,
+    /// Whether BED outputs append extras after the format's own columns.
+    extras_passthrough: bool,
+    /// Color written for BED9/BED12 records that carry none.
+    default_item_rgb: Option<Rgb>,
+    /// Extra key and scale factor used to derive the BED score.
+    score_extra: Option<(Vec<u8>, f64)>,
+    /// Extra key holding the bedGraph value.
+    value_key: Option<Vec<u8>>,
+    /// Whether GTF/GFF CDS rows reuse the record's stored phases.
+    preserve_phase: bool,
+    /// Whether GFF output links gene, mRNA and child rows via `ID`/`Parent`.
+    gff3_hierarchy: bool,
+    /// Whether GFF attribute values percent-encode reserved characters.
+    gff3_encode: bool,
+    /// Compression level (0-9) for compressed path outputs.
+    compression_level: Option<u32>,
+    /// Whether path outputs create missing parent directories.
+    create_dirs: bool,
+    /// UCSC `track` line written before the first record.
+    track_line: Option<String>,
+    /// UCSC `browser` lines written before the track line.
+    browser_lines: Vec<String>,
+    /// Whether GTF/GFF output includes `CDS` rows.
+    emit_cds: bool,
+    /// Whether GTF/GFF output includes `start_codon`/`stop_codon` rows.
+    emit_codons: bool,
+    /// Whether GTF exon and CDS rows carry an `exon_number` attribute.
+    emit_exon_number: bool,
+    /// Replacement names for GTF/GFF feature types.
+    feature_type_overrides: Vec<(Vec<u8>, Vec<u8>)>,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -95,6 +209,21 @@ impl Default for WriterOptions {
             include_non_numeric_extras: false,
             include_numeric_extras: true,
             extras_allowlist: None,
+            extras_passthrough: true,
+            default_item_rgb: None,
+            score_extra: None,
+            value_key: None,
+            preserve_phase: false,
+            gff3_hierarchy: false,
+            gff3_encode: false,
+            compression_level: None,
+            create_dirs: false,
+            track_line: None,
+            browser_lines: Vec::new(),
+            emit_cds: true,
+            emit_codons: true,
+            emit_exon_number: false,
+            feature_type_overrides: Vec::new(),
         }
     }
 }
@@ -136,6 +265,183 @@ impl WriterOptions {
         self.extras_allowlist = None;
         self
     }
+
+    /// Controls whether BED outputs append extras after their own columns.
+    ///
+    /// Enabled by default. Disable it to project records onto exactly the
+    /// columns of the target format, e.g. to write BED12 input as plain BED6
+    /// even when it was read with trailing columns kept as extras.
+    pub fn extras_passthrough(mut self, enabled: bool) -> Self {
+        self.extras_passthrough = enabled;
+        self
+    }
+
+    /// Derives the BED score from a numeric extra instead of `record.score`.
+    ///
+    /// The first value of `key` is parsed as a number, multiplied by `scale`,
+    /// rounded and clamped to 0-1000. Records where the extra is missing or
+    /// not numeric fall back to their own score. Useful to display e.g. a
+    /// narrowPeak `signalValue` as BED score.
+    pub fn score_from_extra<K: Into<Vec<u8>>>(mut self, key: K, scale: f64) -> Self {
+        self.score_extra = Some((key.into(), scale));
+        self
+    }
+
+    /// Takes the bedGraph value column from the extra `key` instead of
+    /// `record.score`.
+    ///
+    /// The first value of `key` is written verbatim, so fractional signals
+    /// keep their precision. Records without the extra fall back to their
+    /// score; a value that is not a number is rejected.
+    pub fn value_key<K: Into<Vec<u8>>>(mut self, key: K) -> Self {
+        self.value_key = Some(key.into());
+        self
+    }
+
+    /// Sets the color written for BED9/BED12 records without an item color.
+    ///
+    /// Records keep their own color when present; otherwise `0,0,0` is used.
+    pub fn default_item_rgb(mut self, rgb: Rgb) -> Self {
+        self.default_item_rgb = Some(rgb);
+        self
+    }
+
+    /// Reuses stored CDS phases when writing GTF/GFF.
+    ///
+    /// By default phases are recomputed assuming the CDS starts in frame 0.
+    /// When enabled, [`GenePred::cds_phases`] is written as-is for records
+    /// with one phase per CDS segment, which keeps the frame of fragmentary
+    /// transcripts. Other records fall back to recomputed phases.
+    pub fn preserve_phase(mut self, preserve: bool) -> Self {
+        self.preserve_phase = preserve;
+        self
+    }
+
+    /// Emits GFF3 output as a `gene` → `mRNA` → child hierarchy.
+    ///
+    /// When enabled, each record is written as an `mRNA` line with
+    /// `ID=<transcript_id>;Parent=<gene_id>` and child rows carrying
+    /// `Parent=<transcript_id>` with stable IDs such as
+    /// `<transcript_id>.exon1` (numbered in genomic order). The gene ID is
+    /// taken from the `gene_id`, `Parent` or `gene` extra, falling back to
+    /// `<transcript_id>.gene`, so a gene never shares its mRNA's ID.
+    ///
+    /// Each gene gets one `gene` line (`ID=<gene_id>`), written before its
+    /// first transcript. Batch writes size it to span all of the gene's
+    /// transcripts; a [`RecordWriter`] cannot look ahead and uses the span of
+    /// the first one. GTF output is unaffected.
+    pub fn gff3_hierarchy(mut self, enabled: bool) -> Self {
+        self.gff3_hierarchy = enabled;
+        self
+    }
+
+    /// Percent-encodes reserved characters in GFF3 attribute values.
+    ///
+    /// When enabled, `;`, `=`, `&`, `,`, `%` and control characters inside
+    /// values are written as `%XX` escapes, as the GFF3 spec requires; list
+    /// values keep their separating commas. Read such files back with
+    /// [`ReaderOptions::decode_percent`](crate::ReaderOptions::decode_percent).
+    /// GTF output is unaffected. Off by default.
+    pub fn gff3_encode(mut self, enabled: bool) -> Self {
+        self.gff3_encode = enabled;
+        self
+    }
+
+    /// Sets the compression level (0-9) used by [`Writer::to_path`] outputs.
+    ///
+    /// Applies to `.gz`, `.zst` and `.bz2` paths; higher levels trade speed
+    /// for smaller files. Values above 9 are clamped, and bzip2 treats 0 as 1.
+    /// Without a level, outputs use each encoder's fast setting.
+    pub fn compression_level(mut self, level: u32) -> Self {
+        self.compression_level = Some(level.min(9));
+        self
+    }
+
+    /// Creates missing parent directories of [`Writer::to_path`] outputs.
+    ///
+    /// Disabled by default, in which case a missing parent directory is
+    /// reported as [`WriterError::Invalid`] naming the path.
+    pub fn create_dirs(mut self, enabled: bool) -> Self {
+        self.create_dirs = enabled;
+        self
+    }
+
+    /// Writes a UCSC `track` line once before the first record.
+    ///
+    /// The `track ` keyword is prepended unless `line` already starts with
+    /// it, so both `name=genes description="Genes"` and the full line work.
+    pub fn track_line(mut self, line: impl Into<String>) -> Self {
+        self.track_line = Some(with_keyword(line.into(), "track"));
+        self
+    }
+
+    /// Writes UCSC `browser` lines once before the first record.
+    ///
+    /// Lines come before any [`WriterOptions::track_line`], as genome
+    /// browsers expect, and get a `browser ` prefix like track lines.
+    pub fn browser_lines<I, S>(mut self, lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.browser_lines = lines
+            .into_iter()
+            .map(|line| with_keyword(line.into(), "browser"))
+            .collect();
+        self
+    }
+
+    /// Controls whether GTF/GFF output includes `CDS` rows.
+    ///
+    /// Enabled by default. Disable it, together with
+    /// [`WriterOptions::emit_codons`], for exon-only output.
+    pub fn emit_cds(mut self, emit: bool) -> Self {
+        self.emit_cds = emit;
+        self
+    }
+
+    /// Controls whether GTF/GFF output includes `start_codon` and
+    /// `stop_codon` rows.
+    ///
+    /// Enabled by default. Non-coding records never get codon rows.
+    pub fn emit_codons(mut self, emit: bool) -> Self {
+        self.emit_codons = emit;
+        self
+    }
+
+    /// Adds `exon_number "N"` to GTF `exon` and `CDS` rows.
+    ///
+    /// Numbers are 1-based in transcription order, so exons of reverse-strand
+    /// records count from the genomic 3' end; a `CDS` row takes the number of
+    /// the exon it lies in. Disabled by default. GFF output is unaffected.
+    pub fn emit_exon_number(mut self, emit: bool) -> Self {
+        self.emit_exon_number = emit;
+        self
+    }
+
+    /// Renames a GTF/GFF feature type on output, e.g. `transcript` to `mRNA`.
+    ///
+    /// Applies to every row whose feature column would be `from`, including
+    /// the `gene` row of [`WriterOptions::gff3_hierarchy`]. Later overrides
+    /// of the same type replace earlier ones.
+    pub fn feature_type_override<A, B>(mut self, from: A, to: B) -> Self
+    where
+        A: Into<Vec<u8>>,
+        B: Into<Vec<u8>>,
+    {
+        let from = from.into();
+        self.feature_type_overrides.retain(|(key, _)| *key != from);
+        self.feature_type_overrides.push((from, to.into()));
+        self
+    }
+
+    /// Returns the output name for a GTF/GFF feature type.
+    fn feature_type<'a>(&'a self, feature: &'a [u8]) -> &'a [u8] {
+        self.feature_type_overrides
+            .iter()
+            .find(|(from, _)| from.as_slice() == feature)
+            .map_or(feature, |(_, to)| to.as_slice())
+    }
 }
 
 #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
@@ -167,6 +473,26 @@ impl<F> Writer<F>
 where
     F: TargetFormat,
 {
+    /// Creates a [`RecordWriter`] that writes records to `writer` as they
+    /// are produced.
+    ///
+    /// The sink is written to directly; wrap files in a [`BufWriter`].
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<W: Write>(writer: W) -> RecordWriter<F, W> {
+        Self::new_with_options(writer, WriterOptions::default())
+    }
+
+    /// Creates a [`RecordWriter`] that applies `options` to every record.
+    pub fn new_with_options<W: Write>(writer: W, options: WriterOptions) -> RecordWriter<F, W> {
+        RecordWriter {
+            writer,
+            options,
+            header_written: false,
+            state: WriteState::default(),
+            _marker: PhantomData,
+        }
+    }
+
     /// Writes a single `GenePred` into the target format.
     ///
     /// The `record` argument is consumed to avoid unnecessary cloning of large
@@ -193,13 +519,29 @@ where
     }
 
     /// Writes all provided `GenePred`s into the target format using options.
+    ///
+    /// Configured browser and track lines are written first.
     pub fn from_records_with_options<W: Write + ?Sized>(
         records: &[GenePred],
         writer: &mut W,
         options: &WriterOptions,
     ) -> WriterResult<()> {
+        write_header(writer, options)?;
+        Self::write_records(records, writer, options)
+    }
+
+    /// Writes records without the browser and track header.
+    fn write_records<W: Write + ?Sized>(
+        records: &[GenePred],
+        writer: &mut W,
+        options: &WriterOptions,
+    ) -> WriterResult<()> {
+        let mut state = WriteState::default();
+        if options.gff3_hierarchy {
+            state.collect_gene_spans(records, options);
+        }
         for record in records {
-            F::write_record_with_options(record, writer, options)?;
+            F::write_stream_record(record, writer, options, &mut state)?;
         }
         Ok(())
     }
@@ -217,23 +559,122 @@ where
         records: &[GenePred],
         options: &WriterOptions,
     ) -> WriterResult<()> {
-        from_path_streaming(path, |writer| {
+        stream_to_path(path.as_ref(), options, |writer| {
             Self::from_records_with_options(records, writer, options)
         })
     }
+
+    /// Opens a path and writes all records, formatting them in parallel.
+    ///
+    /// Output is byte-identical to [`Writer::to_path`]: records are rendered
+    /// into per-chunk buffers on the rayon pool and written in input order.
+    #[cfg(feature = "rayon")]
+    pub fn par_to_path<P: AsRef<Path>>(path: P, records: &[GenePred]) -> WriterResult<()> {
+        Self::par_to_path_with_options(path, records, &WriterOptions::default())
+    }
+
+    /// Opens a path and writes all records in parallel with writer options.
+    #[cfg(feature = "rayon")]
+    pub fn par_to_path_with_options<P: AsRef<Path>>(
+        path: P,
+        records: &[GenePred],
+        options: &WriterOptions,
+    ) -> WriterResult<()> {
+        // Bound memory by rendering a few chunks per thread before flushing.
+        let batch = PAR_CHUNK_SIZE * rayon::current_num_threads() * 4;
+        stream_to_path(path.as_ref(), options, |writer| {
+            write_header(writer, options)?;
+            // Gene rows are shared across records, so chunks cannot be
+            // rendered independently.
+            if options.gff3_hierarchy {
+                return Self::write_records(records, writer, options);
+            }
+            for batch in records.chunks(batch) {
+                let buffers = batch
+                    .par_chunks(PAR_CHUNK_SIZE)
+                    .map(|chunk| {
+                        let mut buffer = Vec::with_capacity(chunk.len() * 128);
+                        Self::write_records(chunk, &mut buffer, options)?;
+                        Ok(buffer)
+                    })
+                    .collect::<WriterResult<Vec<_>>>()?;
+                for buffer in buffers {
+                    writer.write_all(&buffer)?;
+                }
+            }
+            Ok(())
+        })
+    }
 }
 
-/// Opens a sink writer for `path`, auto-detecting compression from the file
-/// extension. Returns a boxed [`Write`] backed by the appropriate encoder.
-fn open_sink(path: &Path) -> WriterResult<Box<dyn Write>> {
-    let file = std::fs::File::create(path)?;
+/// Prefixes `line` with `keyword` and a space unless it already starts with it.
+fn with_keyword(line: String, keyword: &str) -> String {
+    let trimmed = line.trim();
+    let has_keyword = trimmed
+        .strip_prefix(keyword)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace));
+    if has_keyword {
+        trimmed.to_string()
+    } else {
+        format!("{keyword} {trimmed}")
+    }
+}
 
+/// Writes the configured browser lines followed by the track line.
+fn write_header<W: Write + ?Sized>(writer: &mut W, options: &WriterOptions) -> io::Result<()> {
+    for line in options.browser_lines.iter().chain(&options.track_line) {
+        writer.write_all(line.as_bytes())?;
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Number of records rendered per parallel work unit.
+#[cfg(feature = "rayon")]
+const PAR_CHUNK_SIZE: usize = 4096;
+
+/// Creates the output file at `path`, optionally creating its parent
+/// directories first.
+///
+/// A missing parent directory is reported as [`WriterError::Invalid`] rather
+/// than a bare OS error.
+fn create_file(path: &Path, create_dirs: bool) -> WriterResult<std::fs::File> {
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty());
+    if let (true, Some(parent)) = (create_dirs, parent) {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::File::create(path).map_err(|err| match parent {
+        Some(parent) if err.kind() == io::ErrorKind::NotFound && !parent.exists() => {
+            WriterError::Invalid(format!(
+                "cannot create {}: directory {} does not exist \
+                 (create it or enable WriterOptions::create_dirs)",
+                path.display(),
+                parent.display()
+            ))
+        }
+        _ => WriterError::Io(err),
+    })
+}
+
+/// Wraps the file created for `path` in a sink writer, auto-detecting
+/// compression from the file extension. Returns a boxed [`Write`] backed by
+/// the appropriate encoder, using `level` (0-9) or each encoder's fast
+/// setting when `None`.
+#[cfg_attr(
+    not(any(feature = "gzip", feature = "zstd", feature = "bz2")),
+    allow(unused_variables)
+)]
+fn open_sink(file: std::fs::File, path: &Path, level: Option<u32>) -> WriterResult<Box<dyn Write>> {
     #[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
     let sink: Box<dyn Write> = match compression_from_extension(path) {
         Compression::Gzip => {
             #[cfg(feature = "gzip")]
             {
-                Box::new(GzEncoder::new(file, GzCompression::fast()))
+                let level = level.map_or(GzCompression::fast(), GzCompression::new);
+                Box::new(GzEncoder::new(file, level))
             }
             #[cfg(not(feature = "gzip"))]
             {
@@ -245,7 +686,9 @@ fn open_sink(path: &Path) -> WriterResult<Box<dyn Write>> {
         Compression::Zstd => {
             #[cfg(feature = "zstd")]
             {
-                let encoder = ZstdEncoder::new(file, 0)
+                // zstd levels run 1-22; 0 selects the library default.
+                let level = level.map_or(0, |level| level.max(1) as i32);
+                let encoder = ZstdEncoder::new(file, level)
                     .map_err(|err| WriterError::Io(io::Error::new(io::ErrorKind::Other, err)))?;
                 Box::new(encoder.auto_finish())
             }
@@ -259,7 +702,10 @@ fn open_sink(path: &Path) -> WriterResult<Box<dyn Write>> {
         Compression::Bzip2 => {
             #[cfg(feature = "bz2")]
             {
-                Box::new(BzEncoder::new(file, BzCompression::fast()))
+                let level = level.map_or(BzCompression::fast(), |level| {
+                    BzCompression::new(level.clamp(1, 9))
+                });
+                Box::new(BzEncoder::new(file, level))
             }
             #[cfg(not(feature = "bz2"))]
             {
@@ -268,7 +714,8 @@ fn open_sink(path: &Path) -> WriterResult<Box<dyn Write>> {
                 ));
             }
         }
-        Compression::None | Compression::Auto => Box::new(file),
+        // `compression_from_extension` never yields `Bgzf`: `.gz` is written as plain gzip.
+        Compression::None | Compression::Auto | Compression::Bgzf => Box::new(file),
     };
 
     #[cfg(not(any(feature = "gzip", feature = "zstd", feature = "bz2")))]
@@ -296,7 +743,16 @@ where
     P: AsRef<Path>,
     EmitFn: FnOnce(&mut dyn Write) -> WriterResult<()>,
 {
-    let sink = open_sink(path.as_ref())?;
+    stream_to_path(path.as_ref(), &WriterOptions::default(), emit)
+}
+
+/// Streams `emit` into `path` honouring the path-related writer options.
+fn stream_to_path<EmitFn>(path: &Path, options: &WriterOptions, emit: EmitFn) -> WriterResult<()>
+where
+    EmitFn: FnOnce(&mut dyn Write) -> WriterResult<()>,
+{
+    let file = create_file(path, options.create_dirs)?;
+    let sink = open_sink(file, path, options.compression_level)?;
     let mut writer = BufWriter::with_capacity(64 * 1024, sink);
     emit(&mut writer)?;
     writer.flush()?;
@@ -312,6 +768,21 @@ pub trait TargetFormat {
         options: &WriterOptions,
     ) -> WriterResult<()>;
 
+    /// Writes a record as part of a longer output, sharing `state` with the
+    /// records written before it.
+    ///
+    /// Most formats ignore the state; GFF3 output with
+    /// [`WriterOptions::gff3_hierarchy`] uses it to write each gene row once.
+    fn write_stream_record<W: Write + ?Sized>(
+        record: &GenePred,
+        writer: &mut W,
+        options: &WriterOptions,
+        state: &mut WriteState,
+    ) -> WriterResult<()> {
+        let _ = state;
+        Self::write_record_with_options(record, writer, options)
+    }
+
     /// Writes a record with default options.
     fn write_record<W: Write + ?Sized>(record: &GenePred, writer: &mut W) -> WriterResult<()> {
         Self::write_record_with_options(record, writer, &WriterOptions::default())
@@ -395,6 +866,48 @@ impl TargetFormat for Bed12 {
     }
 }
 
+impl TargetFormat for BedGraph {
+    /// Writes a `GenePred` record as a 4-column bedGraph row.
+    fn write_record_with_options<W: Write + ?Sized>(
+        record: &GenePred,
+        writer: &mut W,
+        options: &WriterOptions,
+    ) -> WriterResult<()> {
+        if record.chrom.is_empty() {
+            return Err(WriterError::MissingField("chrom"));
+        }
+
+        writer.write_all(&record.chrom)?;
+        writer.write_all(b"\t")?;
+        write_u64(writer, record.start)?;
+        writer.write_all(b"\t")?;
+        write_u64(writer, record.end)?;
+        writer.write_all(b"\t")?;
+
+        let value = options
+            .value_key
+            .as_ref()
+            .and_then(|key| record.get_extra(key)?.first());
+        match value {
+            Some(value) => {
+                let numeric = std::str::from_utf8(value)
+                    .ok()
+                    .is_some_and(|text| text.trim().parse::<f64>().is_ok());
+                if !numeric {
+                    return Err(WriterError::Invalid(format!(
+                        "bedGraph value '{}' is not a number",
+                        String::from_utf8_lossy(value)
+                    )));
+                }
+                writer.write_all(value.trim_ascii())?;
+            }
+            None => write_u64(writer, u64::from(record.score.unwrap_or(0)))?,
+        }
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+}
+
 impl TargetFormat for crate::gxf::Gtf {
     /// Writes a `GenePred` record in GTF format.
     fn write_record_with_options<W: Write + ?Sized>(
@@ -402,7 +915,13 @@ impl TargetFormat for crate::gxf::Gtf {
         writer: &mut W,
         options: &WriterOptions,
     ) -> WriterResult<()> {
-        write_gxf(record, writer, GxfKind::Gtf, options)
+        write_gxf(
+            record,
+            writer,
+            GxfKind::Gtf,
+            options,
+            &mut WriteState::default(),
+        )
     }
 }
 
@@ -413,10 +932,166 @@ impl TargetFormat for crate::gxf::Gff {
         writer: &mut W,
         options: &WriterOptions,
     ) -> WriterResult<()> {
-        write_gxf(record, writer, GxfKind::Gff, options)
+        write_gxf(
+            record,
+            writer,
+            GxfKind::Gff,
+            options,
+            &mut WriteState::default(),
+        )
+    }
+
+    /// Writes a `GenePred` record in GFF format, emitting each gene row once.
+    fn write_stream_record<W: Write + ?Sized>(
+        record: &GenePred,
+        writer: &mut W,
+        options: &WriterOptions,
+        state: &mut WriteState,
+    ) -> WriterResult<()> {
+        write_gxf(record, writer, GxfKind::Gff, options, state)
+    }
+}
+
+impl TargetFormat for GenePredTable {
+    /// Writes a `GenePred` record as a 10-column genePred table row.
+    fn write_record_with_options<W: Write + ?Sized>(
+        record: &GenePred,
+        writer: &mut W,
+        _options: &WriterOptions,
+    ) -> WriterResult<()> {
+        write_genepred_table(record, writer)
+    }
+}
+
+impl TargetFormat for RefFlat {
+    /// Writes a `GenePred` record as an 11-column refFlat row.
+    ///
+    /// The gene name is taken from the `gene_name` or `gene_id` extras,
+    /// falling back to the transcript name.
+    fn write_record_with_options<W: Write + ?Sized>(
+        record: &GenePred,
+        writer: &mut W,
+        _options: &WriterOptions,
+    ) -> WriterResult<()> {
+        let extras = record.extras();
+        let gene_name = extras
+            .get(GENE_NAME_KEY)
+            .or_else(|| extras.get(b"gene_id".as_ref()))
+            .and_then(ExtraValue::first)
+            .or(record.name.as_deref())
+            .unwrap_or(b".");
+        writer.write_all(gene_name)?;
+        writer.write_all(b"\t")?;
+        write_genepred_table(record, writer)
+    }
+}
+
+impl TargetFormat for Psl {
+    /// Writes a `GenePred` record as a 21-column PSL row.
+    ///
+    /// The query is the spliced transcript named by `name`, so `qStarts`
+    /// are cumulative block offsets and introns are counted as target
+    /// inserts. Records without a strand are written on `+`.
+    fn write_record_with_options<W: Write + ?Sized>(
+        record: &GenePred,
+        writer: &mut W,
+        _options: &WriterOptions,
+    ) -> WriterResult<()> {
+        if record.chrom.is_empty() {
+            return Err(WriterError::MissingField("chrom"));
+        }
+
+        let exons = derive_exons(record);
+        let query_size: u64 = exons.iter().map(|(start, end)| end - start).sum();
+        let target_gap = record.len().saturating_sub(query_size);
+        let strand = match record.strand {
+            Some(Strand::Reverse) => b'-',
+            _ => b'+',
+        };
+
+        // matches, misMatches, repMatches, nCount, qNumInsert, qBaseInsert,
+        // tNumInsert, tBaseInsert
+        let target_inserts = exons.len().saturating_sub(1) as u64;
+        for value in [query_size, 0, 0, 0, 0, 0, target_inserts, target_gap] {
+            write_u64(writer, value)?;
+            writer.write_all(b"\t")?;
+        }
+        writer.write_all(&[strand, b'\t'])?;
+        writer.write_all(record.name.as_deref().unwrap_or(b"."))?;
+        for value in [query_size, 0, query_size] {
+            writer.write_all(b"\t")?;
+            write_u64(writer, value)?;
+        }
+        writer.write_all(b"\t")?;
+        writer.write_all(&record.chrom)?;
+        for value in [record.end, record.start, record.end, exons.len() as u64] {
+            writer.write_all(b"\t")?;
+            write_u64(writer, value)?;
+        }
+
+        writer.write_all(b"\t")?;
+        for (start, end) in &exons {
+            write_u64(writer, end - start)?;
+            writer.write_all(b",")?;
+        }
+        writer.write_all(b"\t")?;
+        let mut offset = 0;
+        for (start, end) in &exons {
+            write_u64(writer, offset)?;
+            writer.write_all(b",")?;
+            offset += end - start;
+        }
+        writer.write_all(b"\t")?;
+        for (start, _) in &exons {
+            write_u64(writer, *start)?;
+            writer.write_all(b",")?;
+        }
+        writer.write_all(b"\n")?;
+        Ok(())
     }
 }
 
+/// Writes the ten genePred columns for a record.
+///
+/// Records without thick bounds are written as non-coding, with
+/// `cdsStart == cdsEnd == txEnd`.
+fn write_genepred_table<W: Write + ?Sized>(record: &GenePred, writer: &mut W) -> WriterResult<()> {
+    if record.chrom.is_empty() {
+        return Err(WriterError::MissingField("chrom"));
+    }
+
+    let name = record.name.as_deref().unwrap_or(b".");
+    let (cds_start, cds_end) = match (record.thick_start, record.thick_end) {
+        (Some(start), Some(end)) => (start, end),
+        _ => (record.end, record.end),
+    };
+    let exons = derive_exons(record);
+
+    writer.write_all(name)?;
+    writer.write_all(b"\t")?;
+    writer.write_all(&record.chrom)?;
+    writer.write_all(b"\t")?;
+    writer.write_all(&[strand_byte(record.strand)])?;
+    for value in [record.start, record.end, cds_start, cds_end] {
+        writer.write_all(b"\t")?;
+        write_u64(writer, value)?;
+    }
+    writer.write_all(b"\t")?;
+    write_u64(writer, exons.len() as u64)?;
+    writer.write_all(b"\t")?;
+    for (start, _) in &exons {
+        write_u64(writer, *start)?;
+        writer.write_all(b",")?;
+    }
+    writer.write_all(b"\t")?;
+    for (_, end) in &exons {
+        write_u64(writer, *end)?;
+        writer.write_all(b",")?;
+    }
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
 /// BED format variants supported by the writer.
 #[derive(Copy, Clone)]
 enum BedFields {
@@ -458,7 +1133,7 @@ fn write_bed_core<W: Write + ?Sized>(
 
     match kind {
         BedFields::Bed3 => {
-            write_bed_extras(writer, &record.extras, options)?;
+            write_bed_extras(writer, record.extras(), options)?;
             return Ok(());
         }
         BedFields::Bed4
@@ -473,21 +1148,12 @@ fn write_bed_core<W: Write + ?Sized>(
         }
     }
 
-    let score: u16 = match kind {
-        BedFields::Bed5
-        | BedFields::Bed6
-        | BedFields::Bed8
-        | BedFields::Bed9
-        | BedFields::Bed12 => 0,
-        BedFields::Bed3 | BedFields::Bed4 => 0,
-    };
-
     if matches!(
         kind,
         BedFields::Bed5 | BedFields::Bed6 | BedFields::Bed8 | BedFields::Bed9 | BedFields::Bed12
     ) {
         writer.write_all(b"\t")?;
-        write_u64(writer, score as u64)?;
+        write_u64(writer, bed_score(record, options) as u64)?;
     }
 
     if matches!(
@@ -509,7 +1175,11 @@ fn write_bed_core<W: Write + ?Sized>(
 
     if matches!(kind, BedFields::Bed9 | BedFields::Bed12) {
         writer.write_all(b"\t")?;
-        write_item_rgb(writer, Rgb(0, 0, 0))?;
+        let rgb = record
+            .item_rgb
+            .or(options.default_item_rgb)
+            .unwrap_or(Rgb(0, 0, 0));
+        write_item_rgb(writer, rgb)?;
     }
 
     if matches!(kind, BedFields::Bed12) {
@@ -542,10 +1212,21 @@ fn write_bed_core<W: Write + ?Sized>(
         writer.write_all(b",")?;
     }
 
-    write_bed_extras(writer, &record.extras, options)?;
+    write_bed_extras(writer, record.extras(), options)?;
     Ok(())
 }
 
+/// Resolves the BED score for a record, honouring `score_from_extra`.
+fn bed_score(record: &GenePred, options: &WriterOptions) -> u16 {
+    let derived = options.score_extra.as_ref().and_then(|(key, scale)| {
+        let raw = record.get_extra(key)?.first()?;
+        let value = std::str::from_utf8(raw).ok()?.trim().parse::<f64>().ok()?;
+        let scaled = value * scale;
+        (!scaled.is_nan()).then(|| scaled.round().clamp(0.0, 1000.0) as u16)
+    });
+    derived.or(record.score).unwrap_or(0)
+}
+
 /// Derives exon coordinates from a GenePred record.
 ///
 /// If the record has no explicit exons, creates a single exon spanning
@@ -586,7 +1267,7 @@ fn write_bed_extras<W: Write + ?Sized>(
     extras: &Extras,
     options: &WriterOptions,
 ) -> WriterResult<()> {
-    if extras.is_empty() {
+    if extras.is_empty() || !options.extras_passthrough {
         writer.write_all(b"\n")?;
         return Ok(());
     }
@@ -639,6 +1320,59 @@ enum GxfKind {
     Gff,
 }
 
+/// State shared by the records of one output stream.
+///
+/// Used through [`TargetFormat::write_stream_record`]; most formats keep no
+/// state.
+#[derive(Debug, Default)]
+pub struct WriteState {
+    /// GFF3 gene IDs whose `gene` row has been written.
+    written_genes: HashSet<Vec<u8>>,
+    /// GFF3 gene spans known ahead of writing, keyed by chromosome and ID.
+    gene_spans: HashMap<(Vec<u8>, Vec<u8>), (u64, u64)>,
+}
+
+impl WriteState {
+    /// Records the span covered by each gene's transcripts in `records`.
+    fn collect_gene_spans(&mut self, records: &[GenePred], options: &WriterOptions) {
+        for record in records {
+            let (_, gene_id) = gff3_ids(record, options);
+            self.gene_spans
+                .entry((record.chrom.clone(), gene_id))
+                .and_modify(|(start, end)| {
+                    *start = (*start).min(record.start);
+                    *end = (*end).max(record.end);
+                })
+                .or_insert((record.start, record.end));
+        }
+    }
+}
+
+/// Returns the GFF3 transcript and gene IDs of `record`.
+///
+/// The gene ID comes from the `gene_id`, `Parent` or `gene` extra; records
+/// without one, or whose gene ID equals the transcript ID, get
+/// `<transcript_id>.gene`.
+fn gff3_ids(record: &GenePred, options: &WriterOptions) -> (Vec<u8>, Vec<u8>) {
+    let (transcript_id, _) = transcript_and_gene_ids(record, false);
+    let extras = record.extras();
+    let gene_id = [b"gene_id".as_ref(), b"Parent", b"gene"]
+        .into_iter()
+        .filter_map(|key| extras.get(key).and_then(ExtraValue::first))
+        .find(|gene_id| *gene_id != transcript_id.as_slice())
+        .map(<[u8]>::to_vec)
+        .unwrap_or_else(|| {
+            let mut gene_id = transcript_id.clone();
+            gene_id.extend_from_slice(b".gene");
+            gene_id
+        });
+    if options.gff3_encode {
+        (percent_encode(&transcript_id), percent_encode(&gene_id))
+    } else {
+        (transcript_id, gene_id)
+    }
+}
+
 /// Writes a GenePred record in GTF or GFF format.
 ///
 /// This function generates multiple feature lines: transcript/mRNA, exons,
@@ -648,27 +1382,95 @@ fn write_gxf<W: Write + ?Sized>(
     writer: &mut W,
     kind: GxfKind,
     options: &WriterOptions,
+    state: &mut WriteState,
 ) -> WriterResult<()> {
     if record.chrom.is_empty() {
         return Err(WriterError::MissingField("chrom"));
     }
 
-    let mut exons = derive_exons(record);
+    let exons = derive_exons(record);
     let strand = record.strand.unwrap_or(Strand::Unknown);
     let mut attrs = build_attributes(record, matches!(kind, GxfKind::Gtf), options);
 
+    let hierarchy = matches!(kind, GxfKind::Gff) && options.gff3_hierarchy;
+    let (transcript_id, gene_id) = if hierarchy {
+        gff3_ids(record, options)
+    } else {
+        transcript_and_gene_ids(record, false)
+    };
+    if hierarchy {
+        if !state.written_genes.contains(&gene_id) {
+            let (start, end) = state
+                .gene_spans
+                .get(&(record.chrom.clone(), gene_id.clone()))
+                .copied()
+                .unwrap_or((record.start, record.end));
+            write_gxf_feature(
+                writer,
+                &record.chrom,
+                options.feature_type(b"gene"),
+                start + 1,
+                end,
+                strand,
+                None,
+                &render_gff_attributes(&mut [(b"ID".to_vec(), gene_id.clone())]),
+                kind,
+            )?;
+            state.written_genes.insert(gene_id.clone());
+        }
+        attrs.retain(|(key, _)| !matches!(key.as_slice(), b"ID" | b"Parent"));
+        attrs.splice(
+            0..0,
+            [
+                (b"ID".to_vec(), transcript_id.clone()),
+                (b"Parent".to_vec(), gene_id),
+            ],
+        );
+    }
+
+    let numbered = matches!(kind, GxfKind::Gtf) && options.emit_exon_number;
+    // Numbers read from GTF input are merged across exons; drop them so
+    // each child row carries only its own.
+    if numbered {
+        attrs.retain(|(key, _)| key.as_slice() != b"exon_number");
+    }
     let attrs = match kind {
         GxfKind::Gtf => render_gtf_attributes(&mut attrs),
         GxfKind::Gff => render_gff_attributes(&mut attrs),
     };
+    // Strand-aware number of the exon containing `start`.
+    let exon_number = |start: u64| {
+        exons
+            .iter()
+            .position(|(exon_start, exon_end)| *exon_start <= start && start < *exon_end)
+            .map(|index| transcript_exon_number(strand, index, exons.len()))
+    };
+    // Child rows reuse the transcript attributes unless a hierarchy or exon
+    // numbers are requested.
+    let child_attrs = |tag: &str, number: usize, exon: Option<usize>| -> Cow<'_, [u8]> {
+        if let (true, Some(exon)) = (numbered, exon) {
+            let mut numbered = attrs.clone();
+            numbered.extend_from_slice(format!(" exon_number \"{exon}\";").as_bytes());
+            return Cow::Owned(numbered);
+        }
+        if !hierarchy {
+            return Cow::Borrowed(attrs.as_slice());
+        }
+        let mut id = transcript_id.clone();
+        id.extend_from_slice(format!(".{tag}{number}").as_bytes());
+        Cow::Owned(render_gff_attributes(&mut [
+            (b"ID".to_vec(), id),
+            (b"Parent".to_vec(), transcript_id.clone()),
+        ]))
+    };
 
     write_gxf_feature(
         writer,
         &record.chrom,
-        match kind {
+        options.feature_type(match kind {
             GxfKind::Gtf => b"transcript",
             GxfKind::Gff => b"mRNA",
-        },
+        }),
         record.start + 1,
         record.end,
         strand,
@@ -677,50 +1479,69 @@ fn write_gxf<W: Write + ?Sized>(
         kind,
     )?;
 
-    for (start, end) in &mut exons {
+    for (idx, (start, end)) in exons.iter().enumerate() {
         write_gxf_feature(
             writer,
             &record.chrom,
-            b"exon",
+            options.feature_type(b"exon"),
             *start + 1,
             *end,
             strand,
             None,
-            &attrs,
+            &child_attrs("exon", idx + 1, exon_number(*start)),
             kind,
         )?;
     }
 
+    // Non-coding records (empty thick region) get no CDS or codon rows.
+    if !record.is_coding() {
+        return Ok(());
+    }
     let coding_exons = record.coding_exons();
     if coding_exons.is_empty() {
         return Ok(());
     }
 
-    let cds_segments = compute_cds_segments(&coding_exons, strand);
-    for (start, end, phase) in cds_segments {
-        write_gxf_feature(
-            writer,
-            &record.chrom,
-            b"CDS",
-            start + 1,
-            end,
-            strand,
-            Some(phase),
-            &attrs,
-            kind,
-        )?;
+    if options.emit_cds {
+        let mut cds_segments = compute_cds_segments(&coding_exons, strand);
+        if options.preserve_phase {
+            if let Some(phases) = record.cds_phases() {
+                if phases.len() == cds_segments.len() {
+                    for (segment, phase) in cds_segments.iter_mut().zip(phases) {
+                        segment.2 = *phase;
+                    }
+                }
+            }
+        }
+        for (idx, (start, end, phase)) in cds_segments.into_iter().enumerate() {
+            write_gxf_feature(
+                writer,
+                &record.chrom,
+                options.feature_type(b"CDS"),
+                start + 1,
+                end,
+                strand,
+                Some(phase),
+                &child_attrs("cds", idx + 1, exon_number(start)),
+                kind,
+            )?;
+        }
+    }
+
+    if !options.emit_codons {
+        return Ok(());
     }
 
     if let Some((start, end)) = start_codon_interval(&coding_exons, strand) {
         write_gxf_feature(
             writer,
             &record.chrom,
-            b"start_codon",
+            options.feature_type(b"start_codon"),
             start + 1,
             end,
             strand,
             None,
-            &attrs,
+            &child_attrs("start_codon", 1, None),
             kind,
         )?;
     }
@@ -729,12 +1550,12 @@ fn write_gxf<W: Write + ?Sized>(
         write_gxf_feature(
             writer,
             &record.chrom,
-            b"stop_codon",
+            options.feature_type(b"stop_codon"),
             start + 1,
             end,
             strand,
             None,
-            &attrs,
+            &child_attrs("stop_codon", 1, None),
             kind,
         )?;
     }
@@ -904,8 +1725,8 @@ fn coding_span(coding_exons: &[(u64, u64)]) -> Option<(u64, u64)> {
 /// use genepred::{GenePred, Extras, ExtraValue};
 ///
 /// let mut record = GenePred::from_coords(b"chr1", 100, 500, Some(b"gene1"));
-/// record.extras.insert(b"gene_id".to_vec(), ExtraValue::Scalar(b"GENE1".to_vec()));
-/// record.extras.insert(b"transcript_id".to_vec(), ExtraValue::Scalar(b"TX1".to_vec()));
+/// record.extras_mut().insert(b"gene_id".to_vec(), ExtraValue::Scalar(b"GENE1".to_vec()));
+/// record.extras_mut().insert(b"transcript_id".to_vec(), ExtraValue::Scalar(b"TX1".to_vec()));
 ///
 /// // GTF format
 /// let gtf_attrs = build_attributes(&record, true);
@@ -922,26 +1743,15 @@ fn build_attributes(
     is_gtf: bool,
     options: &WriterOptions,
 ) -> Vec<(Vec<u8>, Vec<u8>)> {
-    let transcript = record
-        .extras
-        .get(if is_gtf {
-            b"transcript_id".as_ref()
-        } else {
-            b"ID".as_ref()
-        })
-        .and_then(ExtraValue::first)
-        .map(|v| v.to_vec())
-        .or_else(|| record.name.clone())
-        .unwrap_or_else(|| b".".to_vec());
-
-    let gene_id = record
-        .extras
-        .get(b"gene_id".as_ref())
-        .and_then(ExtraValue::first)
-        .map(|v| v.to_vec())
-        .unwrap_or_else(|| transcript.clone());
+    let extras = record.extras();
+    let encode = !is_gtf && options.gff3_encode;
+    let (mut transcript, mut gene_id) = transcript_and_gene_ids(record, is_gtf);
+    if encode {
+        transcript = percent_encode(&transcript);
+        gene_id = percent_encode(&gene_id);
+    }
 
-    let mut pairs = Vec::with_capacity(record.extras.len() + 3);
+    let mut pairs = Vec::with_capacity(extras.len() + 3);
     // gene_id and transcript_id are emitted first (when allowed) for deterministic output
     if allow_extra_key(b"gene_id", options) {
         pairs.push((b"gene_id".to_vec(), gene_id.clone()));
@@ -953,8 +1763,8 @@ fn build_attributes(
         pairs.push((b"ID".to_vec(), transcript.clone()));
     }
 
-    let mut rest: Vec<(Vec<u8>, Vec<u8>)> = Vec::with_capacity(record.extras.len());
-    for (key, value) in &record.extras {
+    let mut rest: Vec<(Vec<u8>, Vec<u8>)> = Vec::with_capacity(extras.len());
+    for (key, value) in extras {
         if is_gtf && (key.as_slice() == b"gene_id" || key.as_slice() == b"transcript_id") {
             continue;
         }
@@ -964,7 +1774,12 @@ fn build_attributes(
         if !allow_extra_key(key, options) {
             continue;
         }
-        let rendered = render_value(value);
+        let rendered = if encode {
+            let items: Vec<Vec<u8>> = value.iter().map(percent_encode).collect();
+            items.join(&b',')
+        } else {
+            render_value(value)
+        };
         rest.push((key.clone(), rendered));
     }
 
@@ -973,6 +1788,32 @@ fn build_attributes(
     pairs
 }
 
+/// Returns the `(transcript, gene)` identifiers written for a record.
+///
+/// The transcript comes from `transcript_id` (GTF) or `ID` (GFF), then the
+/// record name, then `.`; the gene from `gene_id`, then the transcript.
+fn transcript_and_gene_ids(record: &GenePred, is_gtf: bool) -> (Vec<u8>, Vec<u8>) {
+    let extras = record.extras();
+    let transcript = extras
+        .get(if is_gtf {
+            b"transcript_id".as_ref()
+        } else {
+            b"ID".as_ref()
+        })
+        .and_then(ExtraValue::first)
+        .map(|v| v.to_vec())
+        .or_else(|| record.name.clone())
+        .unwrap_or_else(|| b".".to_vec());
+
+    let gene_id = extras
+        .get(b"gene_id".as_ref())
+        .and_then(ExtraValue::first)
+        .map(|v| v.to_vec())
+        .unwrap_or_else(|| transcript.clone());
+
+    (transcript, gene_id)
+}
+
 /// Returns true if the key is explicitly allowed, or if no allowlist is
 /// configured. Otherwise, returns false.
 fn allow_extra_key(key: &[u8], options: &WriterOptions) -> bool {
@@ -1005,9 +1846,9 @@ fn render_gtf_attributes(pairs: &mut [(Vec<u8>, Vec<u8>)]) -> Vec<u8> {
     let mut buf = Vec::with_capacity(64);
     for (key, value) in pairs.iter() {
         buf.extend_from_slice(key);
-        buf.extend_from_slice(b" \"");
-        buf.extend_from_slice(value);
-        buf.extend_from_slice(b"\"; ");
+        buf.push(b' ');
+        push_quoted(&mut buf, value);
+        buf.extend_from_slice(b"; ");
     }
     while buf.last().is_some_and(|b| *b == b' ') {
         buf.pop();
@@ -1169,6 +2010,23 @@ fn render_value(value: &ExtraValue) -> Vec<u8> {
     }
 }
 
+/// Percent-encodes the bytes GFF3 reserves in attribute values.
+///
+/// Covers `;`, `=`, `&`, `,`, `%` itself and control characters such as tab
+/// and newline; other bytes are copied unchanged.
+fn percent_encode(value: &[u8]) -> Vec<u8> {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    let mut buf = Vec::with_capacity(value.len());
+    for &byte in value {
+        if matches!(byte, b';' | b'=' | b'&' | b',' | b'%') || byte.is_ascii_control() {
+            buf.extend_from_slice(&[b'%', HEX[(byte >> 4) as usize], HEX[(byte & 0xF) as usize]]);
+        } else {
+            buf.push(byte);
+        }
+    }
+    buf
+}
+
 /// Writes a u64 value to the writer as decimal text.
 ///
 /// This is a fast implementation that avoids allocations by using
@@ -3,18 +3,70 @@ use bzip2::write::BzEncoder;
 #[cfg(feature = "bz2")]
 use bzip2::Compression as BzCompression;
 use genepred::reader::Reader;
-use genepred::{Bed12, Bed3, Bed4, Bed6, ExtraValue, Gff, Gtf, ReaderOptions, Strand};
+use genepred::{
+    AggregationLevel, Bed12, Bed3, Bed4, Bed5, Bed6, Bed9, BedPe, ChromStyle, ConflictPolicy,
+    ErrorPolicy, ExonlessPolicy, ExtraValue, Extras, FastaSequence, FeatureKind, GenePred,
+    GenePredTable, Gff, Gtf, ReaderOptions, ReappearPolicy, RefFlat, Rgb, SequenceRegion, Strand,
+};
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 #[cfg(any(feature = "bz2", feature = "zstd"))]
 use std::fs::File;
 #[cfg(any(feature = "bz2", feature = "zstd"))]
 use std::io::Write;
-#[cfg(any(feature = "bz2", feature = "zstd"))]
+#[cfg(any(feature = "bz2", feature = "zstd", feature = "mmap", feature = "rayon"))]
 use tempfile::tempdir;
 #[cfg(feature = "zstd")]
 use zstd::stream::write::Encoder as ZstdEncoder;
 
+/// Allocator tracking live and peak heap bytes per thread, so concurrently
+/// running tests do not disturb each other's measurements.
+#[cfg(feature = "mmap")]
+mod tracking {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    pub struct Tracking;
+
+    thread_local! {
+        static LIVE: Cell<isize> = const { Cell::new(0) };
+        static PEAK: Cell<isize> = const { Cell::new(0) };
+    }
+
+    fn record(delta: isize) {
+        let _ = LIVE.try_with(|live| {
+            let now = live.get() + delta;
+            live.set(now);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(now)));
+        });
+    }
+
+    unsafe impl GlobalAlloc for Tracking {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            record(layout.size() as isize);
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            record(-(layout.size() as isize));
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    /// Runs `f` and returns the peak bytes it held above the starting level.
+    pub fn peak_during<T>(f: impl FnOnce() -> T) -> (T, usize) {
+        let base = LIVE.with(Cell::get);
+        PEAK.with(|peak| peak.set(base));
+        let out = f();
+        let peak = PEAK.with(Cell::get);
+        (out, (peak - base).max(0) as usize)
+    }
+}
+
+#[cfg(feature = "mmap")]
+#[global_allocator]
+static ALLOCATOR: tracking::Tracking = tracking::Tracking;
+
 #[test]
 fn test_reader_from_string_bed3() {
     let data = "chr1\t10\t20\nchr1\t30\t40";
@@ -34,6 +86,41 @@ fn test_reader_from_string_bed3() {
     assert_eq!(second.end(), 40);
 }
 
+#[test]
+fn test_reader_strips_leading_bom() {
+    let data = "\u{FEFF}chr1\t10\t20\nchr1\t30\t40\n";
+    let mut reader: Reader<Bed3> =
+        Reader::from_reader(std::io::Cursor::new(data.as_bytes())).unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].chrom(), b"chr1".as_ref());
+    assert_eq!(records[1].chrom(), b"chr1".as_ref());
+}
+
+#[test]
+fn test_reader_keeps_mid_stream_bom() {
+    let data = "chr1\t10\t20\n\u{FEFF}chr1\t30\t40\n";
+    let mut reader: Reader<Bed3> =
+        Reader::from_reader(std::io::Cursor::new(data.as_bytes())).unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records[0].chrom(), b"chr1".as_ref());
+    assert_eq!(records[1].chrom(), "\u{FEFF}chr1".as_bytes());
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_reader_strips_leading_bom_mmap() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("bom.bed");
+    std::fs::write(&path, "\u{FEFF}chr1\t10\t20\nchr2\t30\t40\n").unwrap();
+
+    let mut reader: Reader<Bed3> = Reader::from_mmap(&path).unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].chrom(), b"chr1".as_ref());
+    assert_eq!(records[1].chrom(), b"chr2".as_ref());
+}
+
 #[test]
 fn test_reader_from_string_bed4() {
     let data = "chr1\t10\t20\tgeneA\nchr1\t30\t40\tgeneB";
@@ -112,6 +199,35 @@ fn test_reader_invalid_line() {
     assert!(records[2].is_ok());
 }
 
+#[test]
+fn test_bedpe_parse_line() {
+    let line = "chr1\t100\t200\tchr7\t5000\t5100\tloop1\t37\t+\t-\tcis";
+    let pair = BedPe::parse_line(line, 1).unwrap();
+
+    assert_eq!(pair.left.chrom(), b"chr1".as_ref());
+    assert_eq!(pair.left.start(), 100);
+    assert_eq!(pair.left.end(), 200);
+    assert_eq!(pair.left.strand(), Some(Strand::Forward));
+    assert_eq!(pair.right.chrom(), b"chr7".as_ref());
+    assert_eq!(pair.right.start(), 5000);
+    assert_eq!(pair.right.end(), 5100);
+    assert_eq!(pair.right.strand(), Some(Strand::Reverse));
+    assert_eq!(pair.name(), Some(b"loop1".as_ref()));
+    assert_eq!(pair.right.name(), Some(b"loop1".as_ref()));
+    assert_eq!(pair.score, b"37".to_vec());
+    assert!(!pair.is_intrachromosomal());
+    assert_eq!(
+        pair.extras.get(b"11".as_ref()),
+        Some(&ExtraValue::Scalar(b"cis".to_vec()))
+    );
+
+    let err = BedPe::parse_line("chr1\t100\t200\tchr7", 2).unwrap_err();
+    assert!(matches!(
+        err,
+        genepred::reader::ReaderError::UnexpectedFieldCount { line: 2, .. }
+    ));
+}
+
 #[test]
 fn test_reader_empty_input() {
     let data = "";
@@ -171,6 +287,112 @@ fn test_reader_gxf_allows_single_base_feature() {
     assert_eq!(gene.block_ends().unwrap(), &[88831747]);
 }
 
+#[test]
+fn test_reader_gxf_exonless_synthesize() {
+    let path = "tests/data/gtf_exonless.gtf";
+    let options = ReaderOptions::new().on_exonless(ExonlessPolicy::Synthesize);
+    let mut reader = Reader::<Gtf>::from_gxf_with_options(path, options).unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].block_count().unwrap(), 1);
+    assert_eq!(records[0].block_starts().unwrap(), &[99]);
+    assert_eq!(records[0].block_ends().unwrap(), &[200]);
+}
+
+#[test]
+fn test_reader_gxf_exonless_skip() {
+    let path = "tests/data/gtf_exonless.gtf";
+    let options = ReaderOptions::new().on_exonless(ExonlessPolicy::Skip);
+    let mut reader = Reader::<Gtf>::from_gxf_with_options(path, options.clone()).unwrap();
+    assert_eq!(reader.records().count(), 0);
+
+    // CDS rows do not count as exons unless derive_exons_from_cds_utr is set.
+    let path = "tests/data/cds_utr.gtf";
+    let mut reader = Reader::<Gtf>::from_gxf_with_options(path, options).unwrap();
+    assert_eq!(reader.records().count(), 0);
+}
+
+#[test]
+fn test_reader_gxf_exonless_error() {
+    let path = "tests/data/gtf_exonless.gtf";
+    let options = ReaderOptions::new().on_exonless(ExonlessPolicy::Error);
+    match Reader::<Gtf>::from_gxf_with_options(path, options) {
+        Err(err) => assert!(err.to_string().contains("tx1")),
+        Ok(_) => panic!("expected exonless transcript to be rejected"),
+    }
+}
+
+#[test]
+fn test_reader_gxf_streaming_matches_preloaded() {
+    let path = "tests/data/multi_transcript.gtf";
+    let mut preloaded: Vec<_> = Reader::<Gtf>::from_gxf(path)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    preloaded.sort_by(|a, b| a.name().cmp(&b.name()));
+
+    let options = ReaderOptions::new().streaming(true);
+    let streamed: Vec<_> = Reader::<Gtf>::from_gxf_with_options(path, options)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+
+    // streaming emits records in file order as transcripts close
+    let names: Vec<_> = streamed.iter().map(|r| r.name().unwrap()).collect();
+    assert_eq!(
+        names,
+        vec![b"TxOne".as_ref(), b"TxTwo".as_ref(), b"TxThree".as_ref()]
+    );
+
+    let mut streamed = streamed;
+    streamed.sort_by(|a, b| a.name().cmp(&b.name()));
+    assert_eq!(streamed, preloaded);
+}
+
+#[test]
+fn test_reader_gxf_streaming_interleaved_needs_window() {
+    let path = "tests/data/interleaved.gtf";
+
+    let options = ReaderOptions::new().streaming(true);
+    let results: Vec<_> = Reader::<Gtf>::from_gxf_with_options(path, options)
+        .unwrap()
+        .collect();
+    assert!(results.iter().any(|r| r.is_err()));
+
+    let options = ReaderOptions::new().streaming(true).streaming_window(2);
+    let records: Vec<_> = Reader::<Gtf>::from_gxf_with_options(path, options)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].block_starts().unwrap(), &[99, 249]);
+    assert_eq!(records[1].block_starts().unwrap(), &[149, 449]);
+
+    let options = ReaderOptions::new()
+        .streaming(true)
+        .on_reappear(ReappearPolicy::Split);
+    let records: Vec<_> = Reader::<Gtf>::from_gxf_with_options(path, options)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    let parts: Vec<_> = records
+        .iter()
+        .map(|record| (record.name().unwrap().to_vec(), record.exons()))
+        .collect();
+    assert_eq!(
+        parts,
+        vec![
+            (b"tx1".to_vec(), vec![(99, 300)]),
+            (b"tx2".to_vec(), vec![(149, 500)]),
+            (b"tx1".to_vec(), vec![(99, 120)]),
+            (b"tx2".to_vec(), vec![(149, 200)]),
+            (b"tx1".to_vec(), vec![(249, 300)]),
+            (b"tx2".to_vec(), vec![(449, 500)]),
+        ]
+    );
+}
+
 #[test]
 fn test_reader_gxf_negative_stop_codon_extends_thick_start() {
     let path = "tests/data/gtf_negative_stop_codon.gtf";
@@ -252,6 +474,1164 @@ fn test_reader_bed12_with_additional_fields() {
     }
 }
 
+#[cfg(feature = "mmap")]
+#[test]
+fn test_reader_bed12_additional_fields_mmap_matches_buffered() {
+    let path = "tests/data/bed12_extra.bed";
+    let read = |mode: genepred::ReaderMode| -> Vec<GenePred> {
+        Reader::<Bed12>::builder()
+            .from_path(path)
+            .mode(mode)
+            .additional_fields(2)
+            .build()
+            .unwrap()
+            .records()
+            .map(|r| r.unwrap())
+            .collect()
+    };
+    let buffered = read(genepred::ReaderMode::Default);
+    let mapped = read(genepred::ReaderMode::Mmap);
+    assert_eq!(mapped.len(), 1);
+    assert_eq!(mapped, buffered);
+    assert_eq!(
+        mapped[0].get_extra(b"14"),
+        Some(&ExtraValue::Scalar(b"bar".to_vec()))
+    );
+
+    let error = Reader::<Gtf>::builder()
+        .from_path("tests/data/simple.gtf")
+        .mode(genepred::ReaderMode::Mmap)
+        .additional_fields(1)
+        .build()
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("additional fields"));
+}
+
+#[test]
+fn test_reader_bed12_lazy_extras_match_eager() {
+    let path = "tests/data/bed12_extra.bed";
+    let eager: Vec<_> = Reader::<Bed12>::builder()
+        .from_path(path)
+        .additional_fields(2)
+        .build()
+        .unwrap()
+        .records()
+        .map(|r| r.unwrap())
+        .collect();
+    let mut lazy: Vec<_> = Reader::<Bed12>::builder()
+        .from_path(path)
+        .additional_fields(2)
+        .lazy_extras(true)
+        .build()
+        .unwrap()
+        .records()
+        .map(|r| r.unwrap())
+        .collect();
+
+    assert_eq!(lazy.len(), 1);
+    assert!(lazy[0].has_pending_extras());
+    assert_eq!(lazy[0].get_extra(b"14"), eager[0].get_extra(b"14"));
+    assert!(!lazy[0].has_pending_extras());
+    assert_eq!(lazy[0].extras(), eager[0].extras());
+    assert_eq!(lazy, eager);
+
+    lazy[0].add_extra("note", "x");
+    assert_eq!(lazy[0].extras().len(), 3);
+    assert_eq!(lazy[0].get_extra(b"13"), eager[0].get_extra(b"13"));
+}
+
+#[test]
+fn test_reader_bed12_lazy_extras_survive_conversion() {
+    let record = Reader::<Bed12>::builder()
+        .from_path("tests/data/bed12_extra.bed")
+        .additional_fields(2)
+        .lazy_extras(true)
+        .build()
+        .unwrap()
+        .records()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert!(record.has_pending_extras());
+    assert_eq!(record.extras().len(), 2);
+
+    assert_eq!(record.to_bed3().unwrap().extras, *record.extras());
+    assert_eq!(record.to_bed6().unwrap().extras, *record.extras());
+    assert_eq!(record.to_bed12().unwrap().extras, *record.extras());
+}
+
+#[test]
+fn test_reader_gff_multi_value_attributes() {
+    let path = "tests/data/dbxref.gff";
+    let mut reader: Reader<Gff> = Reader::from_path(path).unwrap();
+    let gene = reader.records().next().unwrap().unwrap();
+    assert_eq!(
+        gene.get_extra(b"Dbxref"),
+        Some(&ExtraValue::Array(vec![
+            b"GeneID:1".to_vec(),
+            b"HGNC:2".to_vec()
+        ]))
+    );
+    assert_eq!(
+        gene.get_extra(b"Note"),
+        Some(&ExtraValue::Scalar(b"a,b".to_vec()))
+    );
+
+    let options = ReaderOptions::new().multi_value_attributes([b"Note".as_ref()]);
+    let mut reader: Reader<Gff> = Reader::<Gff>::from_gxf_with_options(path, options).unwrap();
+    let gene = reader.records().next().unwrap().unwrap();
+    assert_eq!(
+        gene.get_extra(b"Dbxref"),
+        Some(&ExtraValue::Scalar(b"GeneID:1,HGNC:2".to_vec()))
+    );
+    assert_eq!(
+        gene.get_extra(b"Note"),
+        Some(&ExtraValue::Array(vec![b"a".to_vec(), b"b".to_vec()]))
+    );
+}
+
+#[test]
+fn test_reader_gff_shared_exon_attaches_to_every_parent() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("shared.gff");
+    std::fs::write(
+        &path,
+        "chr1\tsrc\tgene\t100\t900\t.\t+\t.\tID=g1\n\
+         chr1\tsrc\tmRNA\t100\t900\t.\t+\t.\tID=tx1;Parent=g1\n\
+         chr1\tsrc\tmRNA\t100\t900\t.\t+\t.\tID=tx2;Parent=g1\n\
+         chr1\tsrc\texon\t100\t200\t.\t+\t.\tParent=tx1,tx2\n\
+         chr1\tsrc\texon\t400\t500\t.\t+\t.\tParent=tx1\n\
+         chr1\tsrc\texon\t700\t900\t.\t+\t.\tParent=tx1,tx2\n",
+    )
+    .unwrap();
+
+    let check = |records: Vec<GenePred>| {
+        let exons = |id: &[u8]| {
+            records
+                .iter()
+                .find(|record| record.get_extra(b"ID").and_then(ExtraValue::first) == Some(id))
+                .unwrap()
+                .exons()
+        };
+        assert_eq!(exons(b"tx1"), vec![(99, 200), (399, 500), (699, 900)]);
+        assert_eq!(exons(b"tx2"), vec![(99, 200), (699, 900)]);
+    };
+
+    let mut reader: Reader<Gff> = Reader::from_path(&path).unwrap();
+    check(reader.records().map(|r| r.unwrap()).collect());
+
+    let options = ReaderOptions::new().streaming(true).streaming_window(2);
+    let mut streamed = Reader::<Gff>::from_gxf_with_options(&path, options).unwrap();
+    check(streamed.records().map(|r| r.unwrap()).collect());
+
+    #[cfg(feature = "rayon")]
+    {
+        let options = ReaderOptions::new().parallel(true);
+        let mut parallel = Reader::<Gff>::from_gxf_with_options(&path, options).unwrap();
+        check(parallel.records().map(|r| r.unwrap()).collect());
+    }
+}
+
+#[test]
+fn test_reader_gtf_derives_exons_from_cds_utr() {
+    let path = "tests/data/cds_utr.gtf";
+    let mut reader: Reader<Gtf> = Reader::from_path(path).unwrap();
+    let gene = reader.records().next().unwrap().unwrap();
+    assert_eq!(gene.exons(), vec![(99, 200)]);
+
+    let options = ReaderOptions::new().derive_exons_from_cds_utr(true);
+    let mut reader: Reader<Gtf> = Reader::<Gtf>::from_gxf_with_options(path, options).unwrap();
+    let gene = reader.records().next().unwrap().unwrap();
+    assert_eq!(gene.block_count(), Some(2));
+    assert_eq!(gene.exons(), vec![(99, 150), (169, 200)]);
+    assert_eq!(gene.thick_start(), Some(119));
+    assert_eq!(gene.thick_end(), Some(180));
+}
+
+#[test]
+fn test_reader_gtf_preserves_scores() {
+    let mut reader: Reader<Gtf> = Reader::from_path("tests/data/scored.gtf").unwrap();
+    let mut records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    records.sort();
+
+    assert_eq!(records[0].name(), Some(b"t1".as_ref()));
+    assert_eq!(records[0].score(), Some(960));
+    assert_eq!(records[1].name(), Some(b"t2".as_ref()));
+    assert_eq!(records[1].score(), Some(13));
+    assert_eq!(records[2].score(), None);
+
+    let line = "chr1\ttest\texon\t1\t10\thigh\t+\t.\tgene_id \"g\"; transcript_id \"t\";\n";
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("bad_score.gtf");
+    std::fs::write(&path, line).unwrap();
+    let err = Reader::<Gtf>::from_path(&path).err().unwrap();
+    assert!(err.to_string().contains("score"));
+}
+
+#[test]
+fn test_reader_gff_decode_percent() {
+    let data =
+        "chr1\ttest\tmRNA\t1\t30\t.\t+\t.\tID=t1;Note=first%3B second%2C third;Dbxref=A%2C1,B:2\n\
+                chr1\ttest\texon\t1\t30\t.\t+\t.\tParent=t1\n";
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("encoded.gff3");
+    std::fs::write(&path, data).unwrap();
+    let read = |options: ReaderOptions| {
+        let mut reader = Reader::<Gff>::from_gxf_with_options(&path, options).unwrap();
+        reader.records().next().unwrap().unwrap()
+    };
+
+    let raw = read(ReaderOptions::new());
+    assert_eq!(
+        raw.extras().get(b"Note".as_ref()),
+        Some(&ExtraValue::Scalar(b"first%3B second%2C third".to_vec()))
+    );
+
+    let decoded = read(ReaderOptions::new().decode_percent(true));
+    assert_eq!(
+        decoded.extras().get(b"Note".as_ref()),
+        Some(&ExtraValue::Scalar(b"first; second, third".to_vec()))
+    );
+    assert_eq!(
+        decoded.extras().get(b"Dbxref".as_ref()),
+        Some(&ExtraValue::Array(vec![b"A,1".to_vec(), b"B:2".to_vec()]))
+    );
+}
+
+#[test]
+fn test_gxf_summarize() {
+    let summary = genepred::gxf::summarize::<Gtf, _>("tests/data/simple.gtf").unwrap();
+    assert_eq!(summary.count(b"transcript"), 1);
+    assert_eq!(summary.count(b"exon"), 2);
+    assert_eq!(summary.count(b"CDS"), 1);
+    assert_eq!(summary.count(b"gene"), 0);
+    assert_eq!(summary.total(), 4);
+    assert_eq!(summary.parents, 1);
+    assert_eq!(
+        summary.chroms.iter().cloned().collect::<Vec<_>>(),
+        vec![b"chr1".to_vec()]
+    );
+
+    let summary = genepred::gxf::summarize::<Gtf, _>("tests/data/multi_transcript.gtf").unwrap();
+    let records = Reader::<Gtf>::from_gxf("tests/data/multi_transcript.gtf")
+        .unwrap()
+        .records()
+        .count();
+    assert_eq!(summary.parents, records);
+}
+
+#[test]
+fn test_reader_gtf_name_attributes() {
+    let data = "chr1\ttest\texon\t1\t10\t.\t+\t.\tgene_id \"g1\"; transcript_id \"t1\"; transcript_name \"TN1\"; gene_name \"GN1\"; ccds_id \"CCDS1\";\n\
+                chr1\ttest\texon\t20\t30\t.\t+\t.\tgene_id \"g2\"; transcript_id \"t2\"; transcript_name \"TN2\";\n";
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("names.gtf");
+    std::fs::write(&path, data).unwrap();
+    let names = |options: ReaderOptions| {
+        let mut reader = Reader::<Gtf>::from_gxf_with_options(&path, options).unwrap();
+        let mut records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+        records.sort();
+        records
+            .iter()
+            .map(|record| record.name().unwrap().to_vec())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        names(ReaderOptions::new()),
+        vec![b"TN1".to_vec(), b"TN2".to_vec()]
+    );
+    assert_eq!(
+        names(ReaderOptions::new().name_attributes([b"ccds_id".as_ref(), b"gene_id".as_ref()])),
+        vec![b"CCDS1".to_vec(), b"g2".to_vec()]
+    );
+    assert_eq!(
+        names(ReaderOptions::new().name_attributes([b"missing".as_ref()])),
+        vec![b"t1".to_vec(), b"t2".to_vec()]
+    );
+}
+
+#[test]
+fn test_reader_bytes_consumed() {
+    let path = "tests/data/bed12.bed";
+    let size = std::fs::metadata(path).unwrap().len();
+
+    let mut reader = Reader::<Bed12>::from_path(path).unwrap();
+    assert_eq!(reader.bytes_consumed(), 0);
+    let mut last = 0;
+    while let Some(record) = reader.next() {
+        record.unwrap();
+        let consumed = reader.bytes_consumed();
+        assert!(consumed > last);
+        last = consumed;
+    }
+    assert_eq!(reader.bytes_consumed(), size);
+
+    let data = std::fs::read(path).unwrap();
+    let mut reader = Reader::<Bed12>::from_bytes(data).unwrap();
+    reader.records().for_each(|r| {
+        r.unwrap();
+    });
+    assert_eq!(reader.bytes_consumed(), size);
+
+    let reports = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let sink = reports.clone();
+    let mut reader = Reader::<Bed12>::builder()
+        .from_path(path)
+        .progress(move |consumed| sink.lock().unwrap().push(consumed))
+        .build()
+        .unwrap();
+    assert!(reader.records().all(|r| r.is_ok()));
+    assert_eq!(*reports.lock().unwrap(), vec![size]);
+}
+
+#[test]
+fn test_reader_from_paths() {
+    let dir = tempfile::tempdir().unwrap();
+    let first = dir.path().join("chr1.bed");
+    let second = dir.path().join("chr2.bed");
+    std::fs::write(&first, "chr1\t10\t20\nchr1\t30\t40\n").unwrap();
+    std::fs::write(&second, "chr2\t5\t15\nchr2\tbad\t25\nchr2\t50\t60\n").unwrap();
+
+    let mut reader = Reader::<Bed3>::from_paths([&first, &second]).unwrap();
+    let results: Vec<_> = reader.records().collect();
+    assert_eq!(results.len(), 5);
+    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 4);
+    match results[3].as_ref().unwrap_err() {
+        genepred::reader::ReaderError::InFile { index, source, .. } => {
+            assert_eq!(*index, 1);
+            assert!(source.to_string().contains("line 2"));
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+
+    reader.rewind().unwrap();
+    let records: Vec<_> = reader.records().filter_map(Result::ok).collect();
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[2].chrom(), b"chr2".as_ref());
+
+    let missing = dir.path().join("missing.bed");
+    assert!(Reader::<Bed3>::from_paths([&first, &missing]).is_err());
+}
+
+#[test]
+fn test_reader_open_failure_names_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("missing.bed");
+    let error = Reader::<Bed3>::from_path(&missing).err().unwrap();
+    match &error {
+        genepred::reader::ReaderError::OpenFailed { path, source } => {
+            assert_eq!(path, &missing);
+            assert_eq!(source.kind(), std::io::ErrorKind::NotFound);
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+    assert!(error.to_string().contains(&missing.display().to_string()));
+
+    let missing = dir.path().join("missing.gtf");
+    let error = Reader::<Gtf>::from_gxf(&missing).err().unwrap();
+    assert!(error.to_string().contains(&missing.display().to_string()));
+}
+
+#[test]
+fn test_reader_genepred_and_refflat_tables() {
+    let check = |records: Vec<GenePred>| {
+        assert_eq!(records.len(), 2);
+
+        let coding = &records[0];
+        assert_eq!(coding.name(), Some(b"NM_001".as_ref()));
+        assert_eq!(coding.strand(), Some(Strand::Forward));
+        assert_eq!((coding.start(), coding.end()), (100, 500));
+        assert_eq!(coding.exons(), vec![(100, 200), (250, 300), (400, 500)]);
+        assert_eq!(coding.thick_start(), Some(150));
+        assert_eq!(coding.thick_end(), Some(450));
+        assert_eq!(
+            coding.coding_exons(),
+            vec![(150, 200), (250, 300), (400, 450)]
+        );
+
+        let noncoding = &records[1];
+        assert_eq!(noncoding.strand(), Some(Strand::Reverse));
+        assert_eq!(noncoding.exons(), vec![(1000, 1200), (1800, 2000)]);
+        assert!(noncoding.coding_exons().is_empty());
+    };
+
+    let mut reader = Reader::<GenePredTable>::from_path("tests/data/sample.genepred").unwrap();
+    check(reader.records().map(|r| r.unwrap()).collect());
+
+    let mut reader = Reader::<RefFlat>::from_path("tests/data/sample.refflat").unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(
+        records[0].get_extra(b"gene_name"),
+        Some(&ExtraValue::Scalar(b"GENE1".to_vec()))
+    );
+    check(records);
+}
+
+#[test]
+fn test_reader_bed12_and_gtf_share_block_model() {
+    let bed = "chr1\t100\t500\ttx1\t0\t+\t100\t500\t0,0,0\t2\t50,100,\t50,300,\n";
+    let mut reader = Reader::<Bed12>::from_reader(std::io::Cursor::new(bed)).unwrap();
+    let from_bed = reader.records().next().unwrap().unwrap();
+
+    let gtf = "chr1\tt\ttranscript\t101\t500\t.\t+\t.\tgene_id \"g1\"; transcript_id \"tx1\";\n\
+               chr1\tt\texon\t151\t200\t.\t+\t.\tgene_id \"g1\"; transcript_id \"tx1\";\n\
+               chr1\tt\texon\t401\t500\t.\t+\t.\tgene_id \"g1\"; transcript_id \"tx1\";\n";
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("offset.gtf");
+    std::fs::write(&path, gtf).unwrap();
+    let mut reader = Reader::<Gtf>::from_path(&path).unwrap();
+    let from_gtf = reader.records().next().unwrap().unwrap();
+
+    assert_eq!(from_bed.start(), from_gtf.start());
+    assert_eq!(from_bed.exons(), vec![(150, 200), (400, 500)]);
+    assert_eq!(from_gtf.exons(), from_bed.exons());
+    assert_eq!(from_gtf.block_starts(), from_bed.block_starts());
+    assert_eq!(from_gtf.block_ends(), from_bed.block_ends());
+
+    for record in [&from_bed, &from_gtf] {
+        let mut buf = Vec::new();
+        genepred::Writer::<Bed12>::from_record(record, &mut buf).unwrap();
+        let line = String::from_utf8(buf.clone()).unwrap();
+        assert!(line.contains("\t2\t50,100,\t50,300,"));
+        let mut reader = Reader::<Bed12>::from_reader(std::io::Cursor::new(buf)).unwrap();
+        assert_eq!(
+            reader.records().next().unwrap().unwrap().exons(),
+            from_bed.exons()
+        );
+    }
+}
+
+#[test]
+fn test_reader_shared_options_across_inputs() {
+    let options = ReaderOptions::new()
+        .additional_fields(3)
+        .buffer_capacity(16 * 1024);
+
+    let mut from_path =
+        Reader::<Bed3>::from_path_custom_fields("tests/data/bed6.bed", options.clone()).unwrap();
+    let records: Vec<_> = from_path.records().map(|r| r.unwrap()).collect();
+    assert_eq!(from_path.additional_fields(), 3);
+    assert_eq!(records.len(), 2);
+    assert_eq!(
+        records[1].extras().get(b"4".as_ref()),
+        Some(&ExtraValue::Scalar(b"geneB".to_vec()))
+    );
+
+    let data = "chr2\t5\t15\tgeneC\t10\t+\n";
+    let mut from_reader =
+        Reader::<Bed3>::from_reader_custom_fields(std::io::Cursor::new(data), options).unwrap();
+    let records: Vec<_> = from_reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(from_reader.additional_fields(), 3);
+    assert_eq!(
+        records[0].extras().get(b"6".as_ref()),
+        Some(&ExtraValue::Scalar(b"+".to_vec()))
+    );
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_reader_options_select_mode() {
+    let options = ReaderOptions::new().mode(genepred::ReaderMode::Mmap);
+    for path in ["tests/data/bed3.bed", "tests/data/bed6.bed"] {
+        let mut reader = Reader::<Bed3>::from_path_custom_fields(path, options.clone()).unwrap();
+        assert_eq!(reader.record_count_hint(), Some(2));
+        assert_eq!(reader.records().count(), 2);
+    }
+}
+
+#[test]
+fn test_reader_custom_comment_char() {
+    let data =
+        "; exported by tool\nchr1\t10\t20\n  ;indented note\n# kept as data?\nchr1\t30\t40\n";
+    let mut reader = Reader::<Bed3>::builder()
+        .from_reader(std::io::Cursor::new(data))
+        .comment_char(b';')
+        .build()
+        .unwrap();
+    let results: Vec<_> = reader.records().collect();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().start(), 10);
+    assert!(results[1].is_err());
+    assert_eq!(results[2].as_ref().unwrap().start(), 30);
+}
+
+#[test]
+fn test_reader_skip_track_lines_disabled() {
+    let data = "track name=demo\nbrowser position chr1:1-100\nchr1\t10\t20\n";
+
+    let mut reader = Reader::<Bed3>::from_reader(std::io::Cursor::new(data)).unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records.len(), 1);
+
+    let options = ReaderOptions::new().skip_track_lines(false);
+    let mut reader =
+        Reader::<Bed3>::from_reader_custom_fields(std::io::Cursor::new(data), options).unwrap();
+    let results: Vec<_> = reader.records().collect();
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_err());
+    assert!(results[1].is_err());
+    assert_eq!(results[2].as_ref().unwrap().end(), 20);
+
+    let data = "browser 1\t0\t10\n";
+    let mut reader = Reader::<Bed3>::builder()
+        .from_reader(std::io::Cursor::new(data))
+        .skip_track_lines(false)
+        .build()
+        .unwrap();
+    let record = reader.records().next().unwrap().unwrap();
+    assert_eq!(record.chrom(), b"browser 1");
+}
+
+#[test]
+fn test_reader_one_based_coordinates() {
+    let data = "chr1\t1\t10\n";
+    let mut reader = Reader::<Bed3>::builder()
+        .from_reader(std::io::Cursor::new(data))
+        .one_based(true)
+        .build()
+        .unwrap();
+    let record = reader.records().next().unwrap().unwrap();
+    assert_eq!((record.start(), record.end()), (0, 10));
+
+    let data = "chr1\t101\t400\ttxA\t0\t+\t121\t360\t0,0,0\t2\t80,100\t0,200\n\
+                chr1\t501\t600\ttxB\t0\t+\t600\t600\t0,0,0\t1\t100\t0\n";
+    let options = ReaderOptions::new().one_based(true);
+    let mut reader =
+        Reader::<Bed12>::from_reader_custom_fields(std::io::Cursor::new(data), options).unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!((records[0].start(), records[0].end()), (100, 400));
+    assert_eq!(records[0].exons(), vec![(100, 180), (300, 400)]);
+    assert_eq!(records[0].thick_start(), Some(120));
+    assert_eq!(records[0].thick_end(), Some(360));
+    assert_eq!(records[1].exons(), vec![(500, 600)]);
+    assert_eq!(records[1].thick_start(), Some(600));
+}
+
+#[test]
+fn test_reader_error_policy() {
+    let data = "chr1\t10\t20\nchr1\tbad\t40\nchr2\t50\t60\n";
+    let build = |policy| {
+        Reader::<Bed3>::builder()
+            .from_reader(std::io::Cursor::new(data))
+            .on_error(policy)
+            .build()
+            .unwrap()
+    };
+
+    let results: Vec<_> = build(ErrorPolicy::Fail).collect();
+    assert_eq!(results.len(), 3);
+    assert!(results[1].is_err());
+
+    let mut reader = build(ErrorPolicy::Skip);
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records.len(), 2);
+    assert!(reader.errors().is_empty());
+
+    let mut reader = build(ErrorPolicy::Collect);
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].chrom(), b"chr1".as_ref());
+    assert_eq!(records[1].chrom(), b"chr2".as_ref());
+    assert_eq!(reader.errors().len(), 1);
+    assert!(reader.errors()[0].to_string().contains("line 2"));
+}
+
+#[test]
+fn test_reader_into_records_across_threads() {
+    let data = "chr1\t10\t20\nchr1\t30\t40\nchr2\t50\t60\n";
+    let records = Reader::<Bed3>::from_reader(std::io::Cursor::new(data.to_string()))
+        .unwrap()
+        .into_records();
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let handle = std::thread::spawn(move || {
+        for record in records {
+            tx.send(record.unwrap()).unwrap();
+        }
+    });
+    let received: Vec<(Vec<u8>, u64)> = rx
+        .iter()
+        .map(|record| (record.chrom().to_vec(), record.start()))
+        .collect();
+    handle.join().unwrap();
+
+    assert_eq!(
+        received,
+        vec![
+            (b"chr1".to_vec(), 10),
+            (b"chr1".to_vec(), 30),
+            (b"chr2".to_vec(), 50),
+        ]
+    );
+}
+
+fn write_conflicting_gtf(dir: &std::path::Path) -> std::path::PathBuf {
+    let path = dir.join("conflict.gtf");
+    std::fs::write(
+        &path,
+        "chr1\tsrc\texon\t100\t150\t.\t+\t.\tgene_id \"g1\"; transcript_id \"tx1\";\n\
+         chr1\tsrc\texon\t170\t200\t.\t-\t.\tgene_id \"g1\"; transcript_id \"tx1\";\n\
+         chr1\tsrc\texon\t300\t350\t.\t+\t.\tgene_id \"g2\"; transcript_id \"tx2\";\n\
+         chr2\tsrc\texon\t400\t450\t.\t+\t.\tgene_id \"g2\"; transcript_id \"tx2\";\n\
+         chr1\tsrc\texon\t500\t550\t.\t+\t.\tgene_id \"g3\"; transcript_id \"tx3\";\n",
+    )
+    .unwrap();
+    path
+}
+
+#[test]
+fn test_reader_gxf_strand_conflict_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_conflicting_gtf(dir.path());
+    assert!(Reader::<Gtf>::from_gxf(&path).is_err());
+}
+
+#[test]
+fn test_reader_gxf_strand_conflict_skip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_conflicting_gtf(dir.path());
+    let options = ReaderOptions::new().on_strand_conflict(ConflictPolicy::Skip);
+    let mut reader = Reader::<Gtf>::from_gxf_with_options(&path, options).unwrap();
+    let names: Vec<_> = reader
+        .records()
+        .map(|r| r.unwrap().name().unwrap().to_vec())
+        .collect();
+    assert_eq!(names, vec![b"tx3".to_vec()]);
+}
+
+#[test]
+fn test_reader_gxf_strand_conflict_set_unknown() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_conflicting_gtf(dir.path());
+    let options = ReaderOptions::new()
+        .on_strand_conflict(ConflictPolicy::SetUnknown)
+        .streaming(true);
+    let mut reader = Reader::<Gtf>::from_gxf_with_options(&path, options).unwrap();
+    let mut records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    records.sort_by_key(|record| record.start());
+    assert_eq!(records.len(), 3);
+
+    assert_eq!(records[0].name(), Some(b"tx1".as_ref()));
+    assert_eq!(records[0].strand(), Some(Strand::Unknown));
+    assert_eq!(records[0].block_count(), Some(2));
+
+    assert_eq!(records[1].name(), Some(b"tx2".as_ref()));
+    assert_eq!(records[1].chrom(), b"chr1".as_ref());
+    assert_eq!(records[1].strand(), Some(Strand::Forward));
+    assert_eq!(records[1].end(), 350);
+    assert_eq!(records[1].block_count(), Some(1));
+}
+
+#[test]
+fn test_reader_gxf_output_order_is_deterministic() {
+    let path = "tests/data/multi_transcript.gtf";
+    let read = || -> Vec<(Vec<u8>, u64, u64)> {
+        Reader::<Gtf>::from_gxf(path)
+            .unwrap()
+            .records()
+            .map(|r| {
+                let record = r.unwrap();
+                (record.chrom().to_vec(), record.start(), record.end())
+            })
+            .collect()
+    };
+
+    let first = read();
+    assert_eq!(
+        first,
+        vec![
+            (b"chr1".to_vec(), 99, 300),
+            (b"chr1".to_vec(), 99, 500),
+            (b"chr2".to_vec(), 999, 2000),
+        ]
+    );
+    for _ in 0..8 {
+        assert_eq!(read(), first);
+    }
+}
+
+#[test]
+fn test_reader_gxf_utr_only_and_extra_feature_types() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("utr.gtf");
+    std::fs::write(
+        &path,
+        "chr1\tsrc\tfive_prime_UTR\t101\t150\t.\t+\t.\tgene_id \"g1\"; transcript_id \"tx1\";\n\
+         chr1\tsrc\tthree_prime_UTR\t301\t400\t.\t+\t.\tgene_id \"g1\"; transcript_id \"tx1\";\n\
+         chr1\tsrc\tpseudogenic_exon\t501\t550\t.\t+\t.\tgene_id \"g2\"; transcript_id \"tx2\";\n\
+         chr1\tsrc\tpseudogenic_exon\t601\t700\t.\t+\t.\tgene_id \"g2\"; transcript_id \"tx2\";\n",
+    )
+    .unwrap();
+
+    let records: Vec<_> = Reader::<Gtf>::from_gxf(&path)
+        .unwrap()
+        .records()
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].exons(), vec![(100, 150), (300, 400)]);
+
+    let options = ReaderOptions::new()
+        .on_exonless(ExonlessPolicy::Error)
+        .extra_feature_types(FeatureKind::Exon, [b"pseudogenic_exon".as_ref()]);
+    let records: Vec<_> = Reader::<Gtf>::from_gxf_with_options(&path, options)
+        .unwrap()
+        .records()
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].name(), Some(b"tx1".as_ref()));
+    assert_eq!(records[0].exons(), vec![(100, 150), (300, 400)]);
+    assert_eq!(records[1].name(), Some(b"tx2".as_ref()));
+    assert_eq!(records[1].exons(), vec![(500, 550), (600, 700)]);
+}
+
+#[test]
+fn test_reader_records_with_raw() {
+    let path = "tests/data/bed6.bed";
+    let lines: Vec<String> = std::fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect();
+
+    let mut reader = Reader::<Bed6>::from_path(path).unwrap();
+    let pairs: Vec<_> = reader.records_with_raw().map(|r| r.unwrap()).collect();
+    assert_eq!(pairs.len(), 2);
+    for ((record, raw), line) in pairs.iter().zip(&lines) {
+        assert_eq!(raw, line);
+        assert_eq!(record.chrom(), b"chr1".as_ref());
+    }
+
+    let mut reader = Reader::<Bed6>::builder()
+        .from_path(path)
+        .retain_raw(true)
+        .build()
+        .unwrap();
+    assert!(reader.raw_line().is_none());
+    reader.records().next().unwrap().unwrap();
+    assert_eq!(reader.raw_line(), Some(lines[0].as_str()));
+}
+
+#[test]
+fn test_reader_records_with_raw_gtf_uses_transcript_id() {
+    let mut reader = Reader::<Gtf>::from_gxf("tests/data/simple.gtf").unwrap();
+    let (_, raw) = reader.records_with_raw().next().unwrap().unwrap();
+    assert_eq!(raw, "tx1");
+}
+
+#[test]
+fn test_reader_rejects_inverted_coordinates() {
+    let mut reader = Reader::<Bed3>::from_reader(std::io::Cursor::new(
+        "chr1\t200\t100\nchr1\t100\t100\n".to_string(),
+    ))
+    .unwrap();
+    let results = reader.records().collect::<Vec<_>>();
+
+    let message = results[0].as_ref().unwrap_err().to_string();
+    assert!(message.contains("line 1"));
+    assert!(message.contains("coordinates"));
+    assert!(message.contains("end (100) must be >= start (200)"));
+    assert!(results[1].as_ref().unwrap().is_empty());
+}
+
+#[test]
+fn test_reader_lenient_score() {
+    let data = "chr1\t10\t20\tpeak1\t50000\nchr1\t30\t40\tpeak2\t500\n";
+    let read = |lenient| {
+        Reader::<Bed5>::builder()
+            .from_reader(std::io::Cursor::new(data.to_string()))
+            .lenient_score(lenient)
+            .build()
+            .unwrap()
+            .records()
+            .collect::<Vec<_>>()
+    };
+
+    let strict = read(false);
+    let message = strict[0].as_ref().unwrap_err().to_string();
+    assert!(message.contains("score"));
+    assert_eq!(strict[1].as_ref().unwrap().score(), Some(500));
+
+    let lenient = read(true);
+    assert_eq!(lenient[0].as_ref().unwrap().score(), Some(1000));
+    assert_eq!(lenient[1].as_ref().unwrap().score(), Some(500));
+
+    let mut reader = Reader::<Bed5>::builder()
+        .from_reader(std::io::Cursor::new("chr1\t10\t20\tpeak\t12.5\n"))
+        .lenient_score(true)
+        .build()
+        .unwrap();
+    assert!(reader.records().next().unwrap().is_err());
+}
+
+#[test]
+fn test_reader_length_range() {
+    let data = "chr1\t10\t15\nchr1\t20\t120\nchr1\t200\t5200\nchr1\t300\t400\n";
+    let mut reader = Reader::<Bed3>::builder()
+        .from_reader(std::io::Cursor::new(data.to_string()))
+        .length_range(50, Some(1000))
+        .build()
+        .unwrap();
+    assert_eq!(reader.filtered_count(), 0);
+
+    let starts: Vec<u64> = reader
+        .records()
+        .map(|record| record.unwrap().start())
+        .collect();
+    assert_eq!(starts, vec![20, 300]);
+    assert_eq!(reader.filtered_count(), 2);
+
+    let mut reader = Reader::<Bed3>::builder()
+        .from_reader(std::io::Cursor::new(data.to_string()))
+        .length_range(10, None)
+        .build()
+        .unwrap();
+    assert_eq!(reader.records().count(), 3);
+    assert_eq!(reader.filtered_count(), 1);
+}
+
+#[test]
+fn test_reader_enforce_chrom_style() {
+    let read = |data: &str, style| {
+        Reader::<Bed3>::builder()
+            .from_reader(std::io::Cursor::new(data.to_string()))
+            .enforce_chrom_style(style)
+            .build()
+            .unwrap()
+            .records()
+            .collect::<Vec<_>>()
+    };
+
+    let results = read("chr1\t10\t20\n1\t30\t40\n", ChromStyle::Ucsc);
+    assert!(results[0].is_ok());
+    let message = results[1].as_ref().unwrap_err().to_string();
+    assert!(message.contains("line 2"));
+    assert!(message.contains("chrom"));
+
+    let results = read("chr1\t10\t20\n1\t30\t40\n", ChromStyle::Ensembl);
+    assert!(results[0].is_err());
+    assert!(results[1].is_ok());
+
+    let results = read(
+        "1\t10\t20\n2\t30\t40\nchrX\t50\t60\n",
+        ChromStyle::Consistent,
+    );
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+    assert!(results[2]
+        .as_ref()
+        .unwrap_err()
+        .to_string()
+        .contains("line 3"));
+
+    let results = read("chrX\t10\t20\n2\t30\t40\n", ChromStyle::Consistent);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+
+    let mut reader = Reader::<Bed3>::builder()
+        .from_reader(std::io::Cursor::new(
+            "chr1\t10\t20\n1\t30\t40\nchr2\t50\t60\n",
+        ))
+        .enforce_chrom_style(ChromStyle::Consistent)
+        .on_error(ErrorPolicy::Collect)
+        .build()
+        .unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(reader.errors().len(), 1);
+}
+
+#[test]
+fn test_reader_normalize_chroms() {
+    let mut reader = Reader::<Bed3>::builder()
+        .from_reader(std::io::Cursor::new(
+            "1\t10\t20\nchrMT\t30\t40\nchrX\t50\t60\n",
+        ))
+        .build()
+        .unwrap();
+    let chroms: Vec<_> = reader
+        .normalize_chroms(ChromStyle::Ucsc)
+        .map(|r| r.unwrap().chrom().to_vec())
+        .collect();
+    assert_eq!(
+        chroms,
+        vec![b"chr1".to_vec(), b"chrM".to_vec(), b"chrX".to_vec()]
+    );
+}
+
+#[test]
+fn test_reader_validate_blocks() {
+    let read = |line: &str, validate: bool| {
+        Reader::<Bed12>::builder()
+            .from_reader(std::io::Cursor::new(line.to_string()))
+            .validate_blocks(validate)
+            .build()
+            .unwrap()
+            .records()
+            .next()
+            .unwrap()
+    };
+    let message = |line: &str| read(line, true).unwrap_err().to_string();
+
+    let valid = "chr1\t100\t400\ttx\t0\t+\t100\t400\t0,0,0\t2\t80,100\t0,200\n";
+    assert!(read(valid, true).is_ok());
+
+    let shifted = "chr1\t100\t400\ttx\t0\t+\t100\t400\t0,0,0\t2\t80,100\t10,200\n";
+    assert!(read(shifted, false).is_ok());
+    assert!(message(shifted).contains("first block starts at 10, expected 0"));
+
+    let short = "chr1\t100\t400\ttx\t0\t+\t100\t400\t0,0,0\t2\t80,90\t0,200\n";
+    assert!(message(short).contains("last block ends at 290, expected feature length 300"));
+
+    let overlapping = "chr1\t100\t400\ttx\t0\t+\t100\t400\t0,0,0\t2\t80,250\t0,50\n";
+    assert!(
+        message(overlapping).contains("block 2 starts at 50, before the previous block ends at 80")
+    );
+
+    let unsorted = "chr1\t100\t400\ttx\t0\t+\t100\t400\t0,0,0\t3\t50,50,100\t0,200,100\n";
+    assert!(
+        message(unsorted).contains("block 3 starts at 100, before the previous block ends at 250")
+    );
+}
+
+#[test]
+fn test_reader_gxf_from_bytes_matches_from_path() {
+    let path = "tests/data/multi_transcript.gtf";
+    let expected: Vec<_> = Reader::<Gtf>::from_path(path)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+
+    let mut reader = Reader::<Gtf>::from_bytes(std::fs::read(path).unwrap()).unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records, expected);
+    reader.rewind().unwrap();
+    assert_eq!(reader.records().count(), expected.len());
+
+    let mut reader: Reader<Gtf> = Reader::builder()
+        .from_bytes(std::fs::read(path).unwrap())
+        .options(ReaderOptions::new().streaming(true))
+        .build()
+        .unwrap();
+    let mut streamed: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    streamed.sort();
+    let mut sorted = expected.clone();
+    sorted.sort();
+    assert_eq!(streamed, sorted);
+
+    let gff = std::fs::read("tests/data/simple.gff").unwrap();
+    let records: Vec<_> = Reader::<Gff>::from_bytes(gff)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    let expected: Vec<_> = Reader::<Gff>::from_path("tests/data/simple.gff")
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(records, expected);
+}
+
+#[test]
+fn test_reader_from_bytes_matches_from_reader() {
+    const DATA: &[u8] = b"\xEF\xBB\xBFchr1\t10\t20\n# comment\nchr1\t30\t40\r\nchr2\t5\t15";
+    let mut borrowed = Reader::<Bed3>::from_bytes(DATA).unwrap();
+    let from_bytes: Vec<_> = borrowed.records().map(|r| r.unwrap()).collect();
+    let from_reader: Vec<_> = Reader::<Bed3>::from_reader(std::io::Cursor::new(DATA))
+        .unwrap()
+        .records()
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(from_bytes.len(), 3);
+    assert_eq!(from_bytes, from_reader);
+    assert_eq!(from_bytes[0].chrom(), b"chr1".as_ref());
+
+    borrowed.rewind().unwrap();
+    assert_eq!(borrowed.record_count_hint(), Some(3));
+
+    #[cfg(feature = "rayon")]
+    {
+        let parallel: Vec<_> = Reader::<Bed3>::from_bytes(DATA)
+            .unwrap()
+            .par_records()
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect();
+        assert_eq!(parallel, from_reader);
+    }
+
+    let owned = Reader::<Bed4>::builder()
+        .from_bytes(b"chr1\t1\t10\tgene\n".to_vec())
+        .one_based(true)
+        .build()
+        .unwrap();
+    let records: Vec<_> = owned.map(|r| r.unwrap()).collect();
+    assert_eq!((records[0].start(), records[0].end()), (0, 10));
+}
+
+#[test]
+fn test_reader_item_rgb_sentinels() {
+    let data = "chr1\t10\t20\ta\t0\t+\t10\t20\t0\n\
+                chr1\t30\t40\tb\t0\t+\t30\t40\t.\n\
+                chr1\t50\t60\tc\t0\t+\t50\t60\t255,0,0\n\
+                chr1\t70\t80\td\t0\t+\t70\t80\t255,0\n";
+    let results: Vec<_> = Reader::<Bed9>::from_reader(std::io::Cursor::new(data))
+        .unwrap()
+        .collect();
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap().item_rgb(), Some(Rgb(0, 0, 0)));
+    assert_eq!(results[1].as_ref().unwrap().item_rgb(), Some(Rgb(0, 0, 0)));
+    assert_eq!(
+        results[2].as_ref().unwrap().item_rgb(),
+        Some(Rgb(255, 0, 0))
+    );
+    let err = results[3].as_ref().unwrap_err().to_string();
+    assert!(err.contains("missing blue component"), "{err}");
+}
+
+#[test]
+fn test_reader_space_delimited_bed6() {
+    let data = "chr1 10 20 geneA 5 +\nchr2  30   40 geneB 0 -  \n";
+    let records: Vec<_> = Reader::<Bed6>::builder()
+        .from_reader(std::io::Cursor::new(data))
+        .delimiter(b' ')
+        .build()
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].name(), Some(b"geneA".as_ref()));
+    assert_eq!(records[1].chrom(), b"chr2".as_ref());
+    assert_eq!((records[1].start(), records[1].end()), (30, 40));
+    assert_eq!(records[1].strand(), Some(Strand::Reverse));
+
+    let piped = "chr1|10|20|geneA|5|+\n";
+    let records: Vec<_> = Reader::<Bed6>::builder()
+        .from_bytes(piped)
+        .delimiter(b'|')
+        .build()
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(records[0].name(), Some(b"geneA".as_ref()));
+    assert_eq!(records[0].strand(), Some(Strand::Forward));
+}
+
+#[test]
+fn test_reader_gff_metadata_pragmas() {
+    let path = "tests/data/pragmas.gff";
+    let mut reader: Reader<Gff> = Reader::from_path(path).unwrap();
+    let metadata = reader.metadata().unwrap().clone();
+    assert_eq!(metadata.gff_version.as_deref(), Some("3"));
+    assert_eq!(
+        metadata.species.as_deref(),
+        Some("https://www.ncbi.nlm.nih.gov/Taxonomy/Browser/wwwtax.cgi?id=9606")
+    );
+    assert_eq!(metadata.genome_build.as_deref(), Some("NCBI GRCh38.p14"));
+    assert_eq!(
+        metadata.sequence_regions,
+        vec![SequenceRegion {
+            seqid: "chr1".into(),
+            start: 1,
+            end: 248956422,
+        }]
+    );
+    assert_eq!(metadata.other, vec!["#!processor example".to_string()]);
+    assert_eq!(reader.records().count(), 1);
+
+    let options = ReaderOptions::new().streaming(true);
+    let mut reader: Reader<Gff> = Reader::<Gff>::from_gxf_with_options(path, options).unwrap();
+    assert_eq!(reader.metadata(), Some(&metadata));
+    let gene = reader.records().next().unwrap().unwrap();
+    assert_eq!(gene.exons(), vec![(100, 200), (300, 400)]);
+
+    let reader: Reader<Bed3> = Reader::from_path("tests/data/bed3.bed").unwrap();
+    assert!(reader.metadata().is_none());
+}
+
+#[test]
+fn test_reader_gff_stops_at_fasta() {
+    let path = "tests/data/fasta.gff";
+    for streaming in [false, true] {
+        let options = ReaderOptions::new().streaming(streaming);
+        let mut reader: Reader<Gff> = Reader::<Gff>::from_gxf_with_options(path, options).unwrap();
+        let records: Vec<_> = reader.records().map(|record| record.unwrap()).collect();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].exons(), vec![(2, 8), (14, 20)]);
+        let metadata = reader.metadata().unwrap();
+        assert_eq!(metadata.sequence_regions[0].end, 24);
+        assert!(metadata.sequences.is_empty());
+    }
+
+    let options = ReaderOptions::new().capture_fasta(true);
+    let reader: Reader<Gff> = Reader::<Gff>::from_gxf_with_options(path, options).unwrap();
+    let metadata = reader.metadata().unwrap();
+    assert_eq!(
+        metadata.sequences,
+        vec![
+            FastaSequence {
+                id: "ctg1".into(),
+                sequence: b"ACGTACGTACGTACGTACGTACGT".to_vec(),
+            },
+            FastaSequence {
+                id: "ctg2".into(),
+                sequence: b"TTTT".to_vec(),
+            },
+        ]
+    );
+    assert_eq!(metadata.sequence("ctg2"), Some(b"TTTT".as_ref()));
+    assert_eq!(metadata.sequence("ctg3"), None);
+}
+
+#[test]
+fn test_reader_gff_discontinuous_cds() {
+    let mut reader: Reader<Gff> = Reader::from_path("tests/data/discontinuous.gff").unwrap();
+    let records: Vec<_> = reader.records().map(|record| record.unwrap()).collect();
+    assert_eq!(records.len(), 1);
+
+    let record = &records[0];
+    assert_eq!(record.name(), Some(b"Tx1".as_ref()));
+    assert_eq!(
+        record.extras().get(b"ID".as_ref()),
+        Some(&ExtraValue::Scalar(b"tx1".to_vec()))
+    );
+    assert_eq!(record.block_count(), Some(3));
+    assert_eq!(record.thick_start(), Some(150));
+    assert_eq!(record.thick_end(), Some(850));
+    assert_eq!(record.cds_phases(), Some([0, 1, 0].as_ref()));
+}
+
+#[test]
+fn test_reader_gtf_aggregate_by_gene() {
+    let path = "tests/data/multi_transcript.gtf";
+    for streaming in [false, true] {
+        let options = ReaderOptions::new()
+            .aggregate_by(AggregationLevel::Gene)
+            .streaming(streaming);
+        let reader: Reader<Gtf> = Reader::<Gtf>::from_gxf_with_options(path, options).unwrap();
+        let mut records: Vec<_> = reader.map(|r| r.unwrap()).collect();
+        records.sort_by(|a, b| a.chrom().cmp(b.chrom()));
+        assert_eq!(records.len(), 2);
+
+        let gene = &records[0];
+        assert_eq!(gene.name(), Some(b"g1".as_ref()));
+        assert_eq!((gene.start(), gene.end()), (99, 500));
+        assert_eq!(gene.exons(), vec![(99, 150), (199, 300), (399, 500)]);
+        assert_eq!(gene.thick_start(), Some(119));
+        assert_eq!(gene.thick_end(), Some(280));
+        assert_eq!(
+            gene.get_extra(b"gene_id"),
+            Some(&ExtraValue::Scalar(b"g1".to_vec()))
+        );
+        assert_eq!(gene.get_extra(b"transcript_id"), None);
+
+        assert_eq!(records[1].name(), Some(b"TxThree".as_ref()));
+        assert_eq!(records[1].block_count(), Some(2));
+    }
+}
+
 #[test]
 fn test_reader_gff_from_path() {
     let path = "tests/data/simple.gff";
@@ -283,6 +1663,17 @@ fn test_reader_bed3_gz_from_path() {
     assert_eq!(records[1].end(), 200);
 }
 
+#[cfg(feature = "bgzf")]
+#[test]
+fn test_reader_bed3_bgzf_from_path() {
+    let path = "tests/data/bed3_bgzf.bed.gz";
+    let mut reader: Reader<Bed3> = Reader::from_path(path).unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].start(), 0);
+    assert_eq!(records[1].end(), 200);
+}
+
 #[cfg(feature = "gzip")]
 #[test]
 fn test_reader_gtf_gz_from_path() {
@@ -295,6 +1686,398 @@ fn test_reader_gtf_gz_from_path() {
     assert_eq!(gene.block_count().unwrap(), 2);
 }
 
+#[cfg(all(feature = "gzip", feature = "mmap"))]
+#[test]
+fn test_reader_gtf_gz_mmap_mode() {
+    let path = "tests/data/simple.gtf.gz";
+    for streaming in [false, true] {
+        let mut reader = Reader::<Gtf>::builder()
+            .from_path(path)
+            .mode(genepred::ReaderMode::Mmap)
+            .options(ReaderOptions::new().streaming(streaming))
+            .build()
+            .unwrap();
+        let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].name().unwrap(), b"GeneOne".as_ref());
+        assert_eq!(records[0].block_count().unwrap(), 2);
+    }
+}
+
+#[test]
+fn test_reader_from_records() {
+    let records = vec![
+        GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new()),
+        GenePred::from_coords(b"chr2".to_vec(), 30, 40, Extras::new()),
+    ];
+    let mut reader: Reader<Bed3> = Reader::from_records(records.clone()).unwrap();
+    let read: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(read, records);
+}
+
+#[test]
+fn test_reader_fold_records() {
+    let data = "chr1\t10\t20\nchr2\t30\t40\nchr1\t50\t60\n";
+    let reader: Reader<Bed3> = Reader::from_reader(std::io::Cursor::new(data.as_bytes())).unwrap();
+    let count = reader.fold_records(0usize, |count, _| count + 1).unwrap();
+    assert_eq!(count, 3);
+
+    let reader: Reader<Bed3> = Reader::from_reader(std::io::Cursor::new(data.as_bytes())).unwrap();
+    let chroms = reader
+        .fold_records(std::collections::BTreeSet::new(), |mut chroms, record| {
+            chroms.insert(record.chrom);
+            chroms
+        })
+        .unwrap();
+    assert_eq!(
+        chroms.into_iter().collect::<Vec<_>>(),
+        vec![b"chr1".to_vec(), b"chr2".to_vec()]
+    );
+
+    let data = "chr1\t10\t20\nbad\nchr1\t50\t60\n";
+    let reader: Reader<Bed3> = Reader::from_reader(std::io::Cursor::new(data.as_bytes())).unwrap();
+    assert!(reader.fold_records(0usize, |count, _| count + 1).is_err());
+}
+
+#[test]
+fn test_reader_keeps_empty_interior_fields() {
+    let data = "chr1\t10\t20\t\t500\t+\n";
+    let mut reader: Reader<Bed6> =
+        Reader::from_reader(std::io::Cursor::new(data.as_bytes())).unwrap();
+    let record = reader.records().next().unwrap().unwrap();
+    assert_eq!(record.name(), Some(b"".as_ref()));
+    assert_eq!(record.score(), Some(500));
+    assert_eq!(record.strand(), Some(Strand::Forward));
+
+    let data = "chr1\t10\t20\tx\t\tkept\n";
+    for lazy in [false, true] {
+        let mut reader: Reader<Bed4> = Reader::builder()
+            .from_reader(std::io::Cursor::new(data.as_bytes()))
+            .additional_fields(2)
+            .lazy_extras(lazy)
+            .build()
+            .unwrap();
+        let record = reader.records().next().unwrap().unwrap();
+        assert_eq!(
+            record.get_extra(b"5"),
+            Some(&ExtraValue::Scalar(Vec::new()))
+        );
+        assert_eq!(
+            record.get_extra(b"6"),
+            Some(&ExtraValue::Scalar(b"kept".to_vec()))
+        );
+    }
+
+    let data = "chr1\t10\t20\tx\n";
+    let mut reader: Reader<Bed6> =
+        Reader::from_reader(std::io::Cursor::new(data.as_bytes())).unwrap();
+    assert!(matches!(
+        reader.records().next(),
+        Some(Err(
+            genepred::reader::ReaderError::UnexpectedFieldCount { .. }
+        ))
+    ));
+}
+
+#[test]
+fn test_reader_count_records() {
+    let data = "track name=x\nchr1\t10\t20\n# comment\n\nchr1\t30\t40\nchr2\t50\t60\n";
+    let mut reader: Reader<Bed3> =
+        Reader::from_reader(std::io::Cursor::new(data.as_bytes())).unwrap();
+    assert_eq!(reader.record_count_hint(), None);
+    assert_eq!(reader.count_records().unwrap(), 3);
+    assert_eq!(reader.current_line(), 6);
+    assert!(reader.records().next().is_none());
+
+    let mut reader: Reader<Gtf> = Reader::from_path("tests/data/multi_transcript.gtf").unwrap();
+    assert_eq!(reader.record_count_hint(), Some(3));
+    reader.records().next().unwrap().unwrap();
+    assert_eq!(reader.record_count_hint(), Some(2));
+    assert_eq!(reader.count_records().unwrap(), 2);
+    assert_eq!(reader.record_count_hint(), Some(0));
+    assert!(reader.records().next().is_none());
+}
+
+#[test]
+fn test_reader_record_count_hint_tracks_in_memory_cursor() {
+    const DATA: &[u8] = b"# header\nchr1\t10\t20\nchr1\t30\t40\nchr2\t50\t60\n";
+    let mut reader = Reader::<Bed3>::from_bytes(DATA).unwrap();
+    assert_eq!(reader.record_count_hint(), Some(3));
+    assert_eq!(reader.record_count_hint(), Some(3));
+    reader.records().next().unwrap().unwrap();
+    assert_eq!(reader.record_count_hint(), Some(2));
+    reader.rewind().unwrap();
+    assert_eq!(reader.record_count_hint(), Some(3));
+    assert_eq!(reader.count_records().unwrap(), 3);
+    assert_eq!(reader.record_count_hint(), Some(0));
+
+    let filtered = Reader::<Bed3>::builder()
+        .from_bytes(DATA)
+        .length_range(0, Some(10))
+        .build()
+        .unwrap();
+    assert_eq!(filtered.record_count_hint(), None);
+}
+
+#[test]
+fn test_reader_count_records_matches_filtered_iteration() {
+    const DATA: &[u8] = b"chr1\t10\t20\nchr1\t10\t20\nchr1\t30\t500\nbad\tline\nchr2\t50\t60\n";
+    let reader = |options: ReaderOptions<'static>| {
+        Reader::<Bed3>::builder()
+            .from_reader(std::io::Cursor::new(DATA))
+            .options(options.on_error(ErrorPolicy::Skip))
+            .build()
+            .unwrap()
+    };
+
+    let cases = [
+        (ReaderOptions::new(), 4),
+        (ReaderOptions::new().length_range(0, Some(100)), 3),
+        (
+            ReaderOptions::new()
+                .length_range(0, Some(100))
+                .dedup_consecutive(true),
+            2,
+        ),
+    ];
+    for (options, expected) in cases {
+        assert_eq!(reader(options.clone()).records().count(), expected);
+        assert_eq!(reader(options).count_records().unwrap(), expected);
+    }
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_reader_count_records_mmap() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("count.bed");
+    std::fs::write(&path, "chr1\t10\t20\n# skip\nchr2\t30\t40").unwrap();
+
+    let mut reader: Reader<Bed3> = Reader::from_mmap(&path).unwrap();
+    assert_eq!(reader.record_count_hint(), Some(2));
+    assert_eq!(reader.count_records().unwrap(), 2);
+    assert_eq!(reader.current_line(), 3);
+    assert_eq!(reader.record_count_hint(), Some(0));
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_reader_gtf_mmap_streams_lazily() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("many.gtf");
+    let mut gtf = String::from("##gtf-version 2.2\n");
+    for i in 0..2000u64 {
+        let start = i * 1000 + 1;
+        let attrs = format!("gene_id \"g{i}\"; transcript_id \"t{i}\";");
+        gtf.push_str(&format!(
+            "chr1\tsrc\ttranscript\t{start}\t{}\t.\t+\t.\t{attrs}\n",
+            start + 499
+        ));
+        gtf.push_str(&format!(
+            "chr1\tsrc\texon\t{start}\t{}\t.\t+\t.\t{attrs}\n",
+            start + 99
+        ));
+        gtf.push_str(&format!(
+            "chr1\tsrc\texon\t{}\t{}\t.\t+\t.\t{attrs}\n",
+            start + 400,
+            start + 499
+        ));
+    }
+    std::fs::write(&path, gtf).unwrap();
+
+    let open = |streaming: bool| {
+        let options = ReaderOptions::new().streaming(streaming);
+        Reader::<Gtf>::from_mmap_with_options(&path, options).unwrap()
+    };
+
+    let mut eager: Vec<_> = open(false).records().map(|r| r.unwrap()).collect();
+    let mut lazy: Vec<_> = open(true).records().map(|r| r.unwrap()).collect();
+    assert_eq!(eager.len(), 2000);
+    eager.sort();
+    lazy.sort();
+    assert_eq!(lazy, eager);
+
+    let (count, eager_peak) = tracking::peak_during(|| open(false).records().count());
+    assert_eq!(count, 2000);
+    let (count, lazy_peak) = tracking::peak_during(|| open(true).records().count());
+    assert_eq!(count, 2000);
+    assert!(
+        lazy_peak * 4 < eager_peak,
+        "lazy peak {lazy_peak} not well below eager peak {eager_peak}"
+    );
+}
+
+#[test]
+fn test_reader_rewind() {
+    let records = vec![
+        GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new()),
+        GenePred::from_coords(b"chr2".to_vec(), 30, 40, Extras::new()),
+    ];
+    let mut reader: Reader<Bed3> = Reader::from_records(records.clone()).unwrap();
+    assert_eq!(reader.records().next().unwrap().unwrap(), records[0]);
+    reader.rewind().unwrap();
+    let read: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(read, records);
+
+    let mut reader: Reader<Gtf> = Reader::from_path("tests/data/simple.gtf").unwrap();
+    let first: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    reader.rewind().unwrap();
+    let second: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(first, second);
+
+    let data = "chr1\t10\t20\n";
+    let mut reader: Reader<Bed3> =
+        Reader::from_reader(std::io::Cursor::new(data.as_bytes())).unwrap();
+    assert_eq!(reader.records().count(), 1);
+    assert!(reader.rewind().is_err());
+}
+
+#[test]
+fn test_reader_from_records_rewind_resets_cursor() {
+    let records = vec![
+        GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new()),
+        GenePred::from_coords(b"chr2".to_vec(), 30, 40, Extras::new()),
+        GenePred::from_coords(b"chr3".to_vec(), 50, 60, Extras::new()),
+    ];
+    let mut reader: Reader<Bed3> = Reader::from_records(records.clone()).unwrap();
+    assert_eq!(reader.record_count_hint(), Some(3));
+    assert_eq!(reader.records().next().unwrap().unwrap(), records[0]);
+    assert_eq!(reader.record_count_hint(), Some(2));
+    assert_eq!(reader.count_records().unwrap(), 2);
+    assert_eq!(reader.record_count_hint(), Some(0));
+
+    for _ in 0..2 {
+        reader.rewind().unwrap();
+        assert_eq!(reader.record_count_hint(), Some(3));
+        let read: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+        assert_eq!(read, records);
+    }
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_reader_rewind_mmap() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("rewind.bed");
+    std::fs::write(&path, "\u{FEFF}chr1\t10\t20\nchr2\t30\t40\n").unwrap();
+
+    let mut reader: Reader<Bed3> = Reader::from_mmap(&path).unwrap();
+    let first: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(reader.current_line(), 2);
+    reader.rewind().unwrap();
+    assert_eq!(reader.current_line(), 0);
+    let second: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(second.len(), 2);
+    assert_eq!(first, second);
+    assert_eq!(second[0].chrom(), b"chr1".as_ref());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_records_from_records() {
+    let records = vec![
+        GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new()),
+        GenePred::from_coords(b"chr2".to_vec(), 30, 40, Extras::new()),
+    ];
+    let reader: Reader<Bed3> = Reader::from_records(records.clone()).unwrap();
+    let mut read: Vec<_> = reader.par_records().unwrap().map(|r| r.unwrap()).collect();
+    read.sort_by_key(|gene| gene.start());
+    assert_eq!(read, records);
+}
+
+#[cfg(feature = "rayon")]
+fn assert_parallel_rejects(
+    configure: impl Fn(genepred::ReaderBuilder<Bed3>) -> genepred::ReaderBuilder<Bed3>,
+    option: &str,
+) {
+    let data: &'static [u8] = b"chr1\t10\t20\nchr1\t10\t20\n";
+    let build = || {
+        configure(Reader::builder().from_reader(std::io::Cursor::new(data)))
+            .build()
+            .unwrap()
+    };
+    for err in [
+        build().par_records().err().unwrap(),
+        build().par_chunks(1).err().unwrap(),
+    ] {
+        assert!(matches!(err, genepred::reader::ReaderError::Builder(_)));
+        assert!(err.to_string().contains(option));
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_records_rejects_error_policy() {
+    assert_parallel_rejects(|builder| builder.on_error(ErrorPolicy::Skip), "on_error");
+    assert_parallel_rejects(|builder| builder.on_error(ErrorPolicy::Collect), "on_error");
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_records_rejects_length_range() {
+    assert_parallel_rejects(|builder| builder.length_range(5, None), "length_range");
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_records_rejects_chrom_style() {
+    assert_parallel_rejects(
+        |builder| builder.enforce_chrom_style(ChromStyle::Ucsc),
+        "enforce_chrom_style",
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_records_rejects_dedup_consecutive() {
+    assert_parallel_rejects(
+        |builder| builder.dedup_consecutive(true),
+        "dedup_consecutive",
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_reader_gtf_parallel_matches_serial() {
+    let mut gtf = String::from("#!genome-build test\n");
+    for idx in 0..2000u64 {
+        let chrom = format!("chr{}", idx % 7 + 1);
+        let strand = if idx % 2 == 0 { '+' } else { '-' };
+        let start = idx * 1000 + 1;
+        let attrs = format!("gene_id \"g{}\"; transcript_id \"t{idx}\";", idx / 3);
+        gtf.push_str(&format!(
+            "{chrom}\tsrc\ttranscript\t{start}\t{}\t.\t{strand}\t.\t{attrs}\n",
+            start + 899
+        ));
+        for (offset, len) in [(0, 200), (400, 100), (700, 200)] {
+            gtf.push_str(&format!(
+                "{chrom}\tsrc\texon\t{}\t{}\t.\t{strand}\t.\t{attrs}\n",
+                start + offset,
+                start + offset + len - 1
+            ));
+        }
+        gtf.push_str(&format!(
+            "{chrom}\tsrc\tCDS\t{}\t{}\t.\t{strand}\t0\t{attrs}\n",
+            start + 100,
+            start + 199
+        ));
+    }
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("large.gtf");
+    std::fs::write(&path, gtf).unwrap();
+
+    let mut serial: Reader<Gtf> = Reader::from_path(&path).unwrap();
+    let mut expected: Vec<GenePred> = serial.records().map(|r| r.unwrap()).collect();
+    expected.sort();
+
+    let options = ReaderOptions::new().parallel(true);
+    let mut parallel: Reader<Gtf> = Reader::<Gtf>::from_gxf_with_options(&path, options).unwrap();
+    assert_eq!(parallel.metadata(), serial.metadata());
+    let records: Vec<GenePred> = parallel.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records.len(), 2000);
+    assert_eq!(records, expected);
+}
+
 #[cfg(feature = "rayon")]
 #[test]
 fn test_par_chunks_from_reader() {
@@ -334,6 +2117,45 @@ fn test_reader_bed3_zst_from_path() {
     assert_eq!(records[1].start(), 150);
 }
 
+#[cfg(feature = "gzip")]
+#[test]
+fn test_reader_from_reader_detects_gzip_magic() {
+    let data = std::fs::read("tests/data/bed3.bed.gz").unwrap();
+    let mut reader: Reader<Bed3> = Reader::from_reader(std::io::Cursor::new(data)).unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[1].end(), 200);
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn test_reader_from_reader_detects_zstd_magic() {
+    let data = std::fs::read("tests/data/bed3.bed").unwrap();
+    let compressed = zstd::stream::encode_all(data.as_slice(), 0).unwrap();
+    let mut reader: Reader<Bed3> = Reader::from_reader(std::io::Cursor::new(compressed)).unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].end(), 100);
+}
+
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "bz2"))]
+#[test]
+fn test_reader_from_reader_short_input_is_uncompressed() {
+    for data in [&b""[..], b"#", b"#\n"] {
+        let mut reader: Reader<Bed3> = Reader::from_reader(std::io::Cursor::new(data)).unwrap();
+        assert_eq!(reader.records().count(), 0);
+    }
+
+    // A lone gzip magic byte is too short to sniff and is parsed as text.
+    let mut reader: Reader<Bed3> = Reader::from_reader(std::io::Cursor::new(b"\x1f")).unwrap();
+    assert!(matches!(
+        reader.records().next(),
+        Some(Err(
+            genepred::reader::ReaderError::UnexpectedFieldCount { .. }
+        ))
+    ));
+}
+
 #[cfg(feature = "bz2")]
 #[test]
 fn test_reader_gtf_bz2_from_path() {
@@ -352,3 +2174,97 @@ fn test_reader_gtf_bz2_from_path() {
     assert_eq!(gene.name().unwrap(), b"GeneOne".as_ref());
     assert_eq!(gene.block_count().unwrap(), 2);
 }
+
+#[test]
+fn test_reader_dedup_consecutive() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("runs.bed");
+    std::fs::write(
+        &path,
+        "chr1\t10\t20\nchr1\t10\t20\nchr1\t10\t20\nchr1\t30\t40\nchr1\t10\t20\n",
+    )
+    .unwrap();
+
+    let collapse = |enabled: bool| -> Vec<(u64, u64)> {
+        Reader::<Bed3>::builder()
+            .from_path(&path)
+            .dedup_consecutive(enabled)
+            .build()
+            .unwrap()
+            .records()
+            .map(|record| record.map(|record| (record.start(), record.end())))
+            .collect::<Result<_, _>>()
+            .unwrap()
+    };
+
+    assert_eq!(collapse(true), vec![(10, 20), (30, 40), (10, 20)]);
+    assert_eq!(collapse(false).len(), 5);
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn test_reader_zstd_dictionary() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("bed3.bed.zst");
+    let data = std::fs::read("tests/data/bed3.bed").unwrap();
+    let dictionary = b"chr1\t100\t200\nchr1\t150\t250\nchr2\t".repeat(8);
+
+    let mut encoder =
+        ZstdEncoder::with_dictionary(File::create(&path).unwrap(), 3, &dictionary).unwrap();
+    encoder.write_all(&data).unwrap();
+    encoder.finish().unwrap();
+
+    let records: Vec<_> = Reader::<Bed3>::builder()
+        .from_path(&path)
+        .zstd_dictionary(dictionary.clone())
+        .build()
+        .unwrap()
+        .records()
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].end(), 100);
+    assert_eq!(records[1].start(), 150);
+
+    let streamed: Vec<_> = Reader::<Bed3>::builder()
+        .from_reader(File::open(&path).unwrap())
+        .zstd_dictionary(dictionary.clone())
+        .build()
+        .unwrap()
+        .records()
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(streamed, records);
+
+    let err = Reader::<Bed3>::builder()
+        .from_path("tests/data/bed3.bed")
+        .zstd_dictionary(dictionary)
+        .build()
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("dictionary"));
+}
+
+#[test]
+fn test_reader_dedup_consecutive_after_rewind() {
+    const DATA: &[u8] = b"chr1\t10\t20\nchr1\t30\t40\nchr1\t10\t20\n";
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ends_like_start.bed");
+    std::fs::write(&path, DATA).unwrap();
+
+    let mapped = Reader::<Bed3>::builder()
+        .from_bytes(DATA)
+        .dedup_consecutive(true)
+        .build()
+        .unwrap();
+    let reopened = Reader::<Bed3>::builder()
+        .from_path(&path)
+        .dedup_consecutive(true)
+        .build()
+        .unwrap();
+    for mut reader in [mapped, reopened] {
+        assert_eq!(reader.records().count(), 3);
+        reader.rewind().unwrap();
+        assert_eq!(reader.records().count(), 3);
+    }
+}
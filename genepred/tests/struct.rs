@@ -1,7 +1,11 @@
 use std::collections::HashMap;
 
-use genepred::bed::{Bed12, Bed3, Bed4, Bed5, Bed6, Bed8, Bed9};
-use genepred::{ExtraValue, Extras, GenePred, Gff, Gtf, Strand};
+use genepred::bed::{Bed12, Bed3, Bed4, Bed5, Bed6, Bed8, Bed9, Rgb};
+use genepred::sort::{natural_chrom_cmp, natural_cmp};
+use genepred::{
+    dedup_by_coords, promoter_overlaps, ChromStyle, ExtraValue, Extras, ExtrasExt, GenePred, Gff,
+    Gtf, IntervalIndex, Strand, StrandParseError, WriterError,
+};
 
 #[test]
 fn test_genepred_from_coords() {
@@ -320,6 +324,22 @@ fn test_genepred_strand_aware_utrs() {
     assert!(gene.three_prime_utr().is_empty());
 }
 
+#[test]
+fn test_genepred_utrs_empty_without_coding_region() {
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 10, 120, Extras::new());
+    gene.set_strand(Some(Strand::Forward));
+    gene.set_block_count(Some(2));
+    gene.set_block_starts(Some(vec![10, 100]));
+    gene.set_block_ends(Some(vec![30, 120]));
+    assert!(gene.five_prime_utr().is_empty());
+    assert!(gene.three_prime_utr().is_empty());
+
+    gene.set_thick_start(Some(20));
+    gene.set_thick_end(Some(20));
+    assert!(gene.five_prime_utr().is_empty());
+    assert!(gene.three_prime_utr().is_empty());
+}
+
 #[test]
 fn test_genepred_unnest_extras() {
     let mut gene = GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new());
@@ -707,3 +727,890 @@ fn test_genepred_to_gxf_with_additional_fields_panics_when_missing_numeric_extra
     let gene = GenePred::from_coords(b"chr1".to_vec(), 10, 20, extras);
     let _ = gene.to_gxf_with_additional_fields::<Gtf>(2, None);
 }
+
+#[test]
+fn test_genepred_promoter_overlaps_is_strand_aware() {
+    let mut forward = GenePred::from_coords(b"chr1".to_vec(), 1000, 2000, Extras::new());
+    forward.set_strand(Some(Strand::Forward));
+    let mut reverse = GenePred::from_coords(b"chr1".to_vec(), 5000, 6000, Extras::new());
+    reverse.set_strand(Some(Strand::Reverse));
+
+    assert_eq!(forward.promoter(500, 100), (500, 1100));
+    assert_eq!(reverse.promoter(500, 100), (5900, 6500));
+
+    let peaks = vec![
+        // upstream of the forward TSS (genomic left side)
+        GenePred::from_coords(b"chr1".to_vec(), 700, 800, Extras::new()),
+        // upstream of the reverse TSS (genomic right side)
+        GenePred::from_coords(b"chr1".to_vec(), 6200, 6300, Extras::new()),
+        // downstream of the reverse TSS, outside its window
+        GenePred::from_coords(b"chr1".to_vec(), 4500, 4600, Extras::new()),
+        // right coordinates, wrong chromosome
+        GenePred::from_coords(b"chr2".to_vec(), 700, 800, Extras::new()),
+    ];
+
+    let hits = promoter_overlaps(&[forward, reverse], &peaks, 500, 100);
+    assert_eq!(hits, vec![(0, vec![0]), (1, vec![1])]);
+}
+
+#[test]
+fn test_genepred_coverage_profile() {
+    let gene = GenePred::from_coords(b"chr1".to_vec(), 100, 110, Extras::new());
+    let reads = [(98, 105), (103, 108)];
+    assert_eq!(
+        gene.coverage_profile(&reads),
+        vec![1, 1, 1, 2, 2, 1, 1, 1, 0, 0]
+    );
+    assert_eq!(gene.coverage_profile(&[]), vec![0; 10]);
+
+    let mut spliced = GenePred::from_coords(b"chr1".to_vec(), 100, 120, Extras::new());
+    spliced.set_block_count(Some(2));
+    spliced.set_block_starts(Some(vec![100, 115]));
+    spliced.set_block_ends(Some(vec![103, 120]));
+    assert_eq!(
+        spliced.exonic_coverage_profile(&[(101, 117)]),
+        vec![0, 1, 1, 1, 1, 0, 0, 0]
+    );
+}
+
+#[test]
+fn test_strand_from_str() {
+    assert_eq!("+".parse::<Strand>(), Ok(Strand::Forward));
+    assert_eq!("-".parse::<Strand>(), Ok(Strand::Reverse));
+    assert_eq!(".".parse::<Strand>(), Ok(Strand::Unknown));
+    assert_eq!("?".parse::<Strand>(), Ok(Strand::Unknown));
+
+    let err: StrandParseError = "\u{2212}".parse::<Strand>().unwrap_err();
+    assert_eq!(err.value(), "\u{2212}");
+    assert!(err.to_string().contains("expected '+', '-', '.', or '?'"));
+}
+
+#[test]
+fn test_strand_helpers() {
+    assert_eq!(Strand::Forward.opposite(), Strand::Reverse);
+    assert_eq!(Strand::Reverse.opposite(), Strand::Forward);
+    assert_eq!(Strand::Unknown.opposite(), Strand::Unknown);
+
+    assert_eq!(Strand::Forward.as_char(), '+');
+    assert_eq!(Strand::Reverse.as_char(), '-');
+    assert_eq!(Strand::Unknown.as_char(), '.');
+
+    assert!(Strand::Forward.is_forward());
+    assert!(!Strand::Forward.is_reverse());
+    assert!(Strand::Reverse.is_reverse());
+    assert!(!Strand::Unknown.is_forward() && !Strand::Unknown.is_reverse());
+}
+
+#[test]
+fn test_genepred_flip_strand() {
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new());
+    gene.flip_strand();
+    assert_eq!(gene.strand(), None);
+
+    gene.set_strand(Some(Strand::Forward));
+    gene.flip_strand();
+    assert_eq!(gene.strand(), Some(Strand::Reverse));
+    assert_eq!((gene.start(), gene.end()), (10, 20));
+    gene.flip_strand();
+    assert_eq!(gene.strand(), Some(Strand::Forward));
+}
+
+#[test]
+fn test_extras_typed_accessors() {
+    let mut extras = Extras::new();
+    extras.insert(b"exon_number".to_vec(), ExtraValue::Scalar(b"3".to_vec()));
+    extras.insert(b"tpm".to_vec(), ExtraValue::Scalar(b"1.5".to_vec()));
+    extras.insert(
+        b"gene_name".to_vec(),
+        ExtraValue::Scalar(b"DDX11L1".to_vec()),
+    );
+    extras.insert(
+        b"tag".to_vec(),
+        ExtraValue::Array(vec![b"basic".to_vec(), b"CCDS".to_vec()]),
+    );
+    extras.insert(b"raw".to_vec(), ExtraValue::Scalar(vec![0xff, 0xfe]));
+
+    assert_eq!(extras.get_i64(b"exon_number"), Some(3));
+    assert_eq!(extras.get_f64(b"tpm"), Some(1.5));
+    assert_eq!(extras.get_f64(b"exon_number"), Some(3.0));
+    assert_eq!(extras.get_i64(b"gene_name"), None);
+    assert_eq!(extras.get_i64(b"missing"), None);
+    assert_eq!(extras.get_str(b"gene_name"), Some("DDX11L1"));
+    assert_eq!(extras.get_str(b"raw"), None);
+    assert_eq!(extras.get_all_str(b"tag"), Some(vec!["basic", "CCDS"]));
+    assert_eq!(extras.get_all_str(b"gene_name"), Some(vec!["DDX11L1"]));
+    assert_eq!(extras.get_all_str(b"missing"), None);
+}
+
+#[test]
+fn test_genepred_merge_exons() {
+    let blocks = |starts: Vec<u64>, ends: Vec<u64>| {
+        let mut gene = GenePred::from_coords(b"chr1".to_vec(), 0, 100, Extras::new());
+        gene.set_block_count(Some(starts.len() as u32));
+        gene.set_block_starts(Some(starts));
+        gene.set_block_ends(Some(ends));
+        gene
+    };
+
+    let mut overlapping = blocks(vec![50, 0, 20], vec![100, 30, 40]);
+    assert_eq!(overlapping.merge_exons(), 1);
+    assert_eq!(overlapping.exons(), vec![(0, 40), (50, 100)]);
+    assert_eq!(overlapping.block_count(), Some(2));
+
+    let mut touching = blocks(vec![0, 30, 60], vec![30, 50, 100]);
+    assert_eq!(touching.merge_exons(), 1);
+    assert_eq!(touching.exons(), vec![(0, 50), (60, 100)]);
+
+    let mut disjoint = blocks(vec![0, 60], vec![30, 100]);
+    let before = disjoint.clone();
+    assert_eq!(disjoint.merge_exons(), 0);
+    assert_eq!(disjoint, before);
+
+    let mut single = GenePred::from_coords(b"chr1".to_vec(), 0, 100, Extras::new());
+    assert_eq!(single.merge_exons(), 0);
+    assert_eq!(single.block_count(), None);
+}
+
+#[test]
+fn test_genepred_splice_sites() {
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new());
+    gene.set_block_count(Some(3));
+    gene.set_block_starts(Some(vec![100, 150, 250]));
+    gene.set_block_ends(Some(vec![120, 200, 300]));
+
+    gene.set_strand(Some(Strand::Forward));
+    assert_eq!(
+        gene.splice_sites(),
+        vec![(120, 122), (148, 150), (200, 202), (248, 250)]
+    );
+
+    gene.set_strand(Some(Strand::Reverse));
+    assert_eq!(
+        gene.splice_sites(),
+        vec![(148, 150), (120, 122), (248, 250), (200, 202)]
+    );
+
+    let single = GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new());
+    assert!(single.splice_sites().is_empty());
+}
+
+#[test]
+fn test_genepred_ord_sorts_by_coordinates() {
+    let named = |chrom: &[u8], start, end, name: &[u8]| {
+        let mut gene = GenePred::from_coords(chrom.to_vec(), start, end, Extras::new());
+        gene.set_name(Some(name.to_vec()));
+        gene
+    };
+
+    let mut records = [
+        named(b"chr2", 10, 20, b"a"),
+        named(b"chr10", 5, 15, b"a"),
+        named(b"chr1", 10, 30, b"a"),
+        named(b"chr1", 10, 20, b"b"),
+        named(b"chr1", 10, 20, b"a"),
+        named(b"chr1", 5, 50, b"a"),
+    ];
+    records.sort();
+
+    let keys: Vec<_> = records
+        .iter()
+        .map(|gene| (gene.chrom(), gene.start(), gene.end(), gene.name().unwrap()))
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            (b"chr1".as_ref(), 5, 50, b"a".as_ref()),
+            (b"chr1".as_ref(), 10, 20, b"a".as_ref()),
+            (b"chr1".as_ref(), 10, 20, b"b".as_ref()),
+            (b"chr1".as_ref(), 10, 30, b"a".as_ref()),
+            (b"chr10".as_ref(), 5, 15, b"a".as_ref()),
+            (b"chr2".as_ref(), 10, 20, b"a".as_ref()),
+        ]
+    );
+
+    let gene = named(b"chr1", 10, 20, b"a");
+    assert_eq!(gene.cmp(&gene.clone()), std::cmp::Ordering::Equal);
+}
+
+#[test]
+fn test_natural_chrom_cmp() {
+    let mut records: Vec<GenePred> = [
+        b"chrX".as_ref(),
+        b"chr10",
+        b"chr2",
+        b"chr1",
+        b"chrY",
+        b"chr1_alt",
+    ]
+    .iter()
+    .map(|chrom| GenePred::from_coords(chrom.to_vec(), 0, 10, Extras::new()))
+    .collect();
+    records.sort_by(natural_chrom_cmp);
+
+    let chroms: Vec<_> = records.iter().map(|gene| gene.chrom()).collect();
+    assert_eq!(
+        chroms,
+        vec![
+            b"chr1".as_ref(),
+            b"chr1_alt",
+            b"chr2",
+            b"chr10",
+            b"chrX",
+            b"chrY",
+        ]
+    );
+
+    let first = GenePred::from_coords(b"chr2".to_vec(), 50, 60, Extras::new());
+    let second = GenePred::from_coords(b"chr2".to_vec(), 100, 110, Extras::new());
+    assert_eq!(natural_chrom_cmp(&first, &second), std::cmp::Ordering::Less);
+    assert_eq!(natural_cmp(b"chr01", b"chr1"), std::cmp::Ordering::Less);
+    assert_eq!(natural_cmp(b"chr9", b"chr10"), std::cmp::Ordering::Less);
+}
+
+#[test]
+fn test_genepred_to_bed_structs() {
+    let mut extras = Extras::new();
+    extras.insert(b"source".to_vec(), ExtraValue::Scalar(b"test".to_vec()));
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, extras.clone());
+
+    assert!(matches!(
+        gene.to_bed6(),
+        Err(WriterError::MissingField("name"))
+    ));
+
+    gene.set_name(Some(b"tx1".to_vec()));
+    gene.set_block_count(Some(2));
+    gene.set_block_starts(Some(vec![100, 150]));
+    gene.set_block_ends(Some(vec![120, 200]));
+
+    assert_eq!(
+        gene.to_bed3().unwrap(),
+        Bed3 {
+            chrom: b"chr1".to_vec(),
+            start: 100,
+            end: 200,
+            extras: extras.clone(),
+        }
+    );
+    assert_eq!(
+        gene.to_bed6().unwrap(),
+        Bed6 {
+            chrom: b"chr1".to_vec(),
+            start: 100,
+            end: 200,
+            name: b"tx1".to_vec(),
+            score: 0,
+            strand: Strand::Unknown,
+            extras: extras.clone(),
+        }
+    );
+    assert_eq!(
+        gene.to_bed12().unwrap(),
+        Bed12 {
+            chrom: b"chr1".to_vec(),
+            start: 100,
+            end: 200,
+            name: b"tx1".to_vec(),
+            score: 0,
+            strand: Strand::Unknown,
+            thick_start: 100,
+            thick_end: 200,
+            item_rgb: Rgb(0, 0, 0),
+            block_count: 2,
+            block_sizes: vec![20, 50],
+            block_starts: vec![0, 50],
+            extras: extras.clone(),
+        }
+    );
+
+    gene.set_strand(Some(Strand::Reverse));
+    gene.set_score(Some(500));
+    gene.set_thick_start(Some(110));
+    gene.set_thick_end(Some(160));
+    let bed = gene.to_bed12().unwrap();
+    assert_eq!(GenePred::from(bed.clone()).to_bed12().unwrap(), bed);
+    assert_eq!((bed.thick_start, bed.thick_end), (110, 160));
+    assert_eq!(bed.strand, Strand::Reverse);
+    assert_eq!(bed.score, 500);
+}
+
+#[test]
+fn test_genepred_intersect_and_subtract() {
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new());
+    gene.set_block_count(Some(3));
+    gene.set_block_starts(Some(vec![100, 150, 250]));
+    gene.set_block_ends(Some(vec![120, 200, 300]));
+
+    // Clips the second exon.
+    assert_eq!(gene.intersect(170, 220), vec![(170, 200)]);
+    assert_eq!(
+        gene.subtract(170, 220),
+        vec![(100, 120), (150, 170), (250, 300)]
+    );
+
+    // Falls entirely within the first intron.
+    assert!(gene.intersect(125, 145).is_empty());
+    assert_eq!(gene.subtract(125, 145), gene.exons());
+
+    // Spans the whole feature.
+    assert_eq!(gene.intersect(0, 1000), gene.exons());
+    assert!(gene.subtract(0, 1000).is_empty());
+
+    // Splits an exon in two.
+    assert_eq!(
+        gene.subtract(160, 170),
+        vec![(100, 120), (150, 160), (170, 200), (250, 300)]
+    );
+}
+
+#[test]
+fn test_genepred_extend() {
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 1000, 2000, Extras::new());
+    gene.set_strand(Some(Strand::Forward));
+    gene.set_block_count(Some(2));
+    gene.set_block_starts(Some(vec![1000, 1800]));
+    gene.set_block_ends(Some(vec![1200, 2000]));
+    gene.extend(100, 50, None);
+    assert_eq!((gene.start(), gene.end()), (900, 2050));
+    assert_eq!(gene.block_starts(), Some(&[1000, 1800][..]));
+    assert_eq!(gene.block_ends(), Some(&[1200, 2000][..]));
+
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 1000, 2000, Extras::new());
+    gene.set_strand(Some(Strand::Reverse));
+    gene.extend(100, 50, None);
+    assert_eq!((gene.start(), gene.end()), (950, 2100));
+
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 30, 2000, Extras::new());
+    gene.set_strand(Some(Strand::Forward));
+    gene.extend(100, 500, Some(2200));
+    assert_eq!((gene.start(), gene.end()), (0, 2200));
+}
+
+#[test]
+fn test_genepred_exons_iter_matches_exons() {
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new());
+    assert_eq!(gene.exons_iter().collect::<Vec<_>>(), vec![(100, 300)]);
+    assert_eq!(gene.exons_iter().collect::<Vec<_>>(), gene.exons());
+
+    gene.set_block_count(Some(3));
+    gene.set_block_starts(Some(vec![100, 150, 250]));
+    gene.set_block_ends(Some(vec![120, 150, 300]));
+    assert_eq!(gene.exons_iter().collect::<Vec<_>>(), gene.exons());
+    assert_eq!(gene.exons(), vec![(100, 120), (250, 300)]);
+    assert_eq!(gene.exonic_length(), 70);
+    assert_eq!(gene.intron_count(), 1);
+
+    gene.set_block_ends(Some(vec![100, 150, 250]));
+    assert_eq!(gene.exons_iter().collect::<Vec<_>>(), vec![(100, 300)]);
+    assert_eq!(gene.exons_iter().collect::<Vec<_>>(), gene.exons());
+}
+
+#[test]
+fn test_genepred_distance_and_adjacency() {
+    let a = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    let overlapping = GenePred::from_coords(b"chr1".to_vec(), 150, 250, Extras::new());
+    let adjacent = GenePred::from_coords(b"chr1".to_vec(), 200, 300, Extras::new());
+    let far = GenePred::from_coords(b"chr1".to_vec(), 1200, 1300, Extras::new());
+    let other_chrom = GenePred::from_coords(b"chr2".to_vec(), 100, 200, Extras::new());
+
+    assert_eq!(a.distance_to(&overlapping), Some(0));
+    assert_eq!(a.distance_to(&adjacent), Some(0));
+    assert_eq!(a.distance_to(&far), Some(1000));
+    assert_eq!(far.distance_to(&a), Some(1000));
+    assert_eq!(a.distance_to(&other_chrom), None);
+
+    assert!(a.is_upstream_of(&adjacent));
+    assert!(a.is_upstream_of(&far));
+    assert!(!a.is_upstream_of(&overlapping));
+    assert!(!a.is_downstream_of(&overlapping));
+    assert!(far.is_downstream_of(&a));
+    assert!(!a.is_upstream_of(&other_chrom));
+
+    let mut reverse = a.clone();
+    reverse.set_strand(Some(Strand::Reverse));
+    assert!(reverse.is_downstream_of(&far));
+    assert!(!reverse.is_upstream_of(&far));
+    let mut far_reverse = far.clone();
+    far_reverse.set_strand(Some(Strand::Reverse));
+    assert!(far_reverse.is_upstream_of(&a));
+}
+
+#[test]
+fn test_genepred_with_builders() {
+    let gene = GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new())
+        .with_name(b"tx1".to_vec())
+        .with_strand(Strand::Reverse)
+        .with_score(900)
+        .with_thick(110, 280)
+        .with_blocks(vec![100, 150, 250], vec![120, 200, 300]);
+
+    let mut expected = GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new());
+    expected.set_name(Some(b"tx1".to_vec()));
+    expected.set_strand(Some(Strand::Reverse));
+    expected.set_score(Some(900));
+    expected.set_thick_start(Some(110));
+    expected.set_thick_end(Some(280));
+    expected.set_block_count(Some(3));
+    expected.set_block_starts(Some(vec![100, 150, 250]));
+    expected.set_block_ends(Some(vec![120, 200, 300]));
+
+    assert_eq!(gene, expected);
+    assert_eq!(
+        gene.coding_exons(),
+        vec![(110, 120), (150, 200), (250, 280)]
+    );
+    assert_eq!(gene.exon_count(), 3);
+}
+
+#[test]
+#[should_panic(expected = "differ in length")]
+fn test_genepred_with_blocks_rejects_mismatched_lengths() {
+    let _ = GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new())
+        .with_blocks(vec![100, 150], vec![120]);
+}
+
+#[test]
+fn test_genepred_try_with_blocks() {
+    let gene = GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new());
+    let err = gene
+        .clone()
+        .try_with_blocks(vec![100, 150], vec![120])
+        .unwrap_err();
+    assert!(matches!(err, WriterError::Invalid(_)));
+    assert_eq!(
+        err.to_string(),
+        "block starts (2) and ends (1) differ in length"
+    );
+
+    let gene = gene
+        .try_with_blocks(vec![100, 150], vec![120, 300])
+        .unwrap();
+    assert_eq!(gene.block_count(), Some(2));
+    assert_eq!(gene.exons(), vec![(100, 120), (150, 300)]);
+}
+
+#[test]
+fn test_extras_case_insensitive_lookup() {
+    let mut extras = Extras::new();
+    extras.insert(b"Name".to_vec(), ExtraValue::Scalar(b"BRCA1".to_vec()));
+    extras.insert(
+        b"ID".to_vec(),
+        ExtraValue::Array(vec![b"tx1".to_vec(), b"tx2".to_vec()]),
+    );
+    extras.insert(b"gene_id".to_vec(), ExtraValue::Scalar(b"lower".to_vec()));
+    extras.insert(b"Gene_ID".to_vec(), ExtraValue::Scalar(b"mixed".to_vec()));
+
+    assert_eq!(extras.get_first_ci(b"name"), Some(&b"BRCA1"[..]));
+    assert_eq!(extras.get_first_ci(b"NAME"), Some(&b"BRCA1"[..]));
+    assert_eq!(extras.get_first_ci(b"Id"), Some(&b"tx1"[..]));
+    assert_eq!(
+        extras.get_ci(b"id"),
+        Some(&ExtraValue::Array(vec![b"tx1".to_vec(), b"tx2".to_vec()]))
+    );
+    assert_eq!(extras.get_first_ci(b"gene_id"), Some(&b"lower"[..]));
+    assert_eq!(extras.get_first_ci(b"Gene_ID"), Some(&b"mixed"[..]));
+    assert_eq!(extras.get_first_ci(b"GENE_ID"), Some(&b"mixed"[..]));
+    assert_eq!(extras.get_ci(b"missing"), None);
+}
+
+#[test]
+fn test_genepred_normalize_chrom() {
+    let normalized = |chrom: &[u8], style| {
+        let mut gene = GenePred::from_coords(chrom.to_vec(), 0, 10, Extras::new());
+        gene.normalize_chrom(style);
+        gene.chrom().to_vec()
+    };
+
+    assert_eq!(normalized(b"1", ChromStyle::Ucsc), b"chr1");
+    assert_eq!(normalized(b"chrMT", ChromStyle::Ucsc), b"chrM");
+    assert_eq!(normalized(b"MT", ChromStyle::Ucsc), b"chrM");
+    assert_eq!(normalized(b"chrX", ChromStyle::Ucsc), b"chrX");
+
+    assert_eq!(normalized(b"chr1", ChromStyle::Ensembl), b"1");
+    assert_eq!(normalized(b"chrM", ChromStyle::Ensembl), b"MT");
+    assert_eq!(normalized(b"M", ChromStyle::Ensembl), b"MT");
+    assert_eq!(normalized(b"X", ChromStyle::Ensembl), b"X");
+
+    assert_eq!(normalized(b"chr1", ChromStyle::Consistent), b"chr1");
+    assert_eq!(normalized(b"1", ChromStyle::Consistent), b"1");
+}
+
+#[test]
+fn test_interval_index_query() {
+    let gene = |chrom: &[u8], start, end, name: &str| {
+        GenePred::from_coords(chrom.to_vec(), start, end, Extras::new()).with_name(name)
+    };
+    let index = IntervalIndex::new(vec![
+        gene(b"chr1", 100, 1000, "outer"),
+        gene(b"chr1", 200, 300, "nested"),
+        gene(b"chr1", 1000, 1100, "adjacent"),
+        gene(b"chr1", 5000, 5100, "far"),
+        gene(b"chr2", 200, 300, "other"),
+    ]);
+    let hits = |chrom: &[u8], start, end| {
+        index
+            .query(chrom, start, end)
+            .map(|record| record.name().unwrap().to_vec())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(index.len(), 5);
+    assert_eq!(
+        hits(b"chr1", 250, 260),
+        vec![b"outer".to_vec(), b"nested".to_vec()]
+    );
+    assert_eq!(hits(b"chr1", 500, 600), vec![b"outer".to_vec()]);
+    assert_eq!(
+        hits(b"chr1", 999, 1001),
+        vec![b"outer".to_vec(), b"adjacent".to_vec()]
+    );
+    assert_eq!(hits(b"chr1", 1000, 1050), vec![b"adjacent".to_vec()]);
+    assert!(hits(b"chr1", 1100, 5000).is_empty());
+    assert!(hits(b"chr1", 0, 100).is_empty());
+    assert_eq!(hits(b"chr2", 0, 1000), vec![b"other".to_vec()]);
+    assert!(hits(b"chr3", 0, 1000).is_empty());
+}
+
+#[test]
+fn test_genepred_dedup_by_coords() {
+    let gene = |name: &str, strand| {
+        GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new())
+            .with_name(name)
+            .with_strand(strand)
+    };
+    let first = gene("first", Strand::Forward);
+    let renamed = gene("second", Strand::Forward);
+    let reverse = gene("third", Strand::Reverse);
+
+    assert_eq!(first.coordinate_key(), renamed.coordinate_key());
+    assert_eq!(first.coord_key(), renamed.coord_key());
+    assert_ne!(first.coord_key(), reverse.coord_key());
+    let loci: std::collections::HashSet<_> = [&first, &renamed, &reverse]
+        .into_iter()
+        .map(GenePred::coord_key)
+        .collect();
+    assert_eq!(loci.len(), 2);
+
+    let unique = dedup_by_coords(vec![first, renamed, reverse]);
+    assert_eq!(unique.len(), 2);
+    assert_eq!(unique[0].name(), Some(b"first".as_ref()));
+    assert_eq!(unique[1].name(), Some(b"third".as_ref()));
+}
+
+#[test]
+fn test_genepred_genomic_transcript_mapping() {
+    let gene = |strand| {
+        GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new())
+            .with_strand(strand)
+            .with_blocks(vec![100, 250], vec![150, 300])
+    };
+
+    let forward = gene(Strand::Forward);
+    assert_eq!(forward.genomic_to_transcript(100), Some(0));
+    assert_eq!(forward.genomic_to_transcript(149), Some(49));
+    assert_eq!(forward.genomic_to_transcript(250), Some(50));
+    assert_eq!(forward.genomic_to_transcript(299), Some(99));
+    assert_eq!(forward.genomic_to_transcript(200), None);
+    assert_eq!(forward.genomic_to_transcript(300), None);
+    assert_eq!(forward.genomic_to_transcript(99), None);
+    assert_eq!(forward.transcript_to_genomic(50), Some(250));
+    assert_eq!(forward.transcript_to_genomic(100), None);
+
+    let reverse = gene(Strand::Reverse);
+    assert_eq!(reverse.genomic_to_transcript(299), Some(0));
+    assert_eq!(reverse.genomic_to_transcript(250), Some(49));
+    assert_eq!(reverse.genomic_to_transcript(149), Some(50));
+    assert_eq!(reverse.genomic_to_transcript(100), Some(99));
+    assert_eq!(reverse.genomic_to_transcript(175), None);
+    assert_eq!(reverse.transcript_to_genomic(49), Some(250));
+    assert_eq!(reverse.transcript_to_genomic(50), Some(149));
+
+    for tpos in 0..100 {
+        for record in [&forward, &reverse] {
+            let pos = record.transcript_to_genomic(tpos).unwrap();
+            assert_eq!(record.genomic_to_transcript(pos), Some(tpos));
+        }
+    }
+}
+
+#[test]
+fn test_genepred_exonic_overlap_and_jaccard() {
+    let blocked = |start, end, blocks: &[(u64, u64)]| {
+        let mut gene = GenePred::from_coords(b"chr1".to_vec(), start, end, Extras::new());
+        gene.set_block_count(Some(blocks.len() as u32));
+        gene.set_block_starts(Some(blocks.iter().map(|(s, _)| *s).collect()));
+        gene.set_block_ends(Some(blocks.iter().map(|(_, e)| *e).collect()));
+        gene
+    };
+    let outer = blocked(100, 400, &[(100, 200), (300, 400)]);
+
+    // Fully nested: every base of the inner exons lies in the outer ones.
+    let nested = blocked(120, 380, &[(120, 180), (320, 380)]);
+    assert_eq!(outer.exonic_overlap_bases(&nested), 120);
+    assert_eq!(nested.exonic_overlap_bases(&outer), 120);
+    assert!((outer.exonic_jaccard(&nested) - 120.0 / 200.0).abs() < 1e-9);
+    assert!((outer.exonic_jaccard(&outer) - 1.0).abs() < 1e-9);
+
+    // Partial: one exon straddles an intron boundary, one sits in the intron.
+    let partial = blocked(150, 350, &[(150, 250), (260, 350)]);
+    assert_eq!(outer.exonic_overlap_bases(&partial), 50 + 50);
+    assert!((outer.exonic_jaccard(&partial) - 100.0 / 290.0).abs() < 1e-9);
+
+    // Disjoint: exons fall entirely within the other record's intron.
+    let intronic = blocked(210, 290, &[(210, 240), (250, 290)]);
+    assert_eq!(outer.exonic_overlap_bases(&intronic), 0);
+    assert_eq!(outer.exonic_jaccard(&intronic), 0.0);
+
+    let mut elsewhere = nested.clone();
+    elsewhere.set_chrom(b"chr2".to_vec());
+    assert_eq!(outer.exonic_overlap_bases(&elsewhere), 0);
+    assert_eq!(outer.exonic_jaccard(&elsewhere), 0.0);
+}
+
+#[test]
+fn test_genepred_cds_and_protein_mapping() {
+    // CDS 140..150 + 250..262: 22 bases, codon 3 (bases 9-11) spans the intron.
+    let gene = |strand| {
+        GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new())
+            .with_strand(strand)
+            .with_blocks(vec![100, 250], vec![150, 300])
+            .with_thick(140, 262)
+    };
+
+    let forward = gene(Strand::Forward);
+    let codon = |record: &GenePred, pos| {
+        record
+            .genomic_to_cds(pos)
+            .map(|cds| record.cds_to_protein(cds))
+    };
+    assert_eq!(codon(&forward, 140), Some((0, 0)));
+    assert_eq!(codon(&forward, 148), Some((2, 2)));
+    assert_eq!(codon(&forward, 149), Some((3, 0)));
+    assert_eq!(codon(&forward, 250), Some((3, 1)));
+    assert_eq!(codon(&forward, 251), Some((3, 2)));
+    assert_eq!(codon(&forward, 252), Some((4, 0)));
+    assert_eq!(codon(&forward, 261), Some((7, 0)));
+    assert_eq!(codon(&forward, 262), None);
+    assert_eq!(codon(&forward, 139), None);
+    assert_eq!(codon(&forward, 200), None);
+
+    let reverse = gene(Strand::Reverse);
+    assert_eq!(codon(&reverse, 261), Some((0, 0)));
+    assert_eq!(codon(&reverse, 252), Some((3, 0)));
+    assert_eq!(codon(&reverse, 250), Some((3, 2)));
+    assert_eq!(codon(&reverse, 149), Some((4, 0)));
+    assert_eq!(codon(&reverse, 140), Some((7, 0)));
+    assert_eq!(codon(&reverse, 262), None);
+
+    let noncoding = GenePred::from_coords(b"chr1".to_vec(), 100, 300, Extras::new());
+    assert_eq!(noncoding.genomic_to_cds(150), None);
+}
+
+#[test]
+fn test_genepred_structurally_eq() {
+    let tags = |values: &[&str]| {
+        let mut extras = Extras::new();
+        extras.insert(
+            b"tag".to_vec(),
+            ExtraValue::Array(values.iter().map(|v| v.as_bytes().to_vec()).collect()),
+        );
+        extras.insert(b"gene_id".to_vec(), ExtraValue::Scalar(b"g1".to_vec()));
+        extras
+    };
+    let a = GenePred::from_coords(b"chr1".to_vec(), 100, 200, tags(&["basic", "CCDS"]))
+        .with_name("tx1")
+        .with_strand(Strand::Forward);
+    let b = GenePred::from_coords(b"chr1".to_vec(), 100, 200, tags(&["CCDS", "basic"]))
+        .with_name("tx1")
+        .with_strand(Strand::Forward);
+    assert_ne!(a, b);
+    assert!(a.structurally_eq(&b));
+
+    let mut blocked = b.clone();
+    blocked.set_block_count(Some(1));
+    blocked.set_block_starts(Some(vec![100]));
+    blocked.set_block_ends(Some(vec![200]));
+    blocked.set_score(Some(7));
+    assert!(a.structurally_eq(&blocked));
+
+    let mut scalar = a.clone();
+    scalar
+        .extras_mut()
+        .insert(b"gene_id".to_vec(), ExtraValue::Array(vec![b"g1".to_vec()]));
+    assert!(a.structurally_eq(&scalar));
+
+    let mut retagged = b.clone();
+    retagged
+        .extras_mut()
+        .insert(b"tag".to_vec(), ExtraValue::Array(vec![b"basic".to_vec()]));
+    assert!(!a.structurally_eq(&retagged));
+    assert!(!a.structurally_eq(&b.clone().with_strand(Strand::Reverse)));
+}
+
+#[test]
+fn test_genepred_windows() {
+    let gene = GenePred::from_coords(b"chr1".to_vec(), 100, 130, Extras::new());
+
+    let exact: Vec<_> = gene.windows(10, 10).collect();
+    assert_eq!(exact, vec![(100, 110), (110, 120), (120, 130)]);
+
+    let partial: Vec<_> = gene.windows(12, 12).collect();
+    assert_eq!(partial, vec![(100, 112), (112, 124), (124, 130)]);
+
+    let overlapping: Vec<_> = gene.windows(10, 5).collect();
+    assert_eq!(
+        overlapping,
+        vec![(100, 110), (105, 115), (110, 120), (115, 125), (120, 130)]
+    );
+
+    let sparse: Vec<_> = gene.windows(5, 20).collect();
+    assert_eq!(sparse, vec![(100, 105), (120, 125)]);
+
+    assert_eq!(gene.windows(0, 10).count(), 0);
+    assert_eq!(gene.windows(10, 0).count(), 0);
+}
+
+#[test]
+fn test_genepred_exon_windows() {
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    gene.set_block_count(Some(3));
+    gene.set_block_starts(Some(vec![100, 150, 190]));
+    gene.set_block_ends(Some(vec![110, 160, 200]));
+
+    let tiles: Vec<_> = gene.exon_windows(12, 12).collect();
+    assert_eq!(
+        tiles,
+        vec![
+            vec![(100, 110), (150, 152)],
+            vec![(152, 160), (190, 194)],
+            vec![(194, 200)],
+        ]
+    );
+
+    let overlapping: Vec<_> = gene.exon_windows(10, 5).collect();
+    assert_eq!(overlapping.len(), 5);
+    assert_eq!(overlapping[1], vec![(105, 110), (150, 155)]);
+
+    let single = GenePred::from_coords(b"chr1".to_vec(), 0, 20, Extras::new());
+    assert!(single
+        .exon_windows(10, 10)
+        .eq(single.windows(10, 10).map(|tile| vec![tile])));
+}
+
+#[test]
+fn test_genepred_try_new_and_validate() {
+    assert!(GenePred::try_new(b"chr1".to_vec(), 10, 20, Extras::new()).is_ok());
+    assert!(GenePred::try_new(b"chr1".to_vec(), 10, 10, Extras::new()).is_ok());
+    assert!(matches!(
+        GenePred::try_new(Vec::new(), 10, 20, Extras::new()),
+        Err(WriterError::MissingField("chrom"))
+    ));
+    assert!(matches!(
+        GenePred::try_new(b"chr1".to_vec(), 20, 10, Extras::new()),
+        Err(WriterError::Invalid(_))
+    ));
+
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    gene.set_block_count(Some(2));
+    gene.set_block_starts(Some(vec![100, 150]));
+    gene.set_block_ends(Some(vec![120, 200]));
+    gene.set_thick_start(Some(110));
+    gene.set_thick_end(Some(160));
+    assert!(gene.validate().is_ok());
+
+    let mut inverted = gene.clone();
+    inverted.set_start(250);
+    assert!(matches!(inverted.validate(), Err(WriterError::Invalid(_))));
+
+    let mut uneven = gene.clone();
+    uneven.set_block_ends(Some(vec![120]));
+    assert!(matches!(uneven.validate(), Err(WriterError::Invalid(msg)) if msg.contains("length")));
+
+    let mut miscounted = gene.clone();
+    miscounted.set_block_count(Some(3));
+    assert!(
+        matches!(miscounted.validate(), Err(WriterError::Invalid(msg)) if msg.contains("count"))
+    );
+
+    let mut thick_outside = gene.clone();
+    thick_outside.set_thick_start(Some(90));
+    assert!(
+        matches!(thick_outside.validate(), Err(WriterError::Invalid(msg)) if msg.contains("thick"))
+    );
+
+    let mut thick_inverted = gene.clone();
+    thick_inverted.set_thick_start(Some(170));
+    assert!(thick_inverted.validate().is_err());
+}
+
+#[test]
+fn test_genepred_add_and_set_attribute() {
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new());
+    gene.add_attribute("tag", "basic");
+    assert_eq!(
+        gene.get_extra(b"tag"),
+        Some(&ExtraValue::Scalar(b"basic".to_vec()))
+    );
+    gene.add_attribute("tag", "CCDS");
+    assert_eq!(
+        gene.get_extra(b"tag"),
+        Some(&ExtraValue::Array(vec![
+            b"basic".to_vec(),
+            b"CCDS".to_vec()
+        ]))
+    );
+    gene.add_attribute("tag", "CCDS");
+    assert_eq!(
+        gene.get_extra(b"tag").map(|value| value.iter().count()),
+        Some(2)
+    );
+
+    let previous = gene.set_attribute("tag", "MANE_Select");
+    assert_eq!(
+        previous,
+        Some(ExtraValue::Array(vec![b"basic".to_vec(), b"CCDS".to_vec()]))
+    );
+    assert_eq!(
+        gene.get_extra(b"tag"),
+        Some(&ExtraValue::Scalar(b"MANE_Select".to_vec()))
+    );
+    assert_eq!(gene.set_attribute("gene_id", "g1"), None);
+
+    let mut extras = Extras::new();
+    extras.add_value("tag", "basic");
+    extras.add_value("tag", "CCDS");
+    assert_eq!(
+        extras.get_all_str(b"tag").map(|values| values.len()),
+        Some(2)
+    );
+    extras.set_value("tag", "basic");
+    assert_eq!(extras.get_str(b"tag"), Some("basic"));
+}
+
+#[test]
+fn test_genepred_mirror() {
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 500, Extras::new());
+    gene.set_strand(Some(Strand::Forward));
+    gene.set_thick_start(Some(150));
+    gene.set_thick_end(Some(450));
+    gene.set_block_count(Some(2));
+    gene.set_block_starts(Some(vec![100, 400]));
+    gene.set_block_ends(Some(vec![200, 500]));
+    let original = gene.clone();
+
+    gene.mirror(1000).unwrap();
+    assert_eq!((gene.start(), gene.end()), (500, 900));
+    assert_eq!(gene.strand(), Some(Strand::Reverse));
+    assert_eq!(
+        (gene.thick_start(), gene.thick_end()),
+        (Some(550), Some(850))
+    );
+    assert_eq!(gene.exons(), vec![(500, 600), (800, 900)]);
+
+    gene.mirror(1000).unwrap();
+    assert_eq!(gene, original);
+
+    let mut short = original.clone();
+    assert!(matches!(short.mirror(450), Err(WriterError::Invalid(_))));
+    assert_eq!(short, original);
+}
@@ -1,9 +1,11 @@
 use genepred::{
+    bed::Rgb,
     genepred::{ExtraValue, Extras, GenePred},
     strand::Strand,
-    Bed12, Bed3, Gff, Gtf, Reader, ReaderOptions, Writer, WriterOptions,
+    verify_roundtrip, Bed12, Bed3, Bed6, Bed9, BedGraph, GenePredTable, Gff, Gtf, Psl, Reader,
+    ReaderOptions, RefFlat, Writer, WriterError, WriterOptions,
 };
-#[cfg(any(feature = "bz2", feature = "zstd"))]
+#[cfg(any(feature = "bz2", feature = "gzip", feature = "zstd", feature = "rayon"))]
 use tempfile::tempdir;
 
 #[test]
@@ -50,6 +52,109 @@ fn write_gtf_from_genepred() {
     assert!(stop_codon.contains("\t178\t180\t.\t+\t.\t"));
 }
 
+#[test]
+fn write_gtf_exon_number_replaces_inherited_numbers() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("numbered.gtf");
+    std::fs::write(
+        &path,
+        "chr1\tsrc\ttranscript\t100\t400\t.\t-\t.\tgene_id \"g1\"; transcript_id \"tx1\";\n\
+         chr1\tsrc\texon\t100\t150\t.\t-\t.\tgene_id \"g1\"; transcript_id \"tx1\"; exon_number \"2\";\n\
+         chr1\tsrc\texon\t300\t400\t.\t-\t.\tgene_id \"g1\"; transcript_id \"tx1\"; exon_number \"1\";\n",
+    )
+    .unwrap();
+    let mut reader: Reader<Gtf> = Reader::from_path(&path).unwrap();
+    let record = reader.records().next().unwrap().unwrap();
+    assert!(record.get_extra(b"exon_number").is_some());
+
+    let mut buf = Vec::new();
+    let opts = WriterOptions::new().emit_exon_number(true);
+    Writer::<Gtf>::from_record_with_options(&record, &mut buf, &opts).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    let rows: Vec<Vec<&str>> = text
+        .lines()
+        .map(|line| line.split('\t').collect())
+        .collect();
+
+    assert_eq!(rows[0][2], "transcript");
+    assert!(!rows[0][8].contains("exon_number"));
+    let exons: Vec<(&str, &str)> = rows
+        .iter()
+        .filter(|row| row[2] == "exon")
+        .map(|row| (row[3], row[8]))
+        .collect();
+    assert_eq!(exons.len(), 2);
+    for (start, attrs) in exons {
+        assert_eq!(attrs.matches("exon_number").count(), 1);
+        let expected = if start == "100" { "2" } else { "1" };
+        assert!(attrs.ends_with(&format!("exon_number \"{expected}\";")));
+    }
+}
+
+#[test]
+fn write_gtf_exon_number_is_strand_aware() {
+    let exon_numbers = |strand: Strand| -> Vec<(String, String)> {
+        let mut gene = GenePred::from_coords(b"chr1".to_vec(), 99, 400, Extras::new());
+        gene.set_name(Some(b"tx1".to_vec()));
+        gene.set_strand(Some(strand));
+        gene.set_block_count(Some(3));
+        gene.set_block_starts(Some(vec![99, 199, 299]));
+        gene.set_block_ends(Some(vec![150, 250, 400]));
+        gene.set_thick_start(Some(119));
+        gene.set_thick_end(Some(220));
+
+        let mut buf = Vec::new();
+        let opts = WriterOptions::new()
+            .emit_exon_number(true)
+            .emit_codons(false);
+        Writer::<Gtf>::from_record_with_options(&gene, &mut buf, &opts).unwrap();
+        String::from_utf8(buf)
+            .unwrap()
+            .lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split('\t').collect();
+                let number = fields[8].split("exon_number \"").nth(1)?;
+                Some((
+                    format!("{}:{}", fields[2], fields[3]),
+                    number.trim_end_matches("\";").to_string(),
+                ))
+            })
+            .collect()
+    };
+    let pairs = |items: &[(&str, &str)]| -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|(a, b)| (a.to_string(), b.to_string()))
+            .collect()
+    };
+
+    assert_eq!(
+        exon_numbers(Strand::Forward),
+        pairs(&[
+            ("exon:100", "1"),
+            ("exon:200", "2"),
+            ("exon:300", "3"),
+            ("CDS:120", "1"),
+            ("CDS:200", "2"),
+        ])
+    );
+    assert_eq!(
+        exon_numbers(Strand::Reverse),
+        pairs(&[
+            ("exon:100", "3"),
+            ("exon:200", "2"),
+            ("exon:300", "1"),
+            ("CDS:120", "3"),
+            ("CDS:200", "2"),
+        ])
+    );
+
+    let mut buf = Vec::new();
+    let gene = GenePred::from_coords(b"chr1".to_vec(), 99, 200, Extras::new());
+    Writer::<Gtf>::from_record(&gene, &mut buf).unwrap();
+    assert!(!String::from_utf8(buf).unwrap().contains("exon_number"));
+}
+
 #[test]
 fn write_gff_reverse_strand_with_phases() {
     let mut gene = GenePred::from_coords(b"chr2".to_vec(), 0, 90, Extras::new());
@@ -100,6 +205,64 @@ fn write_bed12_preserves_blocks() {
     );
 }
 
+#[test]
+fn write_gtf_from_noncoding_bed12() {
+    let bed = "chr3\t100\t260\tncTx\t0\t+\t100\t100\t0,0,0\t2\t50,60,\t0,100,\n";
+    let mut reader = Reader::<Bed12>::from_reader(std::io::Cursor::new(bed)).unwrap();
+    let record = reader.records().next().unwrap().unwrap();
+    assert!(!record.is_coding());
+
+    let mut buf = Vec::new();
+    Writer::<Gtf>::from_record(&record, &mut buf).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    let features: Vec<&str> = text
+        .lines()
+        .map(|line| line.split('\t').nth(2).unwrap())
+        .collect();
+    assert_eq!(features, vec!["transcript", "exon", "exon"]);
+}
+
+#[test]
+fn write_gff3_encodes_reserved_characters() {
+    let mut extras = Extras::new();
+    extras.insert(b"Name".to_vec(), ExtraValue::Scalar(b"a,b;c".to_vec()));
+    extras.insert(
+        b"Dbxref".to_vec(),
+        ExtraValue::Array(vec![b"x=1".to_vec(), b"y&2".to_vec()]),
+    );
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 99, 200, extras);
+    gene.set_name(Some(b"tx1".to_vec()));
+    gene.set_strand(Some(Strand::Forward));
+
+    let mut plain = Vec::new();
+    Writer::<Gff>::from_record(&gene, &mut plain).unwrap();
+    assert!(String::from_utf8(plain).unwrap().contains("Name=a,b;c"));
+
+    let options = WriterOptions::new().gff3_encode(true);
+    let mut buf = Vec::new();
+    Writer::<Gff>::from_record_with_options(&gene, &mut buf, &options).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    assert!(text.contains("Name=a%2Cb%3Bc"));
+    assert!(text.contains("Dbxref=x%3D1,y%262"));
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("encoded.gff3");
+    std::fs::write(&path, &text).unwrap();
+    let options = ReaderOptions::new()
+        .decode_percent(true)
+        .multi_value_attributes([b"Dbxref".as_ref()]);
+    let mut reader = Reader::<Gff>::from_gxf_with_options(&path, options).unwrap();
+    let record = reader.records().next().unwrap().unwrap();
+    assert_eq!(
+        record.extras().get(b"Name".as_ref()),
+        Some(&ExtraValue::Scalar(b"a,b;c".to_vec()))
+    );
+    assert_eq!(
+        record.extras().get(b"Dbxref".as_ref()),
+        Some(&ExtraValue::Array(vec![b"x=1".to_vec(), b"y&2".to_vec()]))
+    );
+}
+
 #[test]
 fn write_bed3_orders_numeric_extras() {
     let mut extras = Extras::new();
@@ -115,6 +278,68 @@ fn write_bed3_orders_numeric_extras() {
     assert_eq!(text.trim_end(), "chr4\t10\t20\tthird\tfourth\tnote=keep");
 }
 
+#[test]
+fn write_bed6_projects_bed12_records() {
+    let path = "tests/data/bed12.bed";
+    let mut reader = Reader::<Bed12>::from_path(path).unwrap();
+    let record = reader.records().next().unwrap().unwrap();
+
+    let mut buf = Vec::new();
+    Writer::<Bed6>::from_record(&record, &mut buf).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    assert_eq!(text, "chr1\t100\t400\ttxA\t950\t+\n");
+
+    let options = ReaderOptions::new().additional_fields(6);
+    let mut reader = Reader::<Bed6>::from_path_custom_fields(path, options).unwrap();
+    let record = reader.records().next().unwrap().unwrap();
+    assert_eq!(record.extras().len(), 6);
+
+    let mut buf = Vec::new();
+    Writer::<Bed6>::from_record(&record, &mut buf).unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap().split('\t').count(), 12);
+
+    let mut buf = Vec::new();
+    let opts = WriterOptions::new().extras_passthrough(false);
+    Writer::<Bed6>::from_record_with_options(&record, &mut buf, &opts).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    assert_eq!(text.trim_end().split('\t').count(), 6);
+    assert_eq!(text, "chr1\t100\t400\ttxA\t950\t+\n");
+}
+
+#[test]
+fn write_bedgraph_value_sources() {
+    let mut covered = GenePred::from_coords(b"chr1".to_vec(), 0, 100, Extras::new());
+    covered.set_score(Some(12));
+    covered.add_extra("signal", "3.75");
+    let mut plain = GenePred::from_coords(b"chr1".to_vec(), 100, 200, Extras::new());
+    plain.set_score(Some(4));
+    let records = vec![covered.clone(), plain];
+
+    let mut buf = Vec::new();
+    Writer::<BedGraph>::from_records(&records, &mut buf).unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "chr1\t0\t100\t12\nchr1\t100\t200\t4\n"
+    );
+
+    let mut buf = Vec::new();
+    let opts = WriterOptions::new().value_key(b"signal".as_ref());
+    Writer::<BedGraph>::from_records_with_options(&records, &mut buf, &opts).unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "chr1\t0\t100\t3.75\nchr1\t100\t200\t4\n"
+    );
+
+    let mut bad = covered;
+    bad.extras_mut()
+        .insert(b"signal".to_vec(), ExtraValue::Scalar(b"high".to_vec()));
+    let mut buf = Vec::new();
+    assert!(matches!(
+        Writer::<BedGraph>::from_record_with_options(&bad, &mut buf, &opts),
+        Err(WriterError::Invalid(_))
+    ));
+}
+
 #[test]
 fn write_bed3_can_disable_numeric_extras() {
     let mut extras = Extras::new();
@@ -227,6 +452,383 @@ fn gtf_to_bed_includes_codons_in_cds_bounds() {
     assert_eq!(fields[7], "200");
 }
 
+#[test]
+fn write_gtf_preserves_initial_cds_phase() {
+    let cds_phases = |text: &str| -> Vec<String> {
+        text.lines()
+            .filter(|line| line.split('\t').nth(2) == Some("CDS"))
+            .map(|line| line.split('\t').nth(7).unwrap().to_string())
+            .collect()
+    };
+
+    let mut reader: Reader<Gtf> = Reader::from_path("tests/data/fragment_phase.gtf").unwrap();
+    let record = reader.records().next().unwrap().unwrap();
+    assert_eq!(record.cds_phases(), Some([2, 2].as_ref()));
+
+    let mut recomputed = Vec::new();
+    Writer::<Gtf>::from_record(&record, &mut recomputed).unwrap();
+    assert_eq!(
+        cds_phases(&String::from_utf8(recomputed).unwrap()),
+        vec!["0", "0"]
+    );
+
+    let options = WriterOptions::new().preserve_phase(true);
+    let mut preserved = Vec::new();
+    Writer::<Gtf>::from_record_with_options(&record, &mut preserved, &options).unwrap();
+    assert_eq!(
+        cds_phases(std::str::from_utf8(&preserved).unwrap()),
+        vec!["2", "2"]
+    );
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("preserved.gtf");
+    std::fs::write(&path, preserved).unwrap();
+    let mut reader: Reader<Gtf> = Reader::from_path(&path).unwrap();
+    let roundtrip = reader.records().next().unwrap().unwrap();
+    assert_eq!(roundtrip.cds_phases(), record.cds_phases());
+}
+
+#[test]
+fn write_gff3_hierarchy_roundtrip() {
+    let transcript = |id: &[u8], gene: &[u8], start: u64| {
+        let mut extras = Extras::new();
+        extras.insert(b"ID".to_vec(), ExtraValue::Scalar(id.to_vec()));
+        extras.insert(b"gene_id".to_vec(), ExtraValue::Scalar(gene.to_vec()));
+        let mut record = GenePred::from_coords(b"chr1".to_vec(), start, start + 300, extras);
+        record.set_name(Some(id.to_vec()));
+        record.set_strand(Some(Strand::Forward));
+        record.set_block_count(Some(2));
+        record.set_block_starts(Some(vec![start, start + 200]));
+        record.set_block_ends(Some(vec![start + 100, start + 300]));
+        record.set_thick_start(Some(start + 50));
+        record.set_thick_end(Some(start + 250));
+        record
+    };
+    let records = vec![
+        transcript(b"tx1", b"g1", 100),
+        transcript(b"tx2", b"g2", 1000),
+    ];
+
+    let options = WriterOptions::new().gff3_hierarchy(true);
+    let mut buf = Vec::new();
+    Writer::<Gff>::from_records_with_options(&records, &mut buf, &options).unwrap();
+    let text = String::from_utf8(buf.clone()).unwrap();
+    let rows: Vec<Vec<&str>> = text
+        .lines()
+        .map(|line| line.split('\t').collect())
+        .collect();
+
+    assert_eq!(rows[0][2], "gene");
+    assert_eq!(rows[0][8], "ID=g1;");
+    assert_eq!(rows[1][2], "mRNA");
+    assert!(rows[1][8].starts_with("ID=tx1;Parent=g1;"));
+    assert_eq!(rows[2][2], "exon");
+    assert_eq!(rows[2][8], "ID=tx1.exon1;Parent=tx1;");
+    assert_eq!(rows[3][8], "ID=tx1.exon2;Parent=tx1;");
+    assert!(rows
+        .iter()
+        .any(|row| row[2] == "CDS" && row[8] == "ID=tx1.cds2;Parent=tx1;"));
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("hierarchy.gff");
+    std::fs::write(&path, buf).unwrap();
+    let mut reader: Reader<Gff> = Reader::from_path(&path).unwrap();
+    let mut parsed: Vec<GenePred> = reader.records().map(|r| r.unwrap()).collect();
+    parsed.sort();
+
+    assert_eq!(parsed.len(), 2);
+    for (record, expected) in parsed.iter().zip(&records) {
+        assert_eq!(record.name(), expected.name());
+        assert_eq!(record.start(), expected.start());
+        assert_eq!(record.end(), expected.end());
+        assert_eq!(record.block_starts(), expected.block_starts());
+        assert_eq!(record.block_ends(), expected.block_ends());
+        assert_eq!(record.thick_start(), expected.thick_start());
+        assert_eq!(record.thick_end(), expected.thick_end());
+    }
+}
+
+#[test]
+fn write_gff3_hierarchy_without_gene_id() {
+    let mut record = GenePred::from_coords(b"chr1".to_vec(), 100, 400, Extras::new());
+    record.set_name(Some(b"tx1".to_vec()));
+    record.set_strand(Some(Strand::Forward));
+
+    let options = WriterOptions::new().gff3_hierarchy(true);
+    let mut buf = Vec::new();
+    Writer::<Gff>::from_records_with_options(&[record], &mut buf, &options).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    let rows: Vec<Vec<&str>> = text
+        .lines()
+        .map(|line| line.split('\t').collect())
+        .collect();
+
+    assert_eq!(rows[0][2], "gene");
+    assert_eq!(rows[0][8], "ID=tx1.gene;");
+    assert_eq!(rows[1][2], "mRNA");
+    assert!(rows[1][8].starts_with("ID=tx1;Parent=tx1.gene;"));
+    assert_eq!(
+        rows.iter()
+            .filter(|row| row[8].contains("Parent=tx1;"))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn write_gff3_hierarchy_shared_gene() {
+    let transcript = |id: &[u8], start: u64, end: u64| {
+        let mut extras = Extras::new();
+        extras.insert(b"ID".to_vec(), ExtraValue::Scalar(id.to_vec()));
+        extras.insert(b"Parent".to_vec(), ExtraValue::Scalar(b"g1".to_vec()));
+        let mut record = GenePred::from_coords(b"chr1".to_vec(), start, end, extras);
+        record.set_strand(Some(Strand::Forward));
+        record
+    };
+    let records = vec![transcript(b"tx1", 100, 400), transcript(b"tx2", 200, 600)];
+    let options = WriterOptions::new().gff3_hierarchy(true);
+
+    let mut buf = Vec::new();
+    Writer::<Gff>::from_records_with_options(&records, &mut buf, &options).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    let genes: Vec<Vec<&str>> = text
+        .lines()
+        .map(|line| line.split('\t').collect::<Vec<_>>())
+        .filter(|row| row[2] == "gene")
+        .collect();
+    assert_eq!(genes.len(), 1);
+    assert_eq!(
+        (genes[0][3], genes[0][4], genes[0][8]),
+        ("101", "600", "ID=g1;")
+    );
+    let mrnas: Vec<&str> = text
+        .lines()
+        .filter(|line| line.contains("\tmRNA\t"))
+        .collect();
+    assert_eq!(mrnas.len(), 2);
+    assert!(mrnas
+        .iter()
+        .all(|line| line.matches("Parent=").count() == 1));
+    assert!(mrnas.iter().all(|line| line.contains("Parent=g1;")));
+
+    let mut streamed = Writer::<Gff>::new_with_options(Vec::new(), options);
+    streamed.write_all(&records).unwrap();
+    let streamed = String::from_utf8(streamed.finish().unwrap()).unwrap();
+    assert_eq!(
+        streamed
+            .lines()
+            .filter(|line| line.contains("\tgene\t"))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn write_bed6_preserves_score() {
+    let mut reader: Reader<Bed6> = Reader::from_path("tests/data/bed6.bed").unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records[0].score(), Some(500));
+
+    let mut buf = Vec::new();
+    Writer::<Bed6>::from_records(&records, &mut buf).unwrap();
+    let written = String::from_utf8(buf).unwrap();
+    let input = std::fs::read_to_string("tests/data/bed6.bed").unwrap();
+
+    let scores = |text: &str| -> Vec<String> {
+        text.lines()
+            .map(|line| line.split('\t').nth(4).unwrap().to_string())
+            .collect()
+    };
+    assert_eq!(scores(&written), scores(&input));
+    assert_eq!(scores(&written), vec!["500", "900"]);
+}
+
+#[test]
+fn write_bed6_score_from_extra() {
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 10, 20, Extras::new());
+    gene.set_name(Some(b"peak1".to_vec()));
+    gene.set_score(Some(7));
+    gene.add_extra("signalValue", "12.34");
+
+    let opts = WriterOptions::new()
+        .include_numeric_extras(false)
+        .score_from_extra("signalValue", 50.0);
+    let mut buf = Vec::new();
+    Writer::<Bed6>::from_record_with_options(&gene, &mut buf, &opts).unwrap();
+    assert_eq!(
+        String::from_utf8(buf).unwrap().trim_end(),
+        "chr1\t10\t20\tpeak1\t617\t."
+    );
+
+    let clamped = WriterOptions::new().score_from_extra("signalValue", 1000.0);
+    let mut buf = Vec::new();
+    Writer::<Bed6>::from_record_with_options(&gene, &mut buf, &clamped).unwrap();
+    assert!(String::from_utf8(buf).unwrap().contains("\tpeak1\t1000\t"));
+
+    let missing = WriterOptions::new().score_from_extra("pValue", 10.0);
+    let mut buf = Vec::new();
+    Writer::<Bed6>::from_record_with_options(&gene, &mut buf, &missing).unwrap();
+    assert!(String::from_utf8(buf).unwrap().contains("\tpeak1\t7\t"));
+}
+
+#[test]
+fn write_bed12_preserves_item_rgb() {
+    let mut reader: Reader<Bed12> = Reader::from_path("tests/data/bed12.bed").unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records[0].item_rgb(), Some(Rgb(0, 128, 0)));
+
+    let mut buf = Vec::new();
+    let opts = WriterOptions::new().default_item_rgb(Rgb(255, 0, 0));
+    Writer::<Bed12>::from_records_with_options(&records, &mut buf, &opts).unwrap();
+    let written = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        written.trim_end(),
+        "chr1\t100\t400\ttxA\t950\t+\t120\t360\t0,128,0\t2\t80,60,\t0,200,"
+    );
+}
+
+#[test]
+fn write_bed9_uses_default_item_rgb() {
+    let gene = GenePred::from_coords(b"chr2".to_vec(), 10, 20, Extras::new());
+
+    let mut buf = Vec::new();
+    Writer::<Bed9>::from_record(&gene, &mut buf).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    assert!(text.trim_end().ends_with("\t0,0,0"));
+
+    let mut buf = Vec::new();
+    let opts = WriterOptions::new().default_item_rgb(Rgb(12, 34, 56));
+    Writer::<Bed9>::from_record_with_options(&gene, &mut buf, &opts).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    assert!(text.trim_end().ends_with("\t12,34,56"));
+}
+
+#[test]
+fn write_bed9_uses_set_item_rgb() {
+    let mut gene = GenePred::from_coords(b"chr2".to_vec(), 10, 20, Extras::new());
+    gene.set_item_rgb(Some(Rgb(9, 8, 7)));
+    assert_eq!(gene.item_rgb(), Some(Rgb(9, 8, 7)));
+
+    let mut buf = Vec::new();
+    Writer::<Bed9>::from_record(&gene, &mut buf).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    assert!(text.trim_end().ends_with("\t9,8,7"));
+    assert_eq!(text.trim_end().as_bytes(), gene.to_bed::<Bed9>().as_slice());
+}
+
+#[test]
+fn genepred_table_roundtrips_bed12() {
+    let mut reader: Reader<Bed12> = Reader::from_path("tests/data/bed12.bed").unwrap();
+    let records: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+
+    let mut buf = Vec::new();
+    Writer::<GenePredTable>::from_records(&records, &mut buf).unwrap();
+    let text = String::from_utf8(buf.clone()).unwrap();
+    assert_eq!(
+        text,
+        "txA\tchr1\t+\t100\t400\t120\t360\t2\t100,300,\t180,360,\n"
+    );
+
+    let mut reader: Reader<GenePredTable> = Reader::from_reader(std::io::Cursor::new(buf)).unwrap();
+    let parsed: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(parsed.len(), 1);
+    let (original, gene) = (&records[0], &parsed[0]);
+    assert_eq!(gene.chrom(), original.chrom());
+    assert_eq!(gene.start(), original.start());
+    assert_eq!(gene.end(), original.end());
+    assert_eq!(gene.name(), original.name());
+    assert_eq!(gene.strand(), original.strand());
+    assert_eq!(gene.thick_start(), original.thick_start());
+    assert_eq!(gene.thick_end(), original.thick_end());
+    assert_eq!(gene.exons(), original.exons());
+}
+
+#[test]
+fn refflat_writes_gene_name_column() {
+    let mut gene = GenePred::from_coords(b"chr2".to_vec(), 10, 50, Extras::new());
+    gene.set_name(Some(b"NM_1".to_vec()));
+    gene.set_strand(Some(Strand::Reverse));
+    gene.add_extra("gene_name", "GENE1");
+
+    let mut buf = Vec::new();
+    Writer::<RefFlat>::from_record(&gene, &mut buf).unwrap();
+    let text = String::from_utf8(buf.clone()).unwrap();
+    assert_eq!(text, "GENE1\tNM_1\tchr2\t-\t10\t50\t50\t50\t1\t10,\t50,\n");
+
+    let mut reader: Reader<RefFlat> = Reader::from_reader(std::io::Cursor::new(buf)).unwrap();
+    let parsed = reader.records().next().unwrap().unwrap();
+    assert_eq!(parsed.name(), Some(b"NM_1".as_ref()));
+    assert_eq!(
+        parsed.get_extra(b"gene_name"),
+        Some(&ExtraValue::Scalar(b"GENE1".to_vec()))
+    );
+    assert_eq!(parsed.thick_start(), parsed.thick_end());
+}
+
+#[test]
+fn verify_roundtrip_reports_bed6_losses() {
+    let path = std::path::Path::new("tests/data/bed12.bed");
+
+    let report = verify_roundtrip::<Bed12, Bed12>(path).unwrap();
+    assert!(report.is_lossless());
+    assert_eq!(report.input_records, 1);
+    assert_eq!(report.output_records, 1);
+
+    let report = verify_roundtrip::<Bed12, Bed6>(path).unwrap();
+    assert!(!report.is_lossless());
+    assert_eq!(report.differences.len(), 1);
+    assert_eq!(
+        report.lost_fields().into_iter().collect::<Vec<_>>(),
+        vec![
+            "block_count",
+            "block_ends",
+            "block_starts",
+            "item_rgb",
+            "thick_end",
+            "thick_start",
+        ]
+    );
+
+    let path = std::path::Path::new("tests/data/multi_transcript.gtf");
+    let report = verify_roundtrip::<Gtf, Gtf>(path).unwrap();
+    assert!(report.is_lossless());
+    assert_eq!(report.output_records, 3);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn write_gzip_compression_levels_roundtrip() {
+    use std::io::Read;
+
+    let records: Vec<GenePred> = (0..2_000u64)
+        .map(|i| {
+            let mut gene =
+                GenePred::from_coords(b"chr1".to_vec(), i * 100, i * 100 + 50, Extras::new());
+            gene.set_name(Some(format!("feature{}", i % 17).into_bytes()));
+            gene
+        })
+        .collect();
+
+    let dir = tempdir().unwrap();
+    let decompressed = |level: u32| {
+        let path = dir.path().join(format!("level{level}.bed.gz"));
+        let options = WriterOptions::new().compression_level(level);
+        Writer::<Bed6>::to_path_with_options(&path, &records, &options).unwrap();
+        let size = std::fs::metadata(&path).unwrap().len();
+        let mut text = String::new();
+        flate2::read::MultiGzDecoder::new(std::fs::File::open(&path).unwrap())
+            .read_to_string(&mut text)
+            .unwrap();
+        (size, text)
+    };
+
+    let (fast_size, fast) = decompressed(1);
+    let (best_size, best) = decompressed(9);
+    assert_eq!(fast, best);
+    assert_eq!(fast.lines().count(), records.len());
+    assert!(best_size <= fast_size);
+}
+
 #[cfg(feature = "zstd")]
 #[test]
 fn write_bed3_zst_roundtrip() {
@@ -260,3 +862,193 @@ fn write_bed3_bz2_roundtrip() {
     assert_eq!(rerecords[0].start(), 0);
     assert_eq!(rerecords[1].end(), 200);
 }
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_to_path_matches_serial_output() {
+    let records: Vec<GenePred> = (0..10_000u64)
+        .map(|i| {
+            let start = i * 1_000;
+            let mut extras = Extras::new();
+            extras.insert(
+                b"gene_id".to_vec(),
+                format!("g{}", i / 3).into_bytes().into(),
+            );
+            let mut gene = GenePred::from_coords(b"chr1".to_vec(), start, start + 600, extras);
+            gene.set_name(Some(format!("tx{i}").into_bytes()));
+            gene.set_strand(Some(if i % 2 == 0 {
+                Strand::Forward
+            } else {
+                Strand::Reverse
+            }));
+            gene.set_block_count(Some(2));
+            gene.set_block_starts(Some(vec![start, start + 400]));
+            gene.set_block_ends(Some(vec![start + 200, start + 600]));
+            gene.set_thick_start(Some(start + 50));
+            gene.set_thick_end(Some(start + 550));
+            gene
+        })
+        .collect();
+
+    let dir = tempdir().unwrap();
+    let serial = dir.path().join("serial.gtf");
+    let parallel = dir.path().join("parallel.gtf");
+    Writer::<Gtf>::to_path(&serial, &records).unwrap();
+    Writer::<Gtf>::par_to_path(&parallel, &records).unwrap();
+
+    let serial = std::fs::read(serial).unwrap();
+    assert!(!serial.is_empty());
+    assert_eq!(std::fs::read(parallel).unwrap(), serial);
+}
+
+#[test]
+fn record_writer_matches_batch_output() {
+    let records: Vec<GenePred> = Reader::<Bed12>::from_path("tests/data/bed12.bed")
+        .unwrap()
+        .map(|record| record.unwrap())
+        .collect();
+    assert!(!records.is_empty());
+
+    let mut batch = Vec::new();
+    Writer::<Gtf>::from_records(&records, &mut batch).unwrap();
+
+    let mut streamed = Writer::<Gtf>::new(Vec::new());
+    for record in Reader::<Bed12>::from_path("tests/data/bed12.bed").unwrap() {
+        streamed.write(&record.unwrap()).unwrap();
+    }
+    assert_eq!(streamed.finish().unwrap(), batch);
+
+    let options = WriterOptions::new().default_item_rgb(Rgb(1, 2, 3));
+    let mut batch = Vec::new();
+    Writer::<Bed12>::from_records_with_options(&records, &mut batch, &options).unwrap();
+    let mut streamed = Writer::<Bed12>::new_with_options(Vec::new(), options);
+    streamed.write_all(&records).unwrap();
+    assert_eq!(streamed.finish().unwrap(), batch);
+}
+
+#[test]
+fn write_gtf_feature_emission_options() {
+    let gene = GenePred::from_coords(b"chr1".to_vec(), 99, 200, Extras::new())
+        .with_name(b"tx1".to_vec())
+        .with_strand(Strand::Forward)
+        .with_thick(119, 180)
+        .with_blocks(vec![99, 169], vec![150, 200]);
+    let features = |options: &WriterOptions| -> Vec<String> {
+        let mut buf = Vec::new();
+        Writer::<Gtf>::from_record_with_options(&gene, &mut buf, options).unwrap();
+        String::from_utf8(buf)
+            .unwrap()
+            .lines()
+            .map(|line| line.split('\t').nth(2).unwrap().to_string())
+            .collect()
+    };
+
+    assert_eq!(
+        features(&WriterOptions::default()),
+        vec![
+            "transcript",
+            "exon",
+            "exon",
+            "CDS",
+            "CDS",
+            "start_codon",
+            "stop_codon"
+        ]
+    );
+    assert_eq!(
+        features(&WriterOptions::new().emit_codons(false)),
+        vec!["transcript", "exon", "exon", "CDS", "CDS"]
+    );
+    assert_eq!(
+        features(&WriterOptions::new().emit_cds(false).emit_codons(false)),
+        vec!["transcript", "exon", "exon"]
+    );
+    assert_eq!(
+        features(
+            &WriterOptions::new()
+                .emit_cds(false)
+                .feature_type_override("transcript", "mRNA")
+        ),
+        vec!["mRNA", "exon", "exon", "start_codon", "stop_codon"]
+    );
+}
+
+#[test]
+fn write_to_path_creates_parent_dirs() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("tmp").join("nested").join("out.bed");
+    let records = vec![GenePred::from_coords(
+        b"chr1".to_vec(),
+        10,
+        20,
+        Extras::new(),
+    )];
+
+    let err = Writer::<Bed3>::to_path(&path, &records).unwrap_err();
+    assert!(matches!(err, WriterError::Invalid(_)));
+    assert!(err.to_string().contains("nested"));
+    assert!(err.to_string().contains("create_dirs"));
+
+    let options = WriterOptions::new().create_dirs(true);
+    Writer::<Bed3>::to_path_with_options(&path, &records, &options).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "chr1\t10\t20\n");
+}
+
+#[test]
+fn psl_block_geometry_matches_blocks() {
+    let mut gene = GenePred::from_coords(b"chr1".to_vec(), 100, 500, Extras::new());
+    gene.set_name(Some(b"tx1".to_vec()));
+    gene.set_strand(Some(Strand::Reverse));
+    gene.set_block_count(Some(3));
+    gene.set_block_starts(Some(vec![100, 250, 450]));
+    gene.set_block_ends(Some(vec![200, 300, 500]));
+
+    let mut buf = Vec::new();
+    Writer::<Psl>::from_record(&gene, &mut buf).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    let fields: Vec<&str> = text.trim_end().split('\t').collect();
+
+    assert_eq!(fields.len(), 21);
+    assert_eq!(fields[0], "200");
+    assert_eq!(fields[6], "2");
+    assert_eq!(fields[7], "200");
+    assert_eq!(fields[8], "-");
+    assert_eq!(fields[9], "tx1");
+    assert_eq!(&fields[10..13], &["200", "0", "200"]);
+    assert_eq!(fields[13], "chr1");
+    assert_eq!(&fields[15..17], &["100", "500"]);
+    assert_eq!(fields[17], "3");
+    assert_eq!(fields[18], "100,50,50,");
+    assert_eq!(fields[19], "0,100,150,");
+    assert_eq!(fields[20], "100,250,450,");
+}
+
+#[test]
+fn write_track_and_browser_header() {
+    let gene = |start| GenePred::from_coords(b"chr1".to_vec(), start, start + 10, Extras::new());
+    let records = vec![gene(10), gene(30)];
+    let options = WriterOptions::new()
+        .browser_lines(["position chr1:1-100"])
+        .track_line("name=genes description=\"My genes\"");
+
+    let mut buf = Vec::new();
+    Writer::<Bed3>::from_records_with_options(&records, &mut buf, &options).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "browser position chr1:1-100");
+    assert_eq!(lines[1], "track name=genes description=\"My genes\"");
+    assert_eq!(lines[2], "chr1\t10\t20");
+    assert_eq!(lines.len(), 4);
+
+    let mut streamed = Writer::<Bed3>::new_with_options(Vec::new(), options.clone());
+    streamed.write_all(&records[..1]).unwrap();
+    streamed.write_all(&records[1..]).unwrap();
+    assert_eq!(String::from_utf8(streamed.finish().unwrap()).unwrap(), text);
+
+    let options = WriterOptions::new().track_line("track name=x");
+    let mut buf = Vec::new();
+    Writer::<Bed3>::from_records_with_options(&records, &mut buf, &options).unwrap();
+    assert!(String::from_utf8(buf)
+        .unwrap()
+        .starts_with("track name=x\nchr1"));
+}